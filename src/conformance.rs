@@ -0,0 +1,187 @@
+//! Determinism checks for lockstep simulations
+//! 
+//! `checksum` runs a fixed set of quaternion, matrix and vector operations that only
+//! use `+` `-` `*` `/` and square roots, which IEEE 754 rounds exactly on every platform,
+//! and hashes the bits of every result
+//! 
+//! Trigonometric functions are **not** covered, they are only reproducible
+//! across platforms with the `libm` feature
+
+use crate::{
+    math,
+    functions::hash::{
+        hash_u32,
+        hash_to_f32,
+    },
+    types::{
+        Vector3, Vector4,
+        Quaternion,
+        Matrix4x4,
+    },
+};
+
+/// Expected result of `checksum`, the same with and without the `simd` and `libm` features
+pub const EXPECTED_CHECKSUM:u64 = 5325789733753690444;
+
+/// Number of operations of every kind hashed by `checksum`
+const ROUNDS:u32 = 64;
+
+/// Hash results of fixed quaternion composition, quaternion rotation,
+/// matrix multiplication, matrix inversion and vector operations
+/// 
+/// Returns: 64-bit *FNV-1a* hash of every result bit pattern
+pub fn checksum() -> u64 {
+    let mut hash = Fnv1a::new();
+
+    // quaternion composition and rotation
+    let mut rotation = Quaternion::IDENTITY;
+    let mut point = Vector3::new( 1.0, 2.0, 3.0 );
+    for round in 0..ROUNDS {
+        let q = unit_quaternion( round );
+        rotation = rotation * q;
+        point = q * point;
+        hash.write( rotation.as_array() );
+        hash.write( point.as_array() );
+    }
+    hash.write( rotation.as_matrix4x4().as_array() );
+
+    // matrix multiplication and inversion
+    let mut matrix = Matrix4x4::new_identity();
+    let mut vector = Vector4::new( 1.0, -1.0, 0.5, 1.0 );
+    for round in 0..ROUNDS {
+        let m = unit_quaternion( round + ROUNDS ).as_matrix4x4() *
+            Matrix4x4::new_scale( &[ value( round, 4 ) + 0.5, value( round, 5 ) + 0.5, value( round, 6 ) + 0.5 ] );
+        matrix = matrix * m;
+        vector = m.mul_vector4( &vector );
+        hash.write( matrix.as_array() );
+        hash.write( vector.as_array() );
+        if let Ok( inverse ) = m.inverse() {
+            hash.write( inverse.as_array() );
+        }
+        hash.write( &[ m.determinant() ] );
+    }
+
+    // vector operations
+    for round in 0..ROUNDS {
+        let a = Vector3::new( value( round, 7 ), value( round, 8 ), value( round, 9 ) );
+        let b = Vector3::new( value( round, 10 ), value( round, 11 ), value( round, 12 ) );
+        let cross = Vector3::cross( &a, &b );
+        hash.write( cross.as_array() );
+        hash.write( &[ Vector3::dot( &a, &b ), a.magnitude() ] );
+        hash.write( cross.normal().as_array() );
+    }
+
+    hash.finish()
+}
+
+/// Returns: `true` if `checksum` matches `EXPECTED_CHECKSUM` on this platform
+pub fn verify() -> bool {
+    checksum() == EXPECTED_CHECKSUM
+}
+
+/// Fixed value between **-1.0** and **1.0**
+fn value( round:u32, channel:u32 ) -> f32 {
+    ( hash_to_f32( hash_u32( ( round * 16 ) + channel ) ) * 2.0 ) - 1.0
+}
+
+/// Fixed normalized rotation
+fn unit_quaternion( round:u32 ) -> Quaternion {
+    let components = [
+        value( round, 0 ) + 2.0, value( round, 1 ),
+        value( round, 2 ), value( round, 3 ),
+    ];
+    let length = math::sqrt( components.iter().map( | c | c * c ).sum() );
+    Quaternion::from_array( components.map( | c | c / length ) )
+}
+
+/// 64-bit *FNV-1a* hash over `f32` bit patterns
+struct Fnv1a( u64 );
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self( 0xcbf29ce484222325 )
+    }
+
+    fn write( &mut self, values:&[f32] ) {
+        for value in values {
+            for byte in value.to_bits().to_le_bytes() {
+                self.0 ^= byte as u64;
+                self.0 = self.0.wrapping_mul( 0x100000001b3 );
+            }
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_checksum() {
+        assert_eq!( checksum(), EXPECTED_CHECKSUM );
+        assert!( verify() );
+    }
+
+    #[test]
+    fn quaternion_composition() {
+        let a = Quaternion::new( 1.0, 2.0, 3.0, 4.0 );
+        let b = Quaternion::new( 5.0, 6.0, 7.0, 8.0 );
+        assert_eq!( ( a * b ).as_array(), &[ -60.0, 12.0, 30.0, 24.0 ] );
+        assert_eq!( ( b * a ).as_array(), &[ -60.0, 20.0, 14.0, 32.0 ] );
+
+        let q = Quaternion::new( 0.5, 0.5, 0.5, 0.5 );
+        assert_eq!( ( q * q.conjugate() ).as_array(), &[ 1.0, 0.0, 0.0, 0.0 ] );
+    }
+
+    #[test]
+    fn quaternion_rotation() {
+        let point = Vector3::new( 1.0, 2.0, 3.0 );
+        // half turn around z
+        assert_eq!( ( Quaternion::new( 0.0, 0.0, 0.0, 1.0 ) * point ).as_array(), &[ -1.0, -2.0, 3.0 ] );
+        // third of a turn around ( 1, 1, 1 ), x -> y -> z -> x
+        assert_eq!( ( Quaternion::new( 0.5, 0.5, 0.5, 0.5 ) * point ).as_array(), &[ 3.0, 1.0, 2.0 ] );
+    }
+
+    #[test]
+    fn matrix_multiplication() {
+        let a = Matrix4x4::from_array([
+             2.0,  0.0, 1.0,  3.0,
+            -1.0,  4.0, 0.0,  2.0,
+             0.0,  1.0, 5.0, -2.0,
+             3.0, -3.0, 1.0,  1.0,
+        ]);
+        let b = Matrix4x4::from_array([
+             1.0, 2.0, 0.0, -1.0,
+             0.0, 1.0, 3.0,  2.0,
+             4.0, 0.0, 1.0,  1.0,
+            -2.0, 1.0, 0.0,  3.0,
+        ]);
+        assert_eq!( ( a * b ).as_array(), &[
+            -3.0, 11.0,  0.0,  6.0,
+             5.0,  1.0, 17.0, -2.0,
+            11.0, -2.0, 10.0, 11.0,
+             4.0, -5.0,  1.0, -1.0,
+        ] );
+        assert_eq!( a.mul_vector4( &Vector4::new( 1.0, -2.0, 3.0, 1.0 ) ).as_array(), &[ 7.0, -8.0, 17.0, -6.0 ] );
+    }
+
+    #[test]
+    fn euler_rotation() {
+        // trigonometry is only reproducible with the libm feature, compare with a tolerance
+        let m = Matrix4x4::new_rotate( &[ 1.0, 0.5, -0.25 ] );
+        let expected = [
+            0.850_300_6,  0.257_208_3, -0.459_165_2, 0.0,
+            0.217_117_4,  0.623_314,    0.751_225_5, 0.0,
+            0.479_425_5, -0.738_460_3,  0.474_159_9, 0.0,
+            0.0,          0.0,          0.0,         1.0,
+        ];
+        for ( component, expected ) in m.as_array().iter().zip( expected ) {
+            assert!( ( component - expected ).abs() < 1e-6, "{} != {}", component, expected );
+        }
+    }
+
+}
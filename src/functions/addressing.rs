@@ -0,0 +1,81 @@
+use crate::{
+    math,
+    types::Vector2,
+};
+
+/// How texture coordinates outside of **0.0**-**1.0** are resolved
+/// 
+/// Matches the sampler address modes of graphics APIs
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AddressMode {
+    /// Tile texture
+    Repeat,
+    /// Tile texture, flipping every other tile
+    MirroredRepeat,
+    /// Clamp to the closest edge
+    ClampToEdge,
+}
+
+/// Resolve single texture coordinate `c` with `mode`
+/// 
+/// Returns: `f32` between **0.0** and **1.0**
+pub fn wrap_coordinate( c:f32, mode:AddressMode ) -> f32 {
+    match mode {
+        AddressMode::Repeat => c - math::floor( c ),
+        AddressMode::MirroredRepeat => {
+            let t = math::rem_euclid( c, 2.0 );
+            if t > 1.0 { 2.0 - t } else { t }
+        },
+        AddressMode::ClampToEdge => c.clamp( 0.0, 1.0 ),
+    }
+}
+
+/// Resolve texture coordinates `uv` with the same `mode` on both axes
+/// 
+/// Returns: new `Vector2` with components between **0.0** and **1.0**
+pub fn wrap_uv( uv:&Vector2, mode:AddressMode ) -> Vector2 {
+    wrap_uv_per_axis( uv, mode, mode )
+}
+
+/// Resolve texture coordinates `uv` with separate modes for *u* and *v* axes
+/// 
+/// Returns: new `Vector2` with components between **0.0** and **1.0**
+pub fn wrap_uv_per_axis( uv:&Vector2, mode_u:AddressMode, mode_v:AddressMode ) -> Vector2 {
+    Vector2::new( wrap_coordinate( uv[0], mode_u ), wrap_coordinate( uv[1], mode_v ) )
+}
+
+/// Resolve integer texel coordinate `texel` on an axis of `size` texels with `mode`
+/// 
+/// Returns: `u32` between **0** and `size` - **1**, **0** if `size` is **0**
+pub fn wrap_texel( texel:i32, size:u32, mode:AddressMode ) -> u32 {
+    if size == 0 {
+        return 0;
+    }
+    let size = size as i64;
+    let texel = texel as i64;
+    let result = match mode {
+        AddressMode::Repeat => texel.rem_euclid( size ),
+        AddressMode::MirroredRepeat => {
+            let t = texel.rem_euclid( size * 2 );
+            if t >= size { ( size * 2 ) - 1 - t } else { t }
+        },
+        AddressMode::ClampToEdge => texel.clamp( 0, size - 1 ),
+    };
+    result as u32
+}
+
+/// Resolve integer texel coordinates `texel` in a texture of `size` texels
+/// with separate modes for *x* and *y* axes
+/// 
+/// Returns: `array` of texel coordinates inside the texture
+pub fn wrap_texel_2d(
+    texel:[i32;2], size:[u32;2],
+    mode_x:AddressMode, mode_y:AddressMode
+) -> [u32;2] {
+    [
+        wrap_texel( texel[0], size[0], mode_x ),
+        wrap_texel( texel[1], size[1], mode_y ),
+    ]
+}
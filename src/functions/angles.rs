@@ -1,19 +1,17 @@
 use super::PI;
+use crate::math;
 
 /// Overflows input `f32` between **0.0** and **360.0**
 /// 
-/// * Returns: `degrees` between **0.0** and **360.0**
+/// * Returns: `degrees` between **0.0** ( *inclusive* ) and **360.0** ( *exclusive* )
 pub fn degrees_overflow( degrees:f32 ) -> f32 {
-    let mut result = degrees;
-
-    if result < 0.0 {
-        result += 360.0;
-    }
-    
-    if result > 360.0 {
-        return result % 360.0;
+    // abs turns -0.0 into 0.0, the remainder is never negative otherwise
+    let result = math::rem_euclid( degrees, 360.0 ).abs();
+    // rem_euclid rounds tiny negative inputs up to exactly 360.0
+    if result >= 360.0 {
+        0.0
     } else {
-        return result;
+        result
     }
 }
 
@@ -0,0 +1,92 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::types::{
+    Vector3, Matrix4x4,
+};
+
+/// Number of elements processed per task by batch functions when the `rayon` feature is enabled
+/// 
+/// Slices no longer than this run on the calling thread,
+/// larger slices are split into chunks of this size and spread across the *rayon* thread pool
+/// 
+/// **4096** elements keeps per-task work well above scheduling overhead
+/// for transforms and color conversions while still splitting
+/// typical scenes ( *tens of thousands of elements* ) across every core
+pub const PARALLEL_CHUNK_SIZE:usize = 4096;
+
+/// Transform every point in `points` in place by `matrix`
+/// 
+/// Points are multiplied with a homogenous coordinate of **1.0**, see `Matrix4x4::mul_vector3`
+pub fn transform_points( matrix:&Matrix4x4, points:&mut [Vector3] ) {
+    for_each_chunk( points, | chunk | {
+        for point in chunk.iter_mut() {
+            *point = matrix.mul_vector3( point );
+        }
+    } );
+}
+
+/// Build skinning matrix palette from joint matrices
+/// 
+/// `joint_world`: joint matrices in model space for the current pose
+/// 
+/// `inverse_bind`: inverse of each joint's matrix in the bind pose
+/// 
+/// `palette`: receives `joint_world` * `inverse_bind` for every joint
+/// 
+/// Only the shortest of the three slices is processed
+pub fn skinning_palette( joint_world:&[Matrix4x4], inverse_bind:&[Matrix4x4], palette:&mut [Matrix4x4] ) {
+    let count = joint_world.len().min( inverse_bind.len() ).min( palette.len() );
+    for_each_chunk_zip(
+        &joint_world[..count], 1, &mut palette[..count], 1,
+        | offset, joints, chunk | {
+            for ( i, ( joint, matrix ) ) in joints.iter().zip( chunk.iter_mut() ).enumerate() {
+                *matrix = *joint * inverse_bind[offset + i];
+            }
+        }
+    );
+}
+
+/// Run `f` over `items`, split into `PARALLEL_CHUNK_SIZE` chunks in parallel with the `rayon` feature
+pub(crate) fn for_each_chunk<T, F>( items:&mut [T], f:F )
+where
+    T:Send,
+    F:Fn( &mut [T] ) + Sync + Send
+{
+    #[cfg(feature = "rayon")]
+    if items.len() > PARALLEL_CHUNK_SIZE {
+        items.par_chunks_mut( PARALLEL_CHUNK_SIZE ).for_each( f );
+        return;
+    }
+
+    f( items );
+}
+
+/// Run `f` over matching chunks of `input` and `output`
+/// 
+/// Every element has `input_stride` values in `input` and `output_stride` values in `output`,
+/// `f` receives the index of the chunk's first element
+pub(crate) fn for_each_chunk_zip<A, B, F>(
+    input:&[A], input_stride:usize,
+    output:&mut [B], output_stride:usize,
+    f:F
+)
+where
+    A:Sync,
+    B:Send,
+    F:Fn( usize, &[A], &mut [B] ) + Sync + Send
+{
+    #[cfg(feature = "rayon")]
+    if input.len() > PARALLEL_CHUNK_SIZE * input_stride {
+        input.par_chunks( PARALLEL_CHUNK_SIZE * input_stride )
+            .zip( output.par_chunks_mut( PARALLEL_CHUNK_SIZE * output_stride ) )
+            .enumerate()
+            .for_each( | ( chunk, ( input, output ) ) | f( chunk * PARALLEL_CHUNK_SIZE, input, output ) );
+        return;
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    let _ = ( input_stride, output_stride );
+
+    f( 0, input, output );
+}
@@ -0,0 +1,81 @@
+use crate::types::{
+    Vector3, Quaternion, Matrix4x4,
+};
+
+/// How billboards turn to face the camera
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BillboardMode {
+    /// Face camera position on every axis
+    Spherical,
+    /// Face camera position while staying upright around *y axis*
+    Cylindrical,
+}
+
+/// Compute orientation of every billboard in `positions` facing camera of `view` matrix
+/// 
+/// Billboards face the camera with their local **+z** axis,
+/// local **+y** axis follows camera up ( `Spherical` ) or world up ( `Cylindrical` )
+/// 
+/// `view`: *world to view* matrix such as `Matrix4x4::new_view`
+/// 
+/// Only the first `min( positions.len(), out.len() )` orientations are written
+pub fn orient_billboards(
+    positions:&[Vector3],
+    view:&Matrix4x4,
+    mode:BillboardMode,
+    out:&mut [Quaternion]
+) {
+    // rows of view rotation are camera axes in world space
+    let camera_right = Vector3::new( view[0], view[4], view[8] );
+    let camera_up    = Vector3::new( view[1], view[5], view[9] );
+    let camera_back  = Vector3::new( view[2], view[6], view[10] );
+    let camera_position = -(
+        ( camera_right * view[12] ) +
+        ( camera_up    * view[13] ) +
+        ( camera_back  * view[14] )
+    );
+
+    let world_up = Vector3::new_up();
+    let fallback_forward = match mode {
+        BillboardMode::Spherical   => camera_back,
+        BillboardMode::Cylindrical => Vector3::new( camera_back[0], 0.0, camera_back[2] ),
+    };
+
+    for ( position, orientation ) in positions.iter().zip( out.iter_mut() ) {
+        let mut forward = camera_position - *position;
+        if mode == BillboardMode::Cylindrical {
+            forward[1] = 0.0;
+        }
+        if forward.sqr_magnitude() <= f32::EPSILON {
+            forward = fallback_forward;
+        }
+        if forward.sqr_magnitude() <= f32::EPSILON {
+            *orientation = Quaternion::IDENTITY;
+            continue;
+        }
+        let forward = forward.normal();
+
+        let ( right, up ) = match mode {
+            BillboardMode::Spherical => {
+                let mut right = Vector3::cross( &camera_up, &forward );
+                if right.sqr_magnitude() <= f32::EPSILON {
+                    right = camera_right;
+                }
+                let right = right.normal();
+                ( right, Vector3::cross( &forward, &right ) )
+            },
+            BillboardMode::Cylindrical => {
+                ( Vector3::cross( &world_up, &forward ).normal(), world_up )
+            },
+        };
+
+        *orientation = Quaternion::from_matrix4x4( &Matrix4x4::from_array([
+            right[0],   right[1],   right[2],   0.0,
+            up[0],      up[1],      up[2],      0.0,
+            forward[0], forward[1], forward[2], 0.0,
+            0.0,        0.0,        0.0,        1.0,
+        ]) );
+    }
+}
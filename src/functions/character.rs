@@ -0,0 +1,60 @@
+use crate::types::Vector3;
+
+/// Remove the part of `velocity` moving into a surface
+/// 
+/// `hit_normal`: normalized surface normal, pointing away from the surface
+/// 
+/// Returns: `velocity` projected onto the surface plane,
+/// unchanged if `velocity` already moves away from the surface
+pub fn slide_velocity( velocity:&Vector3, hit_normal:&Vector3 ) -> Vector3 {
+    let into_surface = Vector3::dot( velocity, hit_normal );
+    if into_surface >= 0.0 {
+        return *velocity;
+    }
+    *velocity - ( *hit_normal * into_surface )
+}
+
+/// Move from `position` by `velocity` * `delta_time`, sliding along everything hit
+/// 
+/// `collide_fn`: sweep query supplied by the caller, receives current position and
+/// remaining motion, returns `None` if the motion is free or
+/// `Some( ( fraction, normal ) )` with the **0.0**-**1.0** fraction of motion
+/// travelled before hitting a surface with normalized `normal`
+/// 
+/// `max_iterations`: maximum number of sweeps, motion still left afterwards is dropped
+/// 
+/// Returns: new position and velocity slid along every surface hit
+pub fn move_and_slide<F>(
+    position:&Vector3,
+    velocity:&Vector3,
+    delta_time:f32,
+    mut collide_fn:F,
+    max_iterations:usize
+) -> ( Vector3, Vector3 )
+where F:FnMut( &Vector3, &Vector3 ) -> Option<( f32, Vector3 )>
+{
+    let mut position = *position;
+    let mut velocity = *velocity;
+    let mut motion   = velocity * delta_time;
+
+    for _ in 0..max_iterations {
+        if motion.sqr_magnitude() <= f32::EPSILON * f32::EPSILON {
+            break;
+        }
+
+        match collide_fn( &position, &motion ) {
+            None => {
+                position = position + motion;
+                break;
+            },
+            Some( ( fraction, normal ) ) => {
+                let fraction = fraction.clamp( 0.0, 1.0 );
+                position = position + ( motion * fraction );
+                motion   = slide_velocity( &( motion * ( 1.0 - fraction ) ), &normal );
+                velocity = slide_velocity( &velocity, &normal );
+            },
+        }
+    }
+
+    ( position, velocity )
+}
@@ -0,0 +1,219 @@
+use core::ops::{
+    Add, Mul,
+};
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    math,
+    types::{
+        Vector2, Vector3,
+        color::RGB,
+    },
+    functions::addressing::{
+        AddressMode,
+        wrap_texel,
+    },
+};
+
+/// Normalized *Sobel* smoothing kernel ( *1, 2, 1* / **4** )
+pub const SOBEL_SMOOTH:[f32;3] = [ 0.25, 0.5, 0.25 ];
+/// Normalized *Sobel* derivative kernel ( *-1, 0, 1* / **2** ), result is the slope per texel
+pub const SOBEL_DERIVATIVE:[f32;3] = [ -0.5, 0.0, 0.5 ];
+
+/// Normalized *gaussian* kernel
+/// 
+/// `radius`: kernel has **2** * `radius` + **1** weights
+/// 
+/// `sigma`: standard deviation in texels, `radius` of about **3** * `sigma` keeps the whole bell
+/// 
+/// Returns: `Vec` of weights summing to **1.0**, a single **1.0** weight if `sigma` is not positive
+pub fn gaussian_kernel( radius:u32, sigma:f32 ) -> Vec<f32> {
+    if sigma <= 0.0 {
+        let mut kernel = vec![ 0.0; ( radius as usize * 2 ) + 1 ];
+        kernel[radius as usize] = 1.0;
+        return kernel;
+    }
+
+    let denominator = 2.0 * sigma * sigma;
+    let radius = radius as i32;
+    let mut kernel:Vec<f32> = ( -radius..=radius )
+        .map( | offset | math::exp( -( ( offset * offset ) as f32 ) / denominator ) )
+        .collect();
+    let sum:f32 = kernel.iter().sum();
+    for weight in kernel.iter_mut() {
+        *weight /= sum;
+    }
+    kernel
+}
+
+/// Convolve `grid` with separable kernel, `kernel_x` along rows then `kernel_y` along columns
+/// 
+/// `grid`: `width` * `height` values stored row by row
+/// 
+/// Kernels must have an odd number of weights and are centered on the middle weight,
+/// texels outside of the grid are resolved with `mode`
+/// 
+/// Returns: new `Vec` of convolved values
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or a kernel has an even length
+pub fn convolve_separable(
+    grid:&[f32],
+    width:u32,
+    height:u32,
+    kernel_x:&[f32],
+    kernel_y:&[f32],
+    mode:AddressMode
+) -> Result<Vec<f32>, String> {
+    separable( grid.len(), width, height, kernel_x, kernel_y, mode, | i | grid[i] )
+}
+
+/// Convolve color `grid` with separable kernel, alpha is convolved too
+/// 
+/// `grid`: `width` * `height` colors stored row by row
+/// 
+/// Returns: new `Vec` of convolved colors, channels are clamped between **0** and **255**
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or a kernel has an even length
+pub fn convolve_separable_rgb(
+    grid:&[RGB],
+    width:u32,
+    height:u32,
+    kernel_x:&[f32],
+    kernel_y:&[f32],
+    mode:AddressMode
+) -> Result<Vec<RGB>, String> {
+    let result = separable(
+        grid.len(), width, height, kernel_x, kernel_y, mode,
+        | i | grid[i].as_vector4()
+    )?;
+    Ok( result.iter().map( | color | color.as_color_rgba() ).collect() )
+}
+
+/// *Gaussian* blur of `grid`
+/// 
+/// Returns: new `Vec` of blurred values
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height`
+pub fn gaussian_blur(
+    grid:&[f32],
+    width:u32,
+    height:u32,
+    radius:u32,
+    sigma:f32,
+    mode:AddressMode
+) -> Result<Vec<f32>, String> {
+    let kernel = gaussian_kernel( radius, sigma );
+    convolve_separable( grid, width, height, &kernel, &kernel, mode )
+}
+
+/// *Gaussian* blur of color `grid`, alpha is blurred too
+/// 
+/// Returns: new `Vec` of blurred colors
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height`
+pub fn gaussian_blur_rgb(
+    grid:&[RGB],
+    width:u32,
+    height:u32,
+    radius:u32,
+    sigma:f32,
+    mode:AddressMode
+) -> Result<Vec<RGB>, String> {
+    let kernel = gaussian_kernel( radius, sigma );
+    convolve_separable_rgb( grid, width, height, &kernel, &kernel, mode )
+}
+
+/// Gradient of `grid` with the *Sobel* operator
+/// 
+/// Returns: new `Vec` of `Vector2` slopes per texel,
+/// **x** towards the next column, **y** towards the next row
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height`
+pub fn sobel(
+    grid:&[f32],
+    width:u32,
+    height:u32,
+    mode:AddressMode
+) -> Result<Vec<Vector2>, String> {
+    let x = convolve_separable( grid, width, height, &SOBEL_DERIVATIVE, &SOBEL_SMOOTH, mode )?;
+    let y = convolve_separable( grid, width, height, &SOBEL_SMOOTH, &SOBEL_DERIVATIVE, mode )?;
+    Ok( x.iter().zip( y.iter() ).map( | ( x, y ) | Vector2::new( *x, *y ) ).collect() )
+}
+
+/// Tangent space normal map from `heights` with the *Sobel* operator
+/// 
+/// `strength`: height difference of **1.0** per texel is scaled by `strength`
+/// 
+/// Returns: new `Vec` of normalized `Vector3`,
+/// **x** towards the next column, **y** towards the next row, **z** out of the surface,
+/// negate **y** for normal maps with **y** pointing up the image
+/// 
+/// Returns: `String` error if `heights` doesn't match `width` * `height`
+pub fn height_to_normal_map(
+    heights:&[f32],
+    width:u32,
+    height:u32,
+    strength:f32,
+    mode:AddressMode
+) -> Result<Vec<Vector3>, String> {
+    let gradients = sobel( heights, width, height, mode )?;
+    Ok( gradients.iter().map( | gradient | {
+        Vector3::new( -gradient[0] * strength, -gradient[1] * strength, 1.0 ).normal()
+    } ).collect() )
+}
+
+fn separable<T, F>(
+    len:usize,
+    width:u32,
+    height:u32,
+    kernel_x:&[f32],
+    kernel_y:&[f32],
+    mode:AddressMode,
+    fetch:F
+) -> Result<Vec<T>, String>
+where
+    T:Copy + Add<Output = T> + Mul<f32, Output = T>,
+    F:Fn( usize ) -> T
+{
+    let ( w, h ) = ( width as usize, height as usize );
+    if len != w * h {
+        return Err( format!("CONVOLUTION ERROR: Expected {} values, got {}!", w * h, len) );
+    }
+    if kernel_x.len().is_multiple_of( 2 ) || kernel_y.len().is_multiple_of( 2 ) {
+        return Err(
+            format!(
+                "CONVOLUTION ERROR: Kernels must have an odd length, got {} and {}!",
+                kernel_x.len(), kernel_y.len()
+            )
+        );
+    }
+
+    let convolve = | kernel:&[f32], position:usize, size:u32, at:&dyn Fn( usize ) -> T | -> T {
+        let radius = ( kernel.len() / 2 ) as i32;
+        let tap = | offset:usize | {
+            let texel = wrap_texel( position as i32 + offset as i32 - radius, size, mode );
+            at( texel as usize ) * kernel[offset]
+        };
+        ( 1..kernel.len() ).fold( tap( 0 ), | result, offset | result + tap( offset ) )
+    };
+
+    let mut rows = Vec::with_capacity( w * h );
+    for y in 0..h {
+        for x in 0..w {
+            rows.push( convolve( kernel_x, x, width, &| texel | fetch( ( y * w ) + texel ) ) );
+        }
+    }
+
+    let mut result = Vec::with_capacity( w * h );
+    for y in 0..h {
+        for x in 0..w {
+            result.push( convolve( kernel_y, y, height, &| texel | rows[ ( texel * w ) + x ] ) );
+        }
+    }
+    Ok( result )
+}
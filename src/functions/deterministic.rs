@@ -0,0 +1,141 @@
+use core::f64::consts::FRAC_PI_2;
+
+/// Number of samples in a quarter sine wave, excluding the last sample
+const QUARTER_TABLE_SIZE:usize = 256;
+
+/// Quarter sine wave from **0.0** to **π / 2.0** generated at compile time
+/// using only basic arithmetic so every platform ends up with the same table
+static QUARTER_SINE_TABLE:[f32;QUARTER_TABLE_SIZE + 1] = generate_quarter_sine_table();
+
+const fn taylor_sin( x:f64 ) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum  = x;
+    let mut n = 1;
+    while n < 12 {
+        term = -term * x2 / ( ( 2 * n ) * ( 2 * n + 1 ) ) as f64;
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+const fn generate_quarter_sine_table() -> [f32;QUARTER_TABLE_SIZE + 1] {
+    let mut table = [0.0;QUARTER_TABLE_SIZE + 1];
+    let mut i = 0;
+    while i <= QUARTER_TABLE_SIZE {
+        let x = ( i as f64 ) * FRAC_PI_2 / ( QUARTER_TABLE_SIZE as f64 );
+        table[i] = taylor_sin( x ) as f32;
+        i += 1;
+    }
+    table
+}
+
+/// Sample full sine wave at table `index` between **0** and **4 x table size**
+fn sample_sine_table( index:usize ) -> f32 {
+    let quadrant = ( index / QUARTER_TABLE_SIZE ) % 4;
+    let offset   = index % QUARTER_TABLE_SIZE;
+    match quadrant {
+        0 =>  QUARTER_SINE_TABLE[offset],
+        1 =>  QUARTER_SINE_TABLE[QUARTER_TABLE_SIZE - offset],
+        2 => -QUARTER_SINE_TABLE[offset],
+        _ => -QUARTER_SINE_TABLE[QUARTER_TABLE_SIZE - offset],
+    }
+}
+
+/// Table based *sine* of `theta`
+/// 
+/// Only uses basic arithmetic so results are **identical** on every platform,
+/// useful for lockstep simulation
+/// 
+/// Maximum error is around **0.000005**,
+/// precision degrades for very large `theta`
+/// 
+/// `theta` is in **Radians**
+/// 
+/// Returns: *NaN* if `theta` is not finite
+pub fn sin( theta:f32 ) -> f32 {
+    // 1.0 / 2π
+    const FRAC_1_TAU:f32 = 0.159_154_94;
+    // 2^23, every f32 at or above this has no fractional part
+    const INTEGER_THRESHOLD:f32 = 8_388_608.0;
+    const TABLE_SIZE:usize = QUARTER_TABLE_SIZE * 4;
+
+    if !theta.is_finite() {
+        return f32::NAN;
+    }
+
+    let turns = theta * FRAC_1_TAU;
+    let mut fraction = if turns.abs() < INTEGER_THRESHOLD {
+        turns - ( ( turns as i32 ) as f32 )
+    } else {
+        0.0
+    };
+    if fraction < 0.0 {
+        fraction += 1.0;
+    }
+
+    // fraction is in 0.0-1.0 ( inclusive ), 1.0 wraps back to the start of the table
+    let position = fraction * TABLE_SIZE as f32;
+    let index    = position as usize;
+    let t        = position - ( index as f32 );
+    let index    = index % TABLE_SIZE;
+
+    let a = sample_sine_table( index );
+    let b = sample_sine_table( ( index + 1 ) % TABLE_SIZE );
+    a + ( ( b - a ) * t )
+}
+
+/// Table based *cosine* of `theta`
+/// 
+/// Same guarantees as `sin`
+/// 
+/// `theta` is in **Radians**
+pub fn cos( theta:f32 ) -> f32 {
+    sin( theta + crate::FRAC_PI_2 )
+}
+
+/// Table based *sine* and *cosine* of `theta`
+/// 
+/// Same guarantees as `sin`
+/// 
+/// Returns: ( *sine*, *cosine* )
+pub fn sin_cos( theta:f32 ) -> ( f32, f32 ) {
+    ( sin( theta ), cos( theta ) )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_core_sin() {
+        for i in -2000..2000 {
+            let theta = i as f32 * 0.01;
+            assert!( ( sin( theta ) - crate::math::sin( theta ) ).abs() < 1e-5 );
+            assert!( ( cos( theta ) - crate::math::cos( theta ) ).abs() < 1e-5 );
+        }
+    }
+
+    #[test]
+    fn non_finite_is_nan() {
+        for theta in [ f32::INFINITY, f32::NEG_INFINITY, f32::NAN ] {
+            assert!( sin( theta ).is_nan() );
+            assert!( cos( theta ).is_nan() );
+        }
+    }
+
+    #[test]
+    fn huge_inputs_stay_in_range() {
+        for theta in [ 1e7, -1e7, 1e20, -1e20, 3.4e38, -3.4e38, f32::MAX, f32::MIN ] {
+            let ( sine, cosine ) = sin_cos( theta );
+            assert!( ( -1.0..=1.0 ).contains( &sine ) );
+            assert!( ( -1.0..=1.0 ).contains( &cosine ) );
+        }
+        // whole number of turns
+        assert_eq!( sin( 1e20 ), 0.0 );
+        // fraction rounds up to a whole turn
+        assert!( sin( -1e-9 ).abs() < 1e-5 );
+    }
+
+}
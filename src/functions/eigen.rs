@@ -0,0 +1,95 @@
+use crate::{
+    math,
+    types::{
+        Vector3, Matrix3x3,
+    },
+};
+
+/// Jacobi sweeps stop once the off-diagonal cells are this small
+/// relative to the whole matrix ( *Frobenius norm* )
+pub const EIGEN_TOLERANCE:f32 = 1.0e-6;
+
+/// Maximum number of Jacobi sweeps, symmetric 3x3 matrices
+/// converge well before this
+pub const EIGEN_MAX_SWEEPS:usize = 32;
+
+/// Eigen decomposition of **symmetric** `Matrix3x3` using cyclic *Jacobi* rotations
+/// 
+/// Only the lower triangle is assumed to mirror the upper triangle,
+/// results are undefined for non-symmetric matrices
+/// 
+/// Iterates until off-diagonal cells are within `EIGEN_TOLERANCE`
+/// or `EIGEN_MAX_SWEEPS` is reached
+/// 
+/// Returns: eigenvalues sorted from **largest** to **smallest** and
+/// `Matrix3x3` with matching normalized eigenvectors as *columns*
+pub fn symmetric_eigen_3x3( m:&Matrix3x3 ) -> ( Vector3, Matrix3x3 ) {
+    // a[row][column]
+    let mut a = [
+        [ m[0], m[3], m[6] ],
+        [ m[1], m[4], m[7] ],
+        [ m[2], m[5], m[8] ],
+    ];
+    let mut v = [
+        [ 1.0, 0.0, 0.0 ],
+        [ 0.0, 1.0, 0.0 ],
+        [ 0.0, 0.0, 1.0 ],
+    ];
+
+    let total:f32 = m.as_array().iter().map( |x| x * x ).sum();
+    let threshold = EIGEN_TOLERANCE * EIGEN_TOLERANCE * total;
+
+    let mut sweep = 0;
+    while sweep < EIGEN_MAX_SWEEPS {
+        let off_diagonal =
+            2.0 * ( ( a[0][1] * a[0][1] ) + ( a[0][2] * a[0][2] ) + ( a[1][2] * a[1][2] ) );
+        if off_diagonal <= threshold {
+            break;
+        }
+
+        for ( p, q ) in [ ( 0, 1 ), ( 0, 2 ), ( 1, 2 ) ] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+
+            let theta = ( a[q][q] - a[p][p] ) / ( 2.0 * a[p][q] );
+            let t = theta.signum() / ( theta.abs() + math::sqrt( ( theta * theta ) + 1.0 ) );
+            let c = 1.0 / math::sqrt( ( t * t ) + 1.0 );
+            let s = t * c;
+
+            // a = transpose( J ) * a * J
+            for row in a.iter_mut() {
+                let ( akp, akq ) = ( row[p], row[q] );
+                row[p] = ( c * akp ) - ( s * akq );
+                row[q] = ( s * akp ) + ( c * akq );
+            }
+            let ( row_p, row_q ) = ( a[p], a[q] );
+            for ( k, ( apk, aqk ) ) in row_p.iter().zip( row_q.iter() ).enumerate() {
+                a[p][k] = ( c * apk ) - ( s * aqk );
+                a[q][k] = ( s * apk ) + ( c * aqk );
+            }
+
+            // v = v * J
+            for row in v.iter_mut() {
+                let ( vkp, vkq ) = ( row[p], row[q] );
+                row[p] = ( c * vkp ) - ( s * vkq );
+                row[q] = ( s * vkp ) + ( c * vkq );
+            }
+        }
+
+        sweep += 1;
+    }
+
+    let mut order = [ 0, 1, 2 ];
+    order.sort_by( |&i, &j| a[j][j].total_cmp( &a[i][i] ) );
+
+    let eigenvalues = Vector3::new( a[order[0]][order[0]], a[order[1]][order[1]], a[order[2]][order[2]] );
+    let mut eigenvectors = Matrix3x3::new_zero();
+    for ( column, &index ) in order.iter().enumerate() {
+        for ( row, v_row ) in v.iter().enumerate() {
+            *eigenvectors.nm_mut_index( row, column ) = v_row[index];
+        }
+    }
+
+    ( eigenvalues, eigenvectors )
+}
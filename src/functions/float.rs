@@ -0,0 +1,135 @@
+/// Returns: next representable `f32` after `f` in the direction of `toward`
+/// 
+/// Returns: `toward` if `f` is equal to `toward`
+/// 
+/// Returns: *NaN* if either input is *NaN*
+pub fn next_after_f32( f:f32, toward:f32 ) -> f32 {
+    if f.is_nan() || toward.is_nan() {
+        return f32::NAN;
+    }
+    if f == toward {
+        return toward;
+    }
+    if f == 0.0 {
+        // smallest subnormal with the sign of toward
+        return f32::from_bits( 1 ).copysign( toward );
+    }
+
+    let bits = f.to_bits();
+    // moving away from zero increases magnitude bits
+    if ( toward > f ) == ( f > 0.0 ) {
+        f32::from_bits( bits + 1 )
+    } else {
+        f32::from_bits( bits - 1 )
+    }
+}
+
+/// Returns: next representable `f64` after `f` in the direction of `toward`
+/// 
+/// Returns: `toward` if `f` is equal to `toward`
+/// 
+/// Returns: *NaN* if either input is *NaN*
+pub fn next_after_f64( f:f64, toward:f64 ) -> f64 {
+    if f.is_nan() || toward.is_nan() {
+        return f64::NAN;
+    }
+    if f == toward {
+        return toward;
+    }
+    if f == 0.0 {
+        // smallest subnormal with the sign of toward
+        return f64::from_bits( 1 ).copysign( toward );
+    }
+
+    let bits = f.to_bits();
+    // moving away from zero increases magnitude bits
+    if ( toward > f ) == ( f > 0.0 ) {
+        f64::from_bits( bits + 1 )
+    } else {
+        f64::from_bits( bits - 1 )
+    }
+}
+
+/// Maps `f32` to `u32` that sorts in the same order as the input
+/// 
+/// Useful for radix sorting floats
+pub fn to_bits_sortable_f32( f:f32 ) -> u32 {
+    let bits = f.to_bits();
+    if bits & ( 1 << 31 ) != 0 {
+        !bits
+    } else {
+        bits | ( 1 << 31 )
+    }
+}
+
+/// Inverse of `to_bits_sortable_f32`
+pub fn from_bits_sortable_f32( bits:u32 ) -> f32 {
+    if bits & ( 1 << 31 ) != 0 {
+        f32::from_bits( bits & !( 1 << 31 ) )
+    } else {
+        f32::from_bits( !bits )
+    }
+}
+
+/// Maps `f64` to `u64` that sorts in the same order as the input
+/// 
+/// Useful for radix sorting floats
+pub fn to_bits_sortable_f64( f:f64 ) -> u64 {
+    let bits = f.to_bits();
+    if bits & ( 1 << 63 ) != 0 {
+        !bits
+    } else {
+        bits | ( 1 << 63 )
+    }
+}
+
+/// Inverse of `to_bits_sortable_f64`
+pub fn from_bits_sortable_f64( bits:u64 ) -> f64 {
+    if bits & ( 1 << 63 ) != 0 {
+        f64::from_bits( bits & !( 1 << 63 ) )
+    } else {
+        f64::from_bits( !bits )
+    }
+}
+
+/// Returns: number of representable `f32` values between `a` and `b`
+/// 
+/// **0.0** and **-0.0** are treated as equal
+/// 
+/// Returns: `u32::MAX` if either input is *NaN*
+pub fn ulp_distance_f32( a:f32, b:f32 ) -> u32 {
+    if a.is_nan() || b.is_nan() {
+        return u32::MAX;
+    }
+
+    let magnitude_a = a.to_bits() & !( 1 << 31 );
+    let magnitude_b = b.to_bits() & !( 1 << 31 );
+
+    if a.is_sign_negative() == b.is_sign_negative() {
+        magnitude_a.abs_diff( magnitude_b )
+    } else {
+        // distance to zero on both sides
+        magnitude_a.saturating_add( magnitude_b )
+    }
+}
+
+/// Returns: number of representable `f64` values between `a` and `b`
+/// 
+/// **0.0** and **-0.0** are treated as equal
+/// 
+/// Returns: `u64::MAX` if either input is *NaN*
+pub fn ulp_distance_f64( a:f64, b:f64 ) -> u64 {
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+
+    let magnitude_a = a.to_bits() & !( 1 << 63 );
+    let magnitude_b = b.to_bits() & !( 1 << 63 );
+
+    if a.is_sign_negative() == b.is_sign_negative() {
+        magnitude_a.abs_diff( magnitude_b )
+    } else {
+        // distance to zero on both sides
+        magnitude_a.saturating_add( magnitude_b )
+    }
+}
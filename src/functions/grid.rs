@@ -0,0 +1,227 @@
+use core::ops::{
+    Add, Mul,
+};
+use alloc::{
+    format,
+    string::String,
+};
+
+use crate::{
+    math,
+    types::{
+        Vector2, Vector4,
+        color::RGB,
+    },
+    functions::addressing::{
+        AddressMode,
+        wrap_texel,
+    },
+};
+
+/// Sample `grid` with *bilinear* filtering
+/// 
+/// `grid`: `width` * `height` values stored row by row
+/// 
+/// `uv`: **0.0**-**1.0** covers the whole grid, texel centers are at half texel offsets
+/// 
+/// Returns: filtered `f32`
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or the grid is empty
+pub fn sample_bilinear( grid:&[f32], width:u32, height:u32, uv:&Vector2, mode:AddressMode ) -> Result<f32, String> {
+    validate( grid.len(), width, height )?;
+    let taps_x = bilinear_taps( uv[0], width, mode );
+    let taps_y = bilinear_taps( uv[1], height, mode );
+    Ok( filter( &taps_x, &taps_y, width, 0.0, | i | grid[i] ) )
+}
+
+/// Sample `grid` with *bicubic* ( *Catmull-Rom* ) filtering
+/// 
+/// `grid`: `width` * `height` values stored row by row
+/// 
+/// `uv`: **0.0**-**1.0** covers the whole grid, texel centers are at half texel offsets
+/// 
+/// Returns: filtered `f32`, may overshoot neighboring values
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or the grid is empty
+pub fn sample_bicubic( grid:&[f32], width:u32, height:u32, uv:&Vector2, mode:AddressMode ) -> Result<f32, String> {
+    validate( grid.len(), width, height )?;
+    let taps_x = bicubic_taps( uv[0], width, mode );
+    let taps_y = bicubic_taps( uv[1], height, mode );
+    Ok( filter( &taps_x, &taps_y, width, 0.0, | i | grid[i] ) )
+}
+
+/// Sample color `grid` with *bilinear* filtering, alpha is filtered too
+/// 
+/// `grid`: `width` * `height` colors stored row by row
+/// 
+/// Returns: filtered `RGB`
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or the grid is empty
+pub fn sample_bilinear_rgb( grid:&[RGB], width:u32, height:u32, uv:&Vector2, mode:AddressMode ) -> Result<RGB, String> {
+    validate( grid.len(), width, height )?;
+    let taps_x = bilinear_taps( uv[0], width, mode );
+    let taps_y = bilinear_taps( uv[1], height, mode );
+    Ok( filter( &taps_x, &taps_y, width, Vector4::new_zero(), | i | grid[i].as_vector4() ).as_color_rgba() )
+}
+
+/// Sample color `grid` with *bicubic* ( *Catmull-Rom* ) filtering, alpha is filtered too
+/// 
+/// `grid`: `width` * `height` colors stored row by row
+/// 
+/// Returns: filtered `RGB`, channels are clamped between **0** and **255**
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or the grid is empty
+pub fn sample_bicubic_rgb( grid:&[RGB], width:u32, height:u32, uv:&Vector2, mode:AddressMode ) -> Result<RGB, String> {
+    validate( grid.len(), width, height )?;
+    let taps_x = bicubic_taps( uv[0], width, mode );
+    let taps_y = bicubic_taps( uv[1], height, mode );
+    Ok( filter( &taps_x, &taps_y, width, Vector4::new_zero(), | i | grid[i].as_vector4() ).as_color_rgba() )
+}
+
+fn validate( len:usize, width:u32, height:u32 ) -> Result<(), String> {
+    if width == 0 || height == 0 {
+        return Err( format!("GRID ERROR: Grid size {}x{} must not be empty!", width, height) );
+    }
+    let expected = width as usize * height as usize;
+    if len != expected {
+        return Err( format!("GRID ERROR: Expected {} values, got {}!", expected, len) );
+    }
+    Ok(())
+}
+
+/// Texel position of `c` relative to texel centers
+/// 
+/// Position is reduced to one period of `mode` before converting to an integer,
+/// so large coordinates can't overflow the texel math, non-finite `c` gives a *NaN* weight
+fn texel_position( c:f32, size:u32, mode:AddressMode ) -> ( i32, f32 ) {
+    let size = size as f32;
+    let x = ( c * size ) - 0.5;
+    let x = match mode {
+        AddressMode::Repeat         => math::rem_euclid( x, size ),
+        AddressMode::MirroredRepeat => math::rem_euclid( x, size * 2.0 ),
+        AddressMode::ClampToEdge    => x.clamp( -2.0, size + 1.0 ),
+    };
+    let base = math::floor( x );
+    ( base as i32, x - base )
+}
+
+fn bilinear_taps( c:f32, size:u32, mode:AddressMode ) -> [( u32, f32 );2] {
+    let ( base, t ) = texel_position( c, size, mode );
+    [
+        ( wrap_texel( base,                    size, mode ), 1.0 - t ),
+        ( wrap_texel( base.saturating_add( 1 ), size, mode ), t ),
+    ]
+}
+
+fn bicubic_taps( c:f32, size:u32, mode:AddressMode ) -> [( u32, f32 );4] {
+    let ( base, t ) = texel_position( c, size, mode );
+    let weights = [
+        t * ( -0.5 + ( t * ( 1.0 - ( 0.5 * t ) ) ) ),
+        1.0 + ( t * t * ( -2.5 + ( 1.5 * t ) ) ),
+        t * ( 0.5 + ( t * ( 2.0 - ( 1.5 * t ) ) ) ),
+        t * t * ( -0.5 + ( 0.5 * t ) ),
+    ];
+    let mut result = [( 0, 0.0 );4];
+    for ( offset, ( tap, weight ) ) in result.iter_mut().zip( weights.iter() ).enumerate() {
+        *tap = ( wrap_texel( base.saturating_add( offset as i32 - 1 ), size, mode ), *weight );
+    }
+    result
+}
+
+fn filter<T, F>( taps_x:&[( u32, f32 )], taps_y:&[( u32, f32 )], width:u32, zero:T, fetch:F ) -> T
+where
+    T:Copy + Add<Output = T> + Mul<f32, Output = T>,
+    F:Fn( usize ) -> T
+{
+    let mut result = zero;
+    for ( y, weight_y ) in taps_y.iter() {
+        for ( x, weight_x ) in taps_x.iter() {
+            let index = ( *y as usize * width as usize ) + *x as usize;
+            result = result + ( fetch( index ) * ( weight_x * weight_y ) );
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID:[f32;4] = [ 0.0, 1.0, 2.0, 3.0 ];
+
+    #[test]
+    fn bilinear_interpolates_texel_centers() {
+        let center = Vector2::new( 0.5, 0.5 );
+        let first  = Vector2::new( 0.25, 0.25 );
+        assert_eq!( sample_bilinear( &GRID, 2, 2, &center, AddressMode::ClampToEdge ), Ok( 1.5 ) );
+        assert_eq!( sample_bilinear( &GRID, 2, 2, &first, AddressMode::ClampToEdge ), Ok( 0.0 ) );
+    }
+
+    #[test]
+    fn bicubic_keeps_constant_grid() {
+        let grid = [ 0.75; 9 ];
+        let uv = Vector2::new( 0.3, 0.8 );
+        let result = sample_bicubic( &grid, 3, 3, &uv, AddressMode::Repeat ).unwrap();
+        assert!( ( result - 0.75 ).abs() < 1e-6 );
+    }
+
+    #[test]
+    fn out_of_range_uvs_wrap() {
+        let grid:[f32;9] = [ 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 ];
+        let uv = Vector2::new( 0.25, 0.75 );
+        for mode in [ AddressMode::Repeat, AddressMode::MirroredRepeat ] {
+            let shifted = Vector2::new( 6.25, -3.25 );
+            assert_eq!(
+                sample_bicubic( &grid, 3, 3, &shifted, mode ),
+                sample_bicubic( &grid, 3, 3, &uv, mode )
+            );
+        }
+        let mirrored = Vector2::new( 1.75, -0.75 );
+        assert_eq!(
+            sample_bilinear( &grid, 3, 3, &mirrored, AddressMode::MirroredRepeat ),
+            sample_bilinear( &grid, 3, 3, &uv, AddressMode::MirroredRepeat )
+        );
+    }
+
+    #[test]
+    fn huge_and_non_finite_uvs() {
+        let uv = Vector2::new( -1e30, 0.5 );
+        assert_eq!( sample_bicubic( &GRID, 2, 2, &uv, AddressMode::ClampToEdge ), Ok( 1.0 ) );
+        let uv = Vector2::new( f32::INFINITY, 0.5 );
+        assert_eq!( sample_bilinear( &GRID, 2, 2, &uv, AddressMode::ClampToEdge ), Ok( 2.0 ) );
+
+        let huge = [ Vector2::new( 1e30, 0.5 ), Vector2::new( -3e37, 2e37 ) ];
+        let non_finite = [
+            Vector2::new( f32::INFINITY, 0.5 ),
+            Vector2::new( 0.5, f32::NEG_INFINITY ),
+            Vector2::new( f32::NAN, 0.5 ),
+        ];
+        for mode in [ AddressMode::Repeat, AddressMode::MirroredRepeat, AddressMode::ClampToEdge ] {
+            for uv in huge.iter() {
+                let bilinear = sample_bilinear( &GRID, 2, 2, uv, mode ).unwrap();
+                let bicubic = sample_bicubic( &GRID, 2, 2, uv, mode ).unwrap();
+                assert!( ( 0.0..=3.0 ).contains( &bilinear ) );
+                assert!( bicubic.is_finite() );
+            }
+            for uv in non_finite.iter() {
+                let _ = sample_bilinear( &GRID, 2, 2, uv, mode ).unwrap();
+                let _ = sample_bicubic_rgb( &[ RGB::new_white(); 4 ], 2, 2, uv, mode ).unwrap();
+            }
+        }
+        let uv = Vector2::new( f32::NAN, 0.5 );
+        assert!( sample_bilinear( &GRID, 2, 2, &uv, AddressMode::Repeat ).unwrap().is_nan() );
+    }
+
+    #[test]
+    fn invalid_grids() {
+        let uv = Vector2::new( 0.5, 0.5 );
+        let mode = AddressMode::Repeat;
+        assert!( sample_bilinear( &GRID, 2, 3, &uv, mode ).is_err() );
+        assert!( sample_bicubic( &GRID, 4, 1, &uv, mode ).is_ok() );
+        assert!( sample_bicubic( &[], 0, 0, &uv, mode ).is_err() );
+        assert!( sample_bilinear( &[], 0, 4, &uv, mode ).is_err() );
+        assert!( sample_bilinear_rgb( &[ RGB::new_white() ], 1, 2, &uv, mode ).is_err() );
+        assert!( sample_bicubic_rgb( &[ RGB::new_white() ], 1, 1, &uv, mode ).is_ok() );
+    }
+
+}
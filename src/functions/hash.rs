@@ -0,0 +1,72 @@
+use crate::{
+    math,
+    PI,
+    types::{
+        Vector2, Vector3,
+    },
+};
+
+/// Hash `u32` using *PCG hash*
+/// 
+/// Stable across platforms and program runs, unlike `std` hashers
+pub fn hash_u32( x:u32 ) -> u32 {
+    let state = x.wrapping_mul( 747796405 ).wrapping_add( 2891336453 );
+    let word  = ( ( state >> ( ( state >> 28 ) + 4 ) ) ^ state ).wrapping_mul( 277803737 );
+    ( word >> 22 ) ^ word
+}
+
+/// Maps hash to `f32` between **0.0** ( *inclusive* ) and **1.0** ( *exclusive* )
+pub fn hash_to_f32( hash:u32 ) -> f32 {
+    // 24 bits of mantissa precision
+    ( hash >> 8 ) as f32 * ( 1.0 / 16_777_216.0 )
+}
+
+/// Hash 2D cell coordinates with `seed`
+pub fn hash_2d_u32( x:i32, y:i32, seed:u32 ) -> u32 {
+    let mut hash = hash_u32( seed );
+    hash = hash_u32( hash ^ x as u32 );
+    hash_u32( hash ^ y as u32 )
+}
+
+/// Hash 3D cell coordinates with `seed`
+pub fn hash_3d_u32( x:i32, y:i32, z:i32, seed:u32 ) -> u32 {
+    let mut hash = hash_u32( seed );
+    hash = hash_u32( hash ^ x as u32 );
+    hash = hash_u32( hash ^ y as u32 );
+    hash_u32( hash ^ z as u32 )
+}
+
+/// Returns: `f32` between **0.0** ( *inclusive* ) and **1.0** ( *exclusive* )
+/// that is stable for given 2D cell coordinates and `seed`
+pub fn hash_2d( x:i32, y:i32, seed:u32 ) -> f32 {
+    hash_to_f32( hash_2d_u32( x, y, seed ) )
+}
+
+/// Returns: `f32` between **0.0** ( *inclusive* ) and **1.0** ( *exclusive* )
+/// that is stable for given 3D cell coordinates and `seed`
+pub fn hash_3d( x:i32, y:i32, z:i32, seed:u32 ) -> f32 {
+    hash_to_f32( hash_3d_u32( x, y, z, seed ) )
+}
+
+/// Returns: `Vector2` with `magnitude` of **1.0**
+/// that is stable for given 2D cell coordinates and `seed`
+pub fn hash_to_unit_vector2( x:i32, y:i32, seed:u32 ) -> Vector2 {
+    let angle = hash_2d( x, y, seed ) * 2.0 * PI;
+    let ( sin, cos ) = math::sin_cos( angle );
+    Vector2::new( cos, sin )
+}
+
+/// Returns: `Vector3` with `magnitude` of **1.0**
+/// that is stable for given 3D cell coordinates and `seed`
+/// 
+/// Directions are uniformly distributed over the sphere
+pub fn hash_to_unit_vector3( x:i32, y:i32, z:i32, seed:u32 ) -> Vector3 {
+    let hash = hash_3d_u32( x, y, z, seed );
+
+    let height = ( hash_to_f32( hash ) * 2.0 ) - 1.0;
+    let angle  = hash_to_f32( hash_u32( hash ) ) * 2.0 * PI;
+
+    let ( sin, cos ) = math::sin_cos( angle );
+    let r = math::sqrt( ( 1.0 - ( height * height ) ).max( 0.0 ) );
+    Vector3::new( r * cos, r * sin, height )
+}
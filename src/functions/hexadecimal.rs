@@ -1,10 +1,17 @@
 use core::fmt::Write;
 
+use alloc::{
+    format,
+    string::String,
+    vec::Vec,
+};
+
 /// Decodes input `str` into bytes `Vec`
 /// 
 /// * Returns: `Vec` of bytes if input `str` has even number length
 /// 
 /// * Returns: `Error` as `String` if input `str` has odd number length
+#[allow(clippy::manual_is_multiple_of, clippy::needless_return, clippy::useless_format)]
 pub fn decode_hex_str( hex:&str ) -> Result<Vec<u8>, String> {
 
     if hex.len() % 2 != 0 {
@@ -36,6 +43,7 @@ pub fn decode_hex_str( hex:&str ) -> Result<Vec<u8>, String> {
 /// * Returns: `u8;3` if input `str` is formatted properly
 /// 
 /// * Returns: `Error` as `String` if input `str` is not formatted properly
+#[allow(clippy::needless_return, clippy::useless_format)]
 pub fn decode_hex_rgb( hex:&str ) -> Result<[u8;3], String> {
 
     let mut hexadecimal = String::from(hex);
@@ -71,6 +79,7 @@ pub fn decode_hex_rgb( hex:&str ) -> Result<[u8;3], String> {
 }
 
 /// Encodes input bytes `array` into *hexadecimal* `String`
+#[allow(clippy::needless_return)]
 pub fn encode_hex( bytes:&[u8] ) -> String {
     let mut result = String::with_capacity( bytes.len() * 2 );
 
@@ -0,0 +1,115 @@
+use crate::types::{
+    Vector2, Vector3, Vector4, VectorN,
+    Matrix4x4,
+    Quaternion, UnitQuaternion,
+    Transform,
+    color::{
+        RGB,
+        srgb_to_linear,
+        linear_to_srgb,
+    },
+};
+
+/// Types that can be interpolated between two values
+/// 
+/// Lets animation and tweening code be written once for every `fmath` type
+/// 
+/// - `f32` `f64` and vectors interpolate *linearly*
+/// - `Quaternion` and `UnitQuaternion` interpolate *spherically*
+/// - `Matrix4x4` is decomposed, see `Matrix4x4::interpolate`
+/// - `Transform` interpolates translation and scale *linearly*, rotation *spherically*
+/// - `RGB` is treated as *sRGB* and interpolated in linear space, alpha *linearly*
+pub trait Lerp: Sized {
+    /// Interpolate from `a` to `b`
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// Returns: new value between `a` and `b`
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self;
+
+    /// Interpolate from `a` to `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new value between `a` and `b`
+    fn lerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::lerp_unclamped( a, b, t.clamp( 0.0, 1.0 ) )
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        super::lerp_unclamped( *a, *b, t )
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        let t = t as f64;
+        ( ( 1.0 - t ) * a ) + ( b * t )
+    }
+}
+
+impl Lerp for Vector2 {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Vector2::lerp_unclamped( a, b, t )
+    }
+}
+
+impl Lerp for Vector3 {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Vector3::lerp_unclamped( a, b, t )
+    }
+}
+
+impl Lerp for Vector4 {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Vector4::lerp_unclamped( a, b, t )
+    }
+}
+
+impl<const N:usize> Lerp for VectorN<N> {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        VectorN::lerp_unclamped( a, b, t )
+    }
+}
+
+impl Lerp for Quaternion {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Quaternion::slerp_unclamped( a, b, t )
+    }
+}
+
+impl Lerp for UnitQuaternion {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        UnitQuaternion::slerp_unclamped( a, b, t )
+    }
+}
+
+impl Lerp for Matrix4x4 {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Matrix4x4::interpolate( a, b, t )
+    }
+}
+
+impl Lerp for Transform {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Transform::lerp_unclamped( a, b, t )
+    }
+}
+
+impl Lerp for RGB {
+    fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        let a = a.as_float_rgba_array();
+        let b = b.as_float_rgba_array();
+        let channel = | i:usize | linear_to_srgb(
+            super::lerp_unclamped( srgb_to_linear( a[i] ), srgb_to_linear( b[i] ), t ).max( 0.0 )
+        );
+        RGB::from_float_rgba(
+            channel( 0 ),
+            channel( 1 ),
+            channel( 2 ),
+            super::lerp_unclamped( a[3], b[3], t ),
+        )
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    math,
+    PI,
+    types::{
+        Vector2, Vector3,
+    },
+};
+
+/// Face of a cubemap
+///
+/// Faces are ordered the same way graphics APIs expect them ( *+X, -X, +Y, -Y, +Z, -Z* )
+///
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CubemapFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+impl CubemapFace {
+    /// Returns: layer index of face
+    pub fn index(&self) -> usize {
+        match self {
+            CubemapFace::PositiveX => 0,
+            CubemapFace::NegativeX => 1,
+            CubemapFace::PositiveY => 2,
+            CubemapFace::NegativeY => 3,
+            CubemapFace::PositiveZ => 4,
+            CubemapFace::NegativeZ => 5,
+        }
+    }
+}
+
+/// Map `direction` to cubemap face and texture coordinates
+///
+/// Follows the *OpenGL* cubemap convention
+///
+/// `direction` does **not** need to be normalized
+///
+/// Returns: `CubemapFace` and `uv` with values between **0.0** and **1.0**
+pub fn direction_to_cubemap_face_uv( direction:&Vector3 ) -> ( CubemapFace, Vector2 ) {
+    let ( x, y, z ) = ( direction[0], direction[1], direction[2] );
+    let ( abs_x, abs_y, abs_z ) = ( x.abs(), y.abs(), z.abs() );
+
+    // major axis, s and t coordinates
+    let ( face, major, s, t ) = {
+        if abs_x >= abs_y && abs_x >= abs_z {
+            if x >= 0.0 {
+                ( CubemapFace::PositiveX, abs_x, -z, -y )
+            } else {
+                ( CubemapFace::NegativeX, abs_x,  z, -y )
+            }
+        } else if abs_y >= abs_z {
+            if y >= 0.0 {
+                ( CubemapFace::PositiveY, abs_y,  x,  z )
+            } else {
+                ( CubemapFace::NegativeY, abs_y,  x, -z )
+            }
+        } else if z >= 0.0 {
+            ( CubemapFace::PositiveZ, abs_z,  x, -y )
+        } else {
+            ( CubemapFace::NegativeZ, abs_z, -x, -y )
+        }
+    };
+
+    (
+        face,
+        Vector2::new(
+            0.5 * ( ( s / major ) + 1.0 ),
+            0.5 * ( ( t / major ) + 1.0 ),
+        )
+    )
+}
+
+/// Map cubemap `face` and texture coordinates to direction
+///
+/// Inverse of `direction_to_cubemap_face_uv`
+///
+/// Returns: normalized `Vector3`
+pub fn cubemap_face_uv_to_direction( face:CubemapFace, uv:&Vector2 ) -> Vector3 {
+    let s = ( uv[0] * 2.0 ) - 1.0;
+    let t = ( uv[1] * 2.0 ) - 1.0;
+
+    let direction = match face {
+        CubemapFace::PositiveX => Vector3::new(  1.0,   -t,   -s ),
+        CubemapFace::NegativeX => Vector3::new( -1.0,   -t,    s ),
+        CubemapFace::PositiveY => Vector3::new(    s,  1.0,    t ),
+        CubemapFace::NegativeY => Vector3::new(    s, -1.0,   -t ),
+        CubemapFace::PositiveZ => Vector3::new(    s,   -t,  1.0 ),
+        CubemapFace::NegativeZ => Vector3::new(   -s,   -t, -1.0 ),
+    };
+
+    direction.normal()
+}
+
+/// Map `direction` to equirectangular texture coordinates
+///
+/// `u` wraps around the *y axis* starting at **-x**,
+/// `v` is **0.0** at **+y** and **1.0** at **-y**
+///
+/// `direction` should be a normalized vector
+///
+/// Returns: `uv` with values between **0.0** and **1.0**
+pub fn direction_to_equirectangular_uv( direction:&Vector3 ) -> Vector2 {
+    Vector2::new(
+        ( math::atan2( direction[2], direction[0] ) / ( 2.0 * PI ) ) + 0.5,
+        math::acos( direction[1].clamp( -1.0, 1.0 ) ) / PI,
+    )
+}
+
+/// Map equirectangular texture coordinates to direction
+///
+/// Inverse of `direction_to_equirectangular_uv`
+///
+/// Returns: normalized `Vector3`
+pub fn equirectangular_uv_to_direction( uv:&Vector2 ) -> Vector3 {
+    let phi   = ( uv[0] - 0.5 ) * 2.0 * PI;
+    let theta = uv[1] * PI;
+
+    let ( sin_theta, cos_theta ) = math::sin_cos( theta );
+    let ( sin_phi, cos_phi )     = math::sin_cos( phi );
+
+    Vector3::new(
+        sin_theta * cos_phi,
+        cos_theta,
+        sin_theta * sin_phi,
+    )
+}
+
+/// Map `direction` to octahedral texture coordinates
+/// 
+/// Upper hemisphere ( **+z** ) covers the inner diamond,
+/// lower hemisphere is folded into the corners
+/// 
+/// `direction` does not need to be normalized
+/// 
+/// Returns: `uv` with values between **0.0** and **1.0**
+pub fn direction_to_octahedral_uv( direction:&Vector3 ) -> Vector2 {
+    let l1 = direction[0].abs() + direction[1].abs() + direction[2].abs();
+    let ( mut x, mut y ) = ( direction[0] / l1, direction[1] / l1 );
+    if direction[2] < 0.0 {
+        let folded_x = ( 1.0 - y.abs() ) * sign_not_zero( x );
+        let folded_y = ( 1.0 - x.abs() ) * sign_not_zero( y );
+        x = folded_x;
+        y = folded_y;
+    }
+    Vector2::new( ( x * 0.5 ) + 0.5, ( y * 0.5 ) + 0.5 )
+}
+
+/// Map octahedral texture coordinates to direction
+/// 
+/// Inverse of `direction_to_octahedral_uv`
+/// 
+/// Returns: normalized `Vector3`
+pub fn octahedral_uv_to_direction( uv:&Vector2 ) -> Vector3 {
+    let ( mut x, mut y ) = ( ( uv[0] * 2.0 ) - 1.0, ( uv[1] * 2.0 ) - 1.0 );
+    let z = 1.0 - x.abs() - y.abs();
+    if z < 0.0 {
+        let folded_x = ( 1.0 - y.abs() ) * sign_not_zero( x );
+        let folded_y = ( 1.0 - x.abs() ) * sign_not_zero( y );
+        x = folded_x;
+        y = folded_y;
+    }
+    Vector3::new( x, y, z ).normal()
+}
+
+/// Direction and solid angle of every texel of a `width` x `height` equirectangular map
+/// 
+/// Texels are visited row by row, directions point through texel centers,
+/// solid angles are exact and sum to **4π**
+/// 
+/// Returns: `Iterator` of ( *direction, solid angle in steradians* )
+pub fn equirectangular_texel_directions( width:u32, height:u32 ) -> impl Iterator<Item = ( Vector3, f32 )> {
+    let texel_width = 2.0 * PI / width as f32;
+    ( 0..height ).flat_map( move | y | {
+        let cos_top    = math::cos( PI * y as f32 / height as f32 );
+        let cos_bottom = math::cos( PI * ( y + 1 ) as f32 / height as f32 );
+        let solid_angle = texel_width * ( cos_top - cos_bottom );
+
+        ( 0..width ).map( move | x | {
+            let uv = Vector2::new(
+                ( x as f32 + 0.5 ) / width as f32,
+                ( y as f32 + 0.5 ) / height as f32,
+            );
+            ( equirectangular_uv_to_direction( &uv ), solid_angle )
+        } )
+    } )
+}
+
+/// Direction and solid angle of every texel of a `size` x `size` octahedral map
+/// 
+/// Texels are visited row by row, directions point through texel centers
+/// 
+/// Solid angles are exact for even `size` and sum to **4π**,
+/// odd sizes split texels across octahedron folds and are approximate
+/// 
+/// Returns: `Iterator` of ( *direction, solid angle in steradians* )
+pub fn octahedral_texel_directions( size:u32 ) -> impl Iterator<Item = ( Vector3, f32 )> {
+    let corner = move | x:u32, y:u32 | {
+        octahedral_uv_to_direction( &Vector2::new( x as f32 / size as f32, y as f32 / size as f32 ) )
+    };
+
+    ( 0..size ).flat_map( move | y | {
+        ( 0..size ).map( move | x | {
+            let ( a, b ) = ( corner( x, y ), corner( x + 1, y ) );
+            let ( c, d ) = ( corner( x, y + 1 ), corner( x + 1, y + 1 ) );
+
+            // split along the diagonal parallel to the octahedron fold in this quadrant
+            // so both triangles lie on a single face
+            let center = ( x * 2 ) + 1;
+            let same_side = ( center > size ) == ( ( y * 2 ) + 1 > size );
+            let solid_angle = if same_side {
+                spherical_triangle_area( &b, &c, &a ) + spherical_triangle_area( &b, &d, &c )
+            } else {
+                spherical_triangle_area( &a, &b, &d ) + spherical_triangle_area( &a, &d, &c )
+            };
+
+            let uv = Vector2::new(
+                ( x as f32 + 0.5 ) / size as f32,
+                ( y as f32 + 0.5 ) / size as f32,
+            );
+            ( octahedral_uv_to_direction( &uv ), solid_angle )
+        } )
+    } )
+}
+
+/// *Stereographic* projection of `direction` onto the plane through the origin, centered on **+z**
+/// 
+/// Projects from **-z**, so the **+z** hemisphere lands inside the unit circle
+/// and **-z** itself goes to infinity
+/// 
+/// `direction` should be a normalized vector
+/// 
+/// Returns: projected point
+pub fn stereographic_project( direction:&Vector3 ) -> Vector2 {
+    let denominator = 1.0 + direction[2];
+    Vector2::new( direction[0] / denominator, direction[1] / denominator )
+}
+
+/// Inverse of `stereographic_project`
+/// 
+/// Returns: normalized `Vector3`
+pub fn stereographic_unproject( point:&Vector2 ) -> Vector3 {
+    let sqr_radius  = point.sqr_magnitude();
+    let denominator = 1.0 + sqr_radius;
+    Vector3::new(
+        ( 2.0 * point[0] ) / denominator,
+        ( 2.0 * point[1] ) / denominator,
+        ( 1.0 - sqr_radius ) / denominator,
+    )
+}
+
+/// *Azimuthal equidistant* projection of `direction`, centered on **+z**
+/// 
+/// Distance from the center is proportional to the angle from **+z**:
+/// **+z** maps to the center, the horizon to radius **0.5** and **-z** to the unit circle
+/// 
+/// `direction` should be a normalized vector
+/// 
+/// Returns: projected point inside the unit circle
+pub fn azimuthal_equidistant_project( direction:&Vector3 ) -> Vector2 {
+    let radius  = math::acos( direction[2].clamp( -1.0, 1.0 ) ) / PI;
+    let azimuth = math::sqrt( ( direction[0] * direction[0] ) + ( direction[1] * direction[1] ) );
+    if azimuth <= 0.0 {
+        // pointing straight along z, any azimuth works
+        return Vector2::new( radius, 0.0 );
+    }
+    Vector2::new( ( direction[0] / azimuth ) * radius, ( direction[1] / azimuth ) * radius )
+}
+
+/// Inverse of `azimuthal_equidistant_project`
+/// 
+/// Returns: normalized `Vector3`
+pub fn azimuthal_equidistant_unproject( point:&Vector2 ) -> Vector3 {
+    let radius = point.magnitude();
+    if radius <= 0.0 {
+        return Vector3::new_forward();
+    }
+    let ( sin_theta, cos_theta ) = math::sin_cos( radius * PI );
+    Vector3::new(
+        ( point[0] / radius ) * sin_theta,
+        ( point[1] / radius ) * sin_theta,
+        cos_theta,
+    )
+}
+
+fn sign_not_zero( value:f32 ) -> f32 {
+    if value >= 0.0 { 1.0 } else { -1.0 }
+}
+
+/// Solid angle of spherical triangle with normalized corners ( *Van Oosterom-Strackee* )
+fn spherical_triangle_area( a:&Vector3, b:&Vector3, c:&Vector3 ) -> f32 {
+    let numerator = Vector3::dot( a, &Vector3::cross( b, c ) ).abs();
+    let denominator = 1.0 + Vector3::dot( a, b ) + Vector3::dot( b, c ) + Vector3::dot( c, a );
+    2.0 * math::atan2( numerator, denominator )
+}
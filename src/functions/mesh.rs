@@ -0,0 +1,208 @@
+use alloc::{
+    format,
+    string::String,
+    vec::Vec,
+};
+
+use crate::{
+    PI,
+    math,
+    types::{
+        Vector3,
+        Pcg32, AliasTable,
+    },
+};
+
+/// Scatter `count` points uniformly over the surface of a triangle mesh
+/// 
+/// Triangles are picked with probability proportional to their area,
+/// points are uniform within each triangle
+/// 
+/// `indices`: triangle list, front faces are *counter-clockwise*
+/// 
+/// Returns: `Vec` of ( *position, normalized face normal* )
+/// 
+/// Returns: `String` error if `indices` length is **not** a multiple of **3**,
+/// an index is out of range or mesh has no area
+pub fn sample_surface(
+    positions:&[Vector3],
+    indices:&[u32],
+    count:usize,
+    rng:&mut Pcg32
+) -> Result<Vec<( Vector3, Vector3 )>, String> {
+    let triangles = triangle_corners( positions, indices )?;
+
+    let areas:Vec<f32> = triangles.iter()
+        .map( | [ a, b, c ] | Vector3::cross( &( *b - *a ), &( *c - *a ) ).magnitude() )
+        .collect();
+    let table = AliasTable::new( &areas )
+        .map_err( | _ | String::from("SAMPLE SURFACE ERROR: Mesh has no surface area!") )?;
+
+    let mut result = Vec::with_capacity( count );
+    for _ in 0..count {
+        let [ a, b, c ] = triangles[ table.sample( rng ) ];
+
+        // square root warps the unit square onto the triangle without clustering
+        let root = math::sqrt( rng.next_f32() );
+        let u = 1.0 - root;
+        let v = rng.next_f32() * root;
+
+        let position = ( a * u ) + ( b * v ) + ( c * ( 1.0 - u - v ) );
+        let normal   = Vector3::cross( &( b - a ), &( c - a ) ).normal();
+        result.push( ( position, normal ) );
+    }
+
+    Ok( result )
+}
+
+/// *Generalized winding number* of a triangle mesh around `point`
+/// 
+/// Sum of signed solid angles of every triangle divided by **4π**,
+/// **1.0** inside and **0.0** outside a closed mesh with *counter-clockwise* front faces,
+/// **-1.0** inside if front faces are *clockwise*.
+/// Meshes with holes or overlaps degrade smoothly instead of failing
+/// 
+/// Returns: winding number
+/// 
+/// Returns: `String` error if `indices` length is **not** a multiple of **3**
+/// or an index is out of range
+pub fn winding_number( point:&Vector3, positions:&[Vector3], indices:&[u32] ) -> Result<f32, String> {
+    let triangles = triangle_corners( positions, indices )?;
+
+    let mut total = 0.0_f64;
+    for [ a, b, c ] in triangles.iter() {
+        let ( a, b, c ) = ( *a - *point, *b - *point, *c - *point );
+        let ( length_a, length_b, length_c ) = ( a.magnitude(), b.magnitude(), c.magnitude() );
+
+        // Van Oosterom-Strackee signed solid angle
+        let numerator   = Vector3::dot( &a, &Vector3::cross( &b, &c ) );
+        let denominator =
+            ( length_a * length_b * length_c ) +
+            ( Vector3::dot( &a, &b ) * length_c ) +
+            ( Vector3::dot( &a, &c ) * length_b ) +
+            ( Vector3::dot( &b, &c ) * length_a );
+        total += ( 2.0 * math::atan2( numerator, denominator ) ) as f64;
+    }
+
+    Ok( ( total / ( 4.0 * PI as f64 ) ) as f32 )
+}
+
+/// Test if `point` is inside triangle mesh using its `winding_number`
+/// 
+/// Same as `point_inside_mesh_with_threshold` with `threshold` of **0.5**
+/// 
+/// Returns: `true` if `point` is inside
+/// 
+/// Returns: `String` error if `indices` length is **not** a multiple of **3**
+/// or an index is out of range
+pub fn point_inside_mesh( point:&Vector3, positions:&[Vector3], indices:&[u32] ) -> Result<bool, String> {
+    point_inside_mesh_with_threshold( point, positions, indices, 0.5 )
+}
+
+/// Test if `point` is inside triangle mesh using its `winding_number`
+/// 
+/// `threshold`: absolute winding number above which `point` counts as inside,
+/// lower it for meshes with holes, raise it to ignore thin overlapping parts
+/// 
+/// Returns: `true` if `point` is inside
+/// 
+/// Returns: `String` error if `indices` length is **not** a multiple of **3**
+/// or an index is out of range
+pub fn point_inside_mesh_with_threshold(
+    point:&Vector3,
+    positions:&[Vector3],
+    indices:&[u32],
+    threshold:f32
+) -> Result<bool, String> {
+    Ok( winding_number( point, positions, indices )?.abs() > threshold )
+}
+
+/// Look up corners of every triangle in `indices`
+fn triangle_corners( positions:&[Vector3], indices:&[u32] ) -> Result<Vec<[Vector3;3]>, String> {
+    if !indices.len().is_multiple_of( 3 ) {
+        return Err(
+            format!(
+                "MESH ERROR: Index count must be a multiple of 3, got {}!",
+                indices.len()
+            )
+        );
+    }
+
+    let mut result = Vec::with_capacity( indices.len() / 3 );
+    for corners in indices.chunks_exact( 3 ) {
+        let mut triangle = [Vector3::new_zero();3];
+        for ( corner, index ) in triangle.iter_mut().zip( corners.iter() ) {
+            *corner = *positions.get( *index as usize ).ok_or_else( || {
+                format!(
+                    "MESH ERROR: Index {} is out of range for {} positions!",
+                    index, positions.len()
+                )
+            } )?;
+        }
+        result.push( triangle );
+    }
+    Ok( result )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// Unit cube with *counter-clockwise* front faces pointing outwards
+    fn cube() -> ( Vec<Vector3>, Vec<u32> ) {
+        let positions = ( 0..8 ).map( | i | {
+            Vector3::new( ( i & 1 ) as f32, ( ( i >> 1 ) & 1 ) as f32, ( ( i >> 2 ) & 1 ) as f32 )
+        } ).collect();
+        let indices = vec![
+            0, 2, 1,  1, 2, 3,
+            4, 5, 6,  5, 7, 6,
+            0, 1, 4,  1, 5, 4,
+            2, 6, 3,  3, 6, 7,
+            0, 4, 2,  2, 4, 6,
+            1, 3, 5,  3, 7, 5,
+        ];
+        ( positions, indices )
+    }
+
+    #[test]
+    fn winding_number_closed_cube() {
+        let ( positions, indices ) = cube();
+        let inside  = winding_number( &Vector3::new( 0.5, 0.5, 0.5 ), &positions, &indices ).unwrap();
+        let corner  = winding_number( &Vector3::new( 0.1, 0.9, 0.2 ), &positions, &indices ).unwrap();
+        let outside = winding_number( &Vector3::new( 2.0, 0.5, -1.0 ), &positions, &indices ).unwrap();
+        assert!( ( inside - 1.0 ).abs() < 1e-5 );
+        assert!( ( corner - 1.0 ).abs() < 1e-5 );
+        assert!( outside.abs() < 1e-5 );
+    }
+
+    #[test]
+    fn winding_number_flipped_cube() {
+        let ( positions, mut indices ) = cube();
+        for triangle in indices.chunks_exact_mut( 3 ) {
+            triangle.swap( 1, 2 );
+        }
+        let inside = winding_number( &Vector3::new( 0.5, 0.5, 0.5 ), &positions, &indices ).unwrap();
+        assert!( ( inside + 1.0 ).abs() < 1e-5 );
+        assert!( point_inside_mesh( &Vector3::new( 0.5, 0.5, 0.5 ), &positions, &indices ).unwrap() );
+    }
+
+    #[test]
+    fn winding_number_open_cube() {
+        let ( positions, indices ) = cube();
+        // remove +z face, center sees it as 1/6 of the sphere
+        let open = [ &indices[..6], &indices[12..] ].concat();
+        let center = winding_number( &Vector3::new( 0.5, 0.5, 0.5 ), &positions, &open ).unwrap();
+        assert!( ( center - ( 5.0 / 6.0 ) ).abs() < 1e-5 );
+        assert!( point_inside_mesh( &Vector3::new( 0.5, 0.5, 0.5 ), &positions, &open ).unwrap() );
+        assert!( !point_inside_mesh_with_threshold( &Vector3::new( 0.5, 0.5, 0.5 ), &positions, &open, 0.9 ).unwrap() );
+    }
+
+    #[test]
+    fn winding_number_invalid_indices() {
+        let ( positions, _ ) = cube();
+        assert!( winding_number( &Vector3::new_zero(), &positions, &[ 0, 1 ] ).is_err() );
+        assert!( winding_number( &Vector3::new_zero(), &positions, &[ 0, 1, 8 ] ).is_err() );
+    }
+
+}
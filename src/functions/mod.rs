@@ -1,5 +1,30 @@
 pub mod hexadecimal;
 pub mod angles;
+pub mod mapping;
+pub mod sampling;
+pub mod float;
+pub mod random;
+pub mod hash;
+pub mod viewport;
+pub mod deterministic;
+pub mod shapes;
+pub mod physics;
+pub mod eigen;
+pub mod polygon;
+pub mod billboard;
+pub mod summation;
+pub mod origin;
+pub mod quantize;
+pub mod addressing;
+pub mod grid;
+pub mod probe;
+pub mod batch;
+pub mod character;
+pub mod interpolation;
+pub mod mesh;
+pub mod pathfinding;
+pub mod poisson;
+pub mod convolution;
 
 use super::PI;
 
@@ -36,6 +61,7 @@ pub fn remap( min1:f32, max1:f32, min2:f32, max2:f32, v:f32 ) -> f32 {
 }
 
 /// Returns: the **largest** value in the given `array`
+#[allow(clippy::needless_return)]
 pub fn max<N>( values:&[N] ) -> N
 where N:PartialOrd + Copy
 {
@@ -52,6 +78,7 @@ where N:PartialOrd + Copy
 }
 
 /// Returns: the **smallest** value in the given `array`
+#[allow(clippy::needless_return)]
 pub fn min<N>( values:&[N] ) -> N
 where N:PartialOrd + Copy
 {
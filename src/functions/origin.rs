@@ -0,0 +1,22 @@
+use alloc::vec::Vec;
+
+use crate::types::{
+    Vector3, Vector3Large, Transform,
+};
+
+/// Move origin of every transform in `transforms` to `new_origin`
+/// 
+/// Translations become relative to `new_origin`, rotation and scale are kept
+pub fn rebase_origin( transforms:&mut [Transform], new_origin:Vector3 ) {
+    for transform in transforms.iter_mut() {
+        let translation = *transform.translation() - new_origin;
+        transform.set_translation( translation );
+    }
+}
+
+/// Move origin of every extended precision position in `positions` to `new_origin`
+/// 
+/// Returns: new `Vec` of `Vector3` relative to `new_origin`
+pub fn rebase_positions( positions:&[Vector3Large], new_origin:&Vector3Large ) -> Vec<Vector3> {
+    positions.iter().map( | position | position.relative_to( new_origin ) ).collect()
+}
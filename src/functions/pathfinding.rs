@@ -0,0 +1,202 @@
+use core::cmp::Ordering;
+use alloc::{
+    collections::{ BinaryHeap, BTreeMap },
+    vec::Vec,
+};
+
+use crate::math;
+
+/// Shortest path from `start` to `goal` with A*
+/// 
+/// `neighbors_fn`: returns every node reachable from a node with the cost of the step,
+/// costs must not be negative
+/// 
+/// `heuristic_fn`: estimated cost from a node to `goal`, must never overestimate
+/// for the path to be the shortest, see `manhattan_2d` `manhattan_3d` `euclidean_2d` `euclidean_3d`
+/// for integer grids
+/// 
+/// Nodes are expanded again when a cheaper path to them is found,
+/// so the heuristic does **not** need to be consistent
+/// 
+/// Returns: nodes from `start` to `goal` ( *both inclusive* ) and total cost,
+/// `None` if `goal` cannot be reached
+pub fn astar<N, F, I, H>(
+    start:N,
+    goal:N,
+    mut neighbors_fn:F,
+    mut heuristic_fn:H
+) -> Option<( Vec<N>, f32 )>
+where
+    N:Copy + Ord,
+    F:FnMut( &N ) -> I,
+    I:IntoIterator<Item = ( N, f32 )>,
+    H:FnMut( &N, &N ) -> f32,
+{
+    let mut frontier = BinaryHeap::new();
+    let mut costs:BTreeMap<N, f32> = BTreeMap::new();
+    let mut came_from:BTreeMap<N, N> = BTreeMap::new();
+    let mut expanded:BTreeMap<N, f32> = BTreeMap::new();
+
+    costs.insert( start, 0.0 );
+    frontier.push( MinCost { cost:heuristic_fn( &start, &goal ), node:start } );
+
+    while let Some( MinCost { node, .. } ) = frontier.pop() {
+        if node == goal {
+            let mut path = Vec::new();
+            path.push( goal );
+            let mut current = goal;
+            while let Some( previous ) = came_from.get( &current ) {
+                current = *previous;
+                path.push( current );
+            }
+            path.reverse();
+            return Some( ( path, costs[&goal] ) );
+        }
+        let cost = costs[&node];
+        // stale entry, node was already expanded with this cost
+        if expanded.insert( node, cost ).is_some_and( | previous | previous <= cost ) {
+            continue;
+        }
+
+        for ( neighbor, step ) in neighbors_fn( &node ) {
+            let next_cost = cost + step;
+            if costs.get( &neighbor ).is_none_or( | known | next_cost < *known ) {
+                costs.insert( neighbor, next_cost );
+                came_from.insert( neighbor, node );
+                frontier.push( MinCost {
+                    cost:next_cost + heuristic_fn( &neighbor, &goal ),
+                    node:neighbor
+                } );
+            }
+        }
+    }
+
+    None
+}
+
+/// Manhattan distance between two grid cells
+/// 
+/// Returns: sum of absolute differences, admissible for **4** neighbor grids with unit steps
+pub fn manhattan_2d( a:&[i32;2], b:&[i32;2] ) -> f32 {
+    a[0].abs_diff( b[0] ) as f32 + a[1].abs_diff( b[1] ) as f32
+}
+
+/// Manhattan distance between two voxel cells
+/// 
+/// Returns: sum of absolute differences, admissible for **6** neighbor grids with unit steps
+pub fn manhattan_3d( a:&[i32;3], b:&[i32;3] ) -> f32 {
+    a[0].abs_diff( b[0] ) as f32 + a[1].abs_diff( b[1] ) as f32 + a[2].abs_diff( b[2] ) as f32
+}
+
+/// Euclidean distance between two grid cells
+/// 
+/// Returns: straight line distance, admissible for any grid with euclidean step costs
+pub fn euclidean_2d( a:&[i32;2], b:&[i32;2] ) -> f32 {
+    let x = a[0].abs_diff( b[0] ) as f32;
+    let y = a[1].abs_diff( b[1] ) as f32;
+    math::sqrt( ( x * x ) + ( y * y ) )
+}
+
+/// Euclidean distance between two voxel cells
+/// 
+/// Returns: straight line distance, admissible for any grid with euclidean step costs
+pub fn euclidean_3d( a:&[i32;3], b:&[i32;3] ) -> f32 {
+    let x = a[0].abs_diff( b[0] ) as f32;
+    let y = a[1].abs_diff( b[1] ) as f32;
+    let z = a[2].abs_diff( b[2] ) as f32;
+    math::sqrt( ( x * x ) + ( y * y ) + ( z * z ) )
+}
+
+/// Min-heap entry ordered by `cost`
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct MinCost<N> {
+    pub(crate) cost:f32,
+    pub(crate) node:N,
+}
+
+impl<N> Eq for MinCost<N>
+where N:Ord {}
+
+impl<N> Ord for MinCost<N>
+where N:Ord
+{
+    fn cmp( &self, other:&Self ) -> Ordering {
+        other.cost.total_cmp( &self.cost ).then_with( || other.node.cmp( &self.node ) )
+    }
+}
+
+impl<N> PartialOrd for MinCost<N>
+where N:Ord
+{
+    fn partial_cmp( &self, other:&Self ) -> Option<Ordering> {
+        Some( self.cmp( other ) )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn grid_path_around_walls() {
+        const GRID:[&str;5] = [
+            ".....",
+            ".###.",
+            "...#.",
+            "##.#.",
+            ".....",
+        ];
+        let open = | x:i32, y:i32 | {
+            x >= 0 && y >= 0 && x < 5 && y < 5 && GRID[y as usize].as_bytes()[x as usize] == b'.'
+        };
+        let neighbors = | cell:&[i32;2] | {
+            let [ x, y ] = *cell;
+            [ [ x + 1, y ], [ x - 1, y ], [ x, y + 1 ], [ x, y - 1 ] ]
+                .into_iter()
+                .filter( | [ x, y ] | open( *x, *y ) )
+                .map( | next | ( next, 1.0 ) )
+                .collect::<Vec<_>>()
+        };
+
+        let ( path, cost ) = astar( [ 0, 0 ], [ 0, 4 ], neighbors, manhattan_2d ).unwrap();
+        assert_eq!( cost, 8.0 );
+        assert_eq!( path, [
+            [ 0, 0 ], [ 0, 1 ], [ 0, 2 ], [ 1, 2 ], [ 2, 2 ],
+            [ 2, 3 ], [ 2, 4 ], [ 1, 4 ], [ 0, 4 ],
+        ] );
+
+        assert!( astar( [ 0, 0 ], [ 1, 1 ], neighbors, manhattan_2d ).is_none() );
+    }
+
+    #[test]
+    fn inconsistent_heuristic_reopens_nodes() {
+        // 0 = start, 3 = goal
+        // 0 -1-> 1 -1-> 2 -3-> 3
+        // 0 -1-> 4 -3-> 2
+        let neighbors = | node:&u32 | -> Vec<( u32, f32 )> {
+            match node {
+                0 => vec![ ( 1, 1.0 ), ( 4, 1.0 ) ],
+                1 => vec![ ( 2, 1.0 ) ],
+                4 => vec![ ( 2, 3.0 ) ],
+                2 => vec![ ( 3, 3.0 ) ],
+                _ => Vec::new(),
+            }
+        };
+        // admissible, but 1 overestimates relative to 2 so 2 is first reached through 4
+        let heuristic = | node:&u32, _:&u32 | if *node == 1 { 4.0 } else { 0.0 };
+
+        let ( path, cost ) = astar( 0, 3, neighbors, heuristic ).unwrap();
+        assert_eq!( cost, 5.0 );
+        assert_eq!( path, [ 0, 1, 2, 3 ] );
+    }
+
+    #[test]
+    fn heuristics() {
+        assert_eq!( manhattan_2d( &[ 1, -2 ], &[ -2, 2 ] ), 7.0 );
+        assert_eq!( manhattan_3d( &[ 0, 0, 0 ], &[ 1, -2, 3 ] ), 6.0 );
+        assert_eq!( euclidean_2d( &[ 0, 0 ], &[ 3, 4 ] ), 5.0 );
+        assert_eq!( euclidean_3d( &[ 1, 1, 1 ], &[ 3, 4, 7 ] ), 7.0 );
+    }
+
+}
@@ -0,0 +1,52 @@
+use crate::types::Vector3;
+
+/// Center of mass of point masses
+/// 
+/// `points`: position and mass of each point
+/// 
+/// Returns: `Vector3` if total mass is larger than **0.0**
+/// 
+/// Returns: `None` if `points` is empty or total mass is **0.0**
+pub fn center_of_mass( points:&[( Vector3, f32 )] ) -> Option<Vector3> {
+    let mut total_mass = 0.0;
+    let mut weighted_sum = Vector3::new_zero();
+    for ( position, mass ) in points.iter() {
+        weighted_sum = weighted_sum + ( *position * *mass );
+        total_mass += mass;
+    }
+
+    if total_mass <= 0.0 {
+        return None;
+    }
+    Some( weighted_sum / total_mass )
+}
+
+/// *Torque* from `force` applied at `arm`
+/// 
+/// `arm`: vector from pivot to where `force` is applied ( *lever arm* )
+/// 
+/// Returns: `Vector3` with axis of rotation as direction and torque as magnitude
+pub fn torque( arm:&Vector3, force:&Vector3 ) -> Vector3 {
+    Vector3::cross( arm, force )
+}
+
+/// *Angular momentum* of point mass around pivot
+/// 
+/// `arm`: vector from pivot to point mass
+/// 
+/// Returns: `Vector3` with axis of rotation as direction and momentum as magnitude
+pub fn angular_momentum( arm:&Vector3, mass:f32, velocity:&Vector3 ) -> Vector3 {
+    Vector3::cross( arm, &( *velocity * mass ) )
+}
+
+/// *Perpendicular* lever arm of `force` applied at `point` around `pivot`
+/// 
+/// Returns: `Vector3` from `pivot` to the closest point on the line of action of `force`
+pub fn lever_arm( pivot:&Vector3, point:&Vector3, force:&Vector3 ) -> Vector3 {
+    let arm = *point - *pivot;
+    let force_sqr_magnitude = force.sqr_magnitude();
+    if force_sqr_magnitude == 0.0 {
+        return arm;
+    }
+    arm - ( *force * ( Vector3::dot( &arm, force ) / force_sqr_magnitude ) )
+}
@@ -0,0 +1,241 @@
+use alloc::{
+    format,
+    string::String,
+    vec::Vec,
+};
+
+use crate::functions::addressing::{
+    AddressMode,
+    wrap_texel,
+};
+
+/// Value of cells outside of a grid
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Boundary {
+    /// Cells outside have a fixed value ( *Dirichlet* )
+    Fixed( f32 ),
+    /// Cells outside copy the closest edge cell, zero gradient across the edge ( *Neumann* )
+    ClampToEdge,
+    /// Grid tiles, opposite edges are neighbors
+    Repeat,
+}
+
+/// Solve *Poisson's equation* ∇²u = `source` on `grid` in place with
+/// red-black *successive over-relaxation*
+/// 
+/// `grid`: initial guess, `width` * `height` values stored row by row
+/// 
+/// `source`: right-hand side, already multiplied by the squared cell size
+/// 
+/// `omega`: relaxation factor between **0.0** and **2.0** ( *exclusive* ),
+/// **1.0** is *Gauss-Seidel*, values around **1.9** converge fastest on large grids
+/// 
+/// Returns: largest change of a cell during the last iteration
+/// 
+/// Returns: `String` error if slices don't match `width` * `height` or `omega` is out of range
+pub fn solve_poisson(
+    grid:&mut [f32],
+    source:&[f32],
+    width:u32,
+    height:u32,
+    boundary:Boundary,
+    iterations:usize,
+    omega:f32
+) -> Result<f32, String> {
+    validate( grid.len(), width, height, omega )?;
+    validate_len( "source", source.len(), width, height )?;
+
+    Ok( relax( grid, width, height, boundary, iterations, omega, | index, neighbors | {
+        Some( ( neighbors - source[index] ) / 4.0 )
+    } ) )
+}
+
+/// Diffuse `grid` in place by one *implicit* step of the heat equation
+/// 
+/// Stable for any `rate`, solves ( 1 + 4 * `rate` ) * u - `rate` * Σneighbors = u₀
+/// with red-black *successive over-relaxation*
+/// 
+/// `rate`: diffusion coefficient * time step / squared cell size
+/// 
+/// `omega`: relaxation factor between **0.0** and **2.0** ( *exclusive* )
+/// 
+/// Returns: largest change of a cell during the last iteration
+/// 
+/// Returns: `String` error if `grid` doesn't match `width` * `height` or `omega` is out of range
+pub fn diffuse(
+    grid:&mut [f32],
+    width:u32,
+    height:u32,
+    rate:f32,
+    boundary:Boundary,
+    iterations:usize,
+    omega:f32
+) -> Result<f32, String> {
+    validate( grid.len(), width, height, omega )?;
+
+    let initial:Vec<f32> = grid.to_vec();
+    let denominator = 1.0 + ( 4.0 * rate );
+    Ok( relax( grid, width, height, boundary, iterations, omega, | index, neighbors | {
+        Some( ( initial[index] + ( rate * neighbors ) ) / denominator )
+    } ) )
+}
+
+/// Fill cells of `grid` that are not `known` with a smooth *harmonic* interpolation
+/// of the known cells ( *Laplace's equation* ), used for lightmap and texture dilation
+/// 
+/// Known cells are never changed, cells outside the grid copy the closest edge cell
+/// 
+/// `omega`: relaxation factor between **0.0** and **2.0** ( *exclusive* )
+/// 
+/// Returns: largest change of a cell during the last iteration
+/// 
+/// Returns: `String` error if slices don't match `width` * `height` or `omega` is out of range
+pub fn fill_laplace(
+    grid:&mut [f32],
+    known:&[bool],
+    width:u32,
+    height:u32,
+    iterations:usize,
+    omega:f32
+) -> Result<f32, String> {
+    validate( grid.len(), width, height, omega )?;
+    validate_len( "known", known.len(), width, height )?;
+
+    Ok( relax( grid, width, height, Boundary::ClampToEdge, iterations, omega, | index, neighbors | {
+        if known[index] { None } else { Some( neighbors / 4.0 ) }
+    } ) )
+}
+
+fn validate( len:usize, width:u32, height:u32, omega:f32 ) -> Result<(), String> {
+    validate_len( "grid", len, width, height )?;
+    if !( omega > 0.0 && omega < 2.0 ) {
+        return Err( format!("POISSON ERROR: Relaxation factor {} must be between 0.0 and 2.0!", omega) );
+    }
+    Ok(())
+}
+
+fn validate_len( name:&str, len:usize, width:u32, height:u32 ) -> Result<(), String> {
+    let expected = width as usize * height as usize;
+    if len != expected {
+        return Err( format!("POISSON ERROR: Expected {} values in {}, got {}!", expected, name, len) );
+    }
+    Ok(())
+}
+
+/// Red-black over-relaxation sweeps
+/// 
+/// `target_fn`: receives cell index and sum of its **4** neighbors,
+/// returns value the cell relaxes towards or `None` to keep the cell
+fn relax<F>(
+    grid:&mut [f32],
+    width:u32,
+    height:u32,
+    boundary:Boundary,
+    iterations:usize,
+    omega:f32,
+    target_fn:F
+) -> f32
+where F:Fn( usize, f32 ) -> Option<f32>
+{
+    let ( w, h ) = ( width as i32, height as i32 );
+    let neighbor = | grid:&[f32], x:i32, y:i32 | -> f32 {
+        let inside = x >= 0 && y >= 0 && x < w && y < h;
+        let ( x, y ) = match boundary {
+            Boundary::Fixed( value ) if !inside => return value,
+            Boundary::Fixed( _ ) => ( x as u32, y as u32 ),
+            Boundary::ClampToEdge => (
+                wrap_texel( x, width, AddressMode::ClampToEdge ),
+                wrap_texel( y, height, AddressMode::ClampToEdge ),
+            ),
+            Boundary::Repeat => (
+                wrap_texel( x, width, AddressMode::Repeat ),
+                wrap_texel( y, height, AddressMode::Repeat ),
+            ),
+        };
+        grid[ ( y as usize * width as usize ) + x as usize ]
+    };
+
+    let mut largest_change = 0.0;
+    for _ in 0..iterations {
+        largest_change = 0.0;
+        for parity in 0..2 {
+            for y in 0..h {
+                let mut x = ( y + parity ) % 2;
+                while x < w {
+                    let index = ( y as usize * width as usize ) + x as usize;
+                    let neighbors =
+                        neighbor( grid, x - 1, y ) + neighbor( grid, x + 1, y ) +
+                        neighbor( grid, x, y - 1 ) + neighbor( grid, x, y + 1 );
+                    if let Some( target ) = target_fn( index, neighbors ) {
+                        let change = omega * ( target - grid[index] );
+                        grid[index] += change;
+                        largest_change = change.abs().max( largest_change );
+                    }
+                    x += 2;
+                }
+            }
+        }
+    }
+    largest_change
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn poisson_residual_vanishes() {
+        let ( w, h ) = ( 8, 6 );
+        let mut source = vec![ 0.0; w * h ];
+        source[ ( 2 * w ) + 3 ] = 1.0;
+        source[ ( 4 * w ) + 6 ] = -2.0;
+        let mut grid = vec![ 0.0; w * h ];
+        let change = solve_poisson( &mut grid, &source, w as u32, h as u32, Boundary::Fixed( 0.5 ), 500, 1.7 ).unwrap();
+        assert!( change < 1e-5 );
+
+        let at = | x:isize, y:isize | {
+            if x < 0 || y < 0 || x >= w as isize || y >= h as isize { 0.5 } else { grid[ ( y as usize * w ) + x as usize ] }
+        };
+        for y in 0..h as isize {
+            for x in 0..w as isize {
+                let laplacian = at( x - 1, y ) + at( x + 1, y ) + at( x, y - 1 ) + at( x, y + 1 ) - ( 4.0 * at( x, y ) );
+                assert!( ( laplacian - source[ ( y as usize * w ) + x as usize ] ).abs() < 1e-4 );
+            }
+        }
+    }
+
+    #[test]
+    fn fill_laplace_interpolates_linearly() {
+        let mut grid = [ 0.0, 0.0, 0.0, 0.0, 4.0 ];
+        let known = [ true, false, false, false, true ];
+        fill_laplace( &mut grid, &known, 5, 1, 200, 1.5 ).unwrap();
+        for ( i, value ) in grid.iter().enumerate() {
+            assert!( ( value - i as f32 ).abs() < 1e-4 );
+        }
+        assert_eq!( grid[0], 0.0 );
+        assert_eq!( grid[4], 4.0 );
+    }
+
+    #[test]
+    fn diffuse_conserves_total_with_repeat() {
+        let mut grid = vec![ 0.0; 16 ];
+        grid[5] = 16.0;
+        diffuse( &mut grid, 4, 4, 0.5, Boundary::Repeat, 100, 1.2 ).unwrap();
+        let total:f32 = grid.iter().sum();
+        assert!( ( total - 16.0 ).abs() < 1e-3 );
+        assert!( grid[5] < 16.0 && grid[5] > grid[0] );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let mut grid = [ 0.0; 4 ];
+        assert!( solve_poisson( &mut grid, &[ 0.0; 3 ], 2, 2, Boundary::ClampToEdge, 1, 1.0 ).is_err() );
+        assert!( diffuse( &mut grid, 2, 2, 1.0, Boundary::ClampToEdge, 1, 2.0 ).is_err() );
+        assert!( diffuse( &mut grid, 3, 2, 1.0, Boundary::ClampToEdge, 1, 1.0 ).is_err() );
+        assert!( fill_laplace( &mut grid, &[ true; 2 ], 2, 2, 1, 1.0 ).is_err() );
+    }
+
+}
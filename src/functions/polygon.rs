@@ -0,0 +1,75 @@
+use crate::types::{
+    Vector2, Vector3,
+};
+
+/// Order in which polygon vertices are visited
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Normal of polygon loop using *Newell's* method
+/// 
+/// Stays stable for concave, non-planar and partially degenerate polygons,
+/// unlike the cross product of the first three vertices
+/// 
+/// Normal faces the side where `points` are *counter-clockwise*
+/// 
+/// Returns: normalized `Vector3`, **zero** if polygon has no area
+pub fn newell_normal( points:&[Vector3] ) -> Vector3 {
+    let mut normal = Vector3::new_zero();
+    for ( i, current ) in points.iter().enumerate() {
+        let next = &points[( i + 1 ) % points.len()];
+        normal[0] += ( current[1] - next[1] ) * ( current[2] + next[2] );
+        normal[1] += ( current[2] - next[2] ) * ( current[0] + next[0] );
+        normal[2] += ( current[0] - next[0] ) * ( current[1] + next[1] );
+    }
+
+    if normal.sqr_magnitude() == 0.0 {
+        return normal;
+    }
+    normal.normal()
+}
+
+/// Signed area of polygon loop ( *shoelace formula* )
+/// 
+/// Returns: positive area if `points` are *counter-clockwise*,
+/// negative area if `points` are *clockwise*
+pub fn signed_area_2d( points:&[Vector2] ) -> f32 {
+    let mut twice_area = 0.0;
+    for ( i, current ) in points.iter().enumerate() {
+        let next = &points[( i + 1 ) % points.len()];
+        twice_area += ( current[0] * next[1] ) - ( next[0] * current[1] );
+    }
+    twice_area / 2.0
+}
+
+/// Winding of polygon loop, assuming **+y** is up
+/// 
+/// Returns: `None` if polygon has no area
+pub fn polygon_winding_2d( points:&[Vector2] ) -> Option<Winding> {
+    let area = signed_area_2d( points );
+    if area > 0.0 {
+        Some( Winding::CounterClockwise )
+    } else if area < 0.0 {
+        Some( Winding::Clockwise )
+    } else {
+        None
+    }
+}
+
+/// Reverse `points` in place if their winding is not `winding`
+/// 
+/// Returns: `true` if `points` were reversed
+pub fn ensure_winding( points:&mut [Vector2], winding:Winding ) -> bool {
+    match polygon_winding_2d( points ) {
+        Some( current ) if current != winding => {
+            points.reverse();
+            true
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,31 @@
+use crate::types::Vector3;
+
+/// *Parallax corrected* cube map lookup direction for a box shaped reflection probe
+/// 
+/// `reflect_dir`: reflection direction in world space, does not need to be normalized
+/// 
+/// `position`: shaded position in world space, should be inside the box
+/// 
+/// `probe_pos`: capture position of the probe
+/// 
+/// `box_min`, `box_max`: probe influence box in world space
+/// 
+/// Returns: direction from `probe_pos` to where `reflect_dir` leaves the box,
+/// not normalized
+pub fn box_project_reflection(
+    reflect_dir:&Vector3,
+    position:&Vector3,
+    probe_pos:&Vector3,
+    box_min:&Vector3,
+    box_max:&Vector3
+) -> Vector3 {
+    // distance along the ray to the planes in front of it on each axis
+    let to_max = ( *box_max - *position ).zip_map( reflect_dir, | d, r | d / r );
+    let to_min = ( *box_min - *position ).zip_map( reflect_dir, | d, r | d / r );
+    let furthest = to_max.zip_map( &to_min, f32::max );
+
+    // ray exits through the nearest of those planes
+    let distance = furthest.fold( f32::INFINITY, f32::min );
+
+    ( *position + ( *reflect_dir * distance ) ) - *probe_pos
+}
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+
+use crate::{
+    math,
+    types::{
+        Vector3, Matrix4x4, Aabb,
+    },
+};
+
+const SNORM16_MAX:f32 = i16::MAX as f32;
+
+/// Quantize `positions` into *snorm16* triples relative to `bounds`
+/// 
+/// `bounds` should contain every position, positions outside are clamped
+/// 
+/// Returns: new `Vec` of *snorm16* triples, decode with `dequantize_position`
+/// or on the GPU with `dequantize_matrix`
+pub fn quantize_positions( positions:&[Vector3], bounds:&Aabb ) -> Vec<[i16;3]> {
+    let center = bounds.center();
+    let half_extents = bounds.half_extents();
+    positions.iter().map( | position | {
+        let mut result = [0i16;3];
+        for ( i, component ) in result.iter_mut().enumerate() {
+            if half_extents[i] <= 0.0 {
+                continue;
+            }
+            let normalized = ( ( position[i] - center[i] ) / half_extents[i] ).clamp( -1.0, 1.0 );
+            *component = math::round( normalized * SNORM16_MAX ) as i16;
+        }
+        result
+    } ).collect()
+}
+
+/// Decode *snorm16* triple produced by `quantize_positions`
+/// 
+/// Returns: new `Vector3` position
+pub fn dequantize_position( quantized:&[i16;3], bounds:&Aabb ) -> Vector3 {
+    let center = bounds.center();
+    let half_extents = bounds.half_extents();
+    Vector3::new(
+        center[0] + ( snorm16_to_f32( quantized[0] ) * half_extents[0] ),
+        center[1] + ( snorm16_to_f32( quantized[1] ) * half_extents[1] ),
+        center[2] + ( snorm16_to_f32( quantized[2] ) * half_extents[2] ),
+    )
+}
+
+/// Decode every *snorm16* triple produced by `quantize_positions`
+/// 
+/// Returns: new `Vec` of `Vector3` positions
+pub fn dequantize_positions( quantized:&[[i16;3]], bounds:&Aabb ) -> Vec<Vector3> {
+    quantized.iter().map( | q | dequantize_position( q, bounds ) ).collect()
+}
+
+/// Decode matrix for positions fetched as normalized *snorm16* on the GPU
+/// 
+/// Maps **-1.0**-**1.0** to `bounds`, multiply into the model matrix
+/// 
+/// Returns: new `Matrix4x4`
+pub fn dequantize_matrix( bounds:&Aabb ) -> Matrix4x4 {
+    Matrix4x4::new_translate( bounds.center().as_array() ) *
+    Matrix4x4::new_scale( bounds.half_extents().as_array() )
+}
+
+/// Convert *snorm16* to `f32` the way GPUs do
+fn snorm16_to_f32( value:i16 ) -> f32 {
+    ( value as f32 / SNORM16_MAX ).max( -1.0 )
+}
@@ -0,0 +1,42 @@
+use crate::types::Pcg32;
+
+/// Pick random item from `items` with probability proportional to its weight
+/// 
+/// Items with weight of **0.0** or less are never picked
+/// 
+/// Returns: `None` if `items` is empty or no weight is larger than **0.0**
+pub fn pick_weighted<'a, T>( items:&'a [( T, f32 )], rng:&mut Pcg32 ) -> Option<&'a T> {
+    let mut total = 0.0;
+    for ( _, weight ) in items.iter() {
+        total += weight.max( 0.0 );
+    }
+    if total <= 0.0 {
+        return None;
+    }
+
+    let mut target = rng.next_f32() * total;
+    let mut last = None;
+    for ( item, weight ) in items.iter() {
+        if *weight <= 0.0 {
+            continue;
+        }
+        if target < *weight {
+            return Some( item );
+        }
+        target -= weight;
+        last = Some( item );
+    }
+
+    // rounding error can leave target just above the last weight
+    last
+}
+
+/// Shuffle `items` in place ( *Fisher-Yates* )
+pub fn shuffle<T>( items:&mut [T], rng:&mut Pcg32 ) {
+    let mut i = items.len();
+    while i > 1 {
+        let j = rng.range_u32( 0..i as u32 ) as usize;
+        i -= 1;
+        items.swap( i, j );
+    }
+}
@@ -0,0 +1,196 @@
+use alloc::vec::Vec;
+
+use crate::{
+    PI,
+    math,
+    types::{
+        Vector2, Vector3,
+        Pcg32,
+    },
+};
+
+/// Returns: `index` element of the *Halton* sequence for given `base`
+/// 
+/// Values are between **0.0** and **1.0**
+/// 
+/// `base` should be a prime number, **2** and **3** are most common
+pub fn halton( index:u32, base:u32 ) -> f32 {
+    let mut result   = 0.0;
+    let mut fraction = 1.0;
+    let mut i = index;
+
+    while i > 0 {
+        fraction /= base as f32;
+        result   += fraction * ( i % base ) as f32;
+        i        /= base;
+    }
+
+    result
+}
+
+/// Sub-pixel offset for *temporal anti-aliasing*
+/// 
+/// Cycles through `sample_count` points of the *Halton( 2, 3 )* sequence
+/// 
+/// `resolution`: render target size in pixels
+/// 
+/// Returns: offset in *normalized device coordinates*,
+/// ready to be applied with `Matrix4x4::jitter`
+pub fn taa_jitter( frame_index:u32, sample_count:u32, resolution:&Vector2 ) -> Vector2 {
+    // skip first element of sequence, it's always 0.0
+    let index = ( frame_index % sample_count.max(1) ) + 1;
+
+    let pixel_offset = Vector2::new(
+        halton( index, 2 ) - 0.5,
+        halton( index, 3 ) - 0.5,
+    );
+
+    Vector2::new(
+        ( pixel_offset[0] * 2.0 ) / resolution[0],
+        ( pixel_offset[1] * 2.0 ) / resolution[1],
+    )
+}
+
+/// Sample direction on hemisphere around **+z** with probability proportional to *cos θ*
+/// 
+/// `u1`, `u2`: uniform random numbers between **0.0** and **1.0**
+/// 
+/// Use `Frame::to_world` to orient result around a surface normal
+/// 
+/// Returns: normalized `Vector3` in local space
+pub fn sample_hemisphere_cosine( u1:f32, u2:f32 ) -> Vector3 {
+    let radius = math::sqrt( u1 );
+    let ( sin, cos ) = math::sin_cos( 2.0 * PI * u2 );
+    Vector3::new(
+        radius * cos,
+        radius * sin,
+        math::sqrt( ( 1.0 - u1 ).max( 0.0 ) ),
+    )
+}
+
+/// Evenly distribute `n` points over the unit sphere using the *Fibonacci* lattice
+/// 
+/// Points spiral from **+z** to **-z**, useful for probe placement and kernel generation
+/// 
+/// Returns: `Vec` of normalized `Vector3`
+pub fn fibonacci_sphere( n:usize ) -> Vec<Vector3> {
+    // π * ( 3 - √5 )
+    const GOLDEN_ANGLE:f32 = 2.399_963_2;
+
+    let mut result = Vec::with_capacity( n );
+    for i in 0..n {
+        let z = 1.0 - ( ( ( i as f32 + 0.5 ) * 2.0 ) / n as f32 );
+        let radius = math::sqrt( ( 1.0 - ( z * z ) ).max( 0.0 ) );
+        let ( sin, cos ) = math::sin_cos( GOLDEN_ANGLE * i as f32 );
+        result.push( Vector3::new( radius * cos, radius * sin, z ) );
+    }
+    result
+}
+
+/// Sample direction uniformly inside a spherical cap around **+z**
+/// 
+/// `u1`, `u2`: uniform random numbers between **0.0** and **1.0**
+/// 
+/// `cos_theta_max`: cosine of the cap's half angle, **-1.0** covers the whole sphere
+/// 
+/// Returns: normalized `Vector3` in local space
+pub fn sample_spherical_cap( u1:f32, u2:f32, cos_theta_max:f32 ) -> Vector3 {
+    let z = 1.0 - ( u1 * ( 1.0 - cos_theta_max ) );
+    let radius = math::sqrt( ( 1.0 - ( z * z ) ).max( 0.0 ) );
+    let ( sin, cos ) = math::sin_cos( 2.0 * PI * u2 );
+    Vector3::new( radius * cos, radius * sin, z )
+}
+
+/// Probability density of `sample_spherical_cap`
+/// 
+/// Returns: density per steradian
+pub fn pdf_spherical_cap( cos_theta_max:f32 ) -> f32 {
+    1.0 / ( 2.0 * PI * ( 1.0 - cos_theta_max ) )
+}
+
+/// Stratified samples inside a spherical cap around **+z**
+/// 
+/// The cap is split into `strata_x` * `strata_y` cells of equal solid angle,
+/// each cell receives one sample jittered with `rng`
+/// 
+/// Returns: `Vec` of normalized `Vector3` in local space
+pub fn stratified_spherical_cap(
+    strata_x:usize,
+    strata_y:usize,
+    cos_theta_max:f32,
+    rng:&mut Pcg32
+) -> Vec<Vector3> {
+    let mut result = Vec::with_capacity( strata_x * strata_y );
+    for y in 0..strata_y {
+        for x in 0..strata_x {
+            let u1 = ( y as f32 + rng.next_f32() ) / strata_y as f32;
+            let u2 = ( x as f32 + rng.next_f32() ) / strata_x as f32;
+            result.push( sample_spherical_cap( u1, u2, cos_theta_max ) );
+        }
+    }
+    result
+}
+
+/// Probability density of `sample_hemisphere_cosine`
+/// 
+/// `cos_theta`: **z** component of local direction
+/// 
+/// Returns: density per steradian, **0.0** below the horizon
+pub fn pdf_hemisphere_cosine( cos_theta:f32 ) -> f32 {
+    cos_theta.max( 0.0 ) / PI
+}
+
+/// Sample microfacet normal around **+z** from *GGX* ( *Trowbridge-Reitz* ) distribution
+/// 
+/// `u1`, `u2`: uniform random numbers between **0.0** and **1.0**
+/// 
+/// `roughness`: perceptual roughness, *alpha* is `roughness * roughness`
+/// 
+/// Returns: normalized half vector `Vector3` in local space
+pub fn sample_ggx( u1:f32, u2:f32, roughness:f32 ) -> Vector3 {
+    let alpha = roughness * roughness;
+    let cos_theta_sqr = ( 1.0 - u1 ) / ( 1.0 + ( ( ( alpha * alpha ) - 1.0 ) * u1 ) );
+    let cos_theta = math::sqrt( cos_theta_sqr.clamp( 0.0, 1.0 ) );
+    let sin_theta = math::sqrt( ( 1.0 - ( cos_theta * cos_theta ) ).max( 0.0 ) );
+    let ( sin, cos ) = math::sin_cos( 2.0 * PI * u2 );
+    Vector3::new(
+        sin_theta * cos,
+        sin_theta * sin,
+        cos_theta,
+    )
+}
+
+/// *GGX* ( *Trowbridge-Reitz* ) normal distribution function
+/// 
+/// `cos_theta_h`: **z** component of local half vector
+/// 
+/// `roughness`: perceptual roughness, *alpha* is `roughness * roughness`
+pub fn ggx_distribution( cos_theta_h:f32, roughness:f32 ) -> f32 {
+    if cos_theta_h <= 0.0 {
+        return 0.0;
+    }
+    let alpha_sqr = roughness * roughness * roughness * roughness;
+    let denominator = ( ( cos_theta_h * cos_theta_h ) * ( alpha_sqr - 1.0 ) ) + 1.0;
+    alpha_sqr / ( PI * denominator * denominator )
+}
+
+/// Probability density of half vector returned by `sample_ggx`
+/// 
+/// Returns: density per steradian of half vector
+pub fn pdf_ggx( cos_theta_h:f32, roughness:f32 ) -> f32 {
+    ggx_distribution( cos_theta_h, roughness ) * cos_theta_h.max( 0.0 )
+}
+
+/// Probability density of direction reflected about half vector sampled by `sample_ggx`
+/// 
+/// `cos_theta_h`: **z** component of local half vector
+/// 
+/// `view_dot_half`: dot product of view direction and half vector
+/// 
+/// Returns: density per steradian of reflected direction
+pub fn pdf_ggx_reflected( cos_theta_h:f32, view_dot_half:f32, roughness:f32 ) -> f32 {
+    if view_dot_half <= 0.0 {
+        return 0.0;
+    }
+    pdf_ggx( cos_theta_h, roughness ) / ( 4.0 * view_dot_half )
+}
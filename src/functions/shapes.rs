@@ -0,0 +1,100 @@
+use alloc::vec::Vec;
+
+use crate::{
+    math,
+    PI,
+    FRAC_PI_2,
+    types::Vector2,
+};
+
+fn point_on_circle( center:&Vector2, radius:f32, angle:f32 ) -> Vector2 {
+    let ( sin, cos ) = math::sin_cos( angle );
+    Vector2::new( center[0] + ( cos * radius ), center[1] + ( sin * radius ) )
+}
+
+/// Points evenly spaced on a circle, counter-clockwise starting at **+x**
+/// 
+/// First point is **not** repeated at the end
+/// 
+/// Returns: `Vec` with `segments` points
+pub fn circle_points( center:&Vector2, radius:f32, segments:usize ) -> Vec<Vector2> {
+    let step = ( 2.0 * PI ) / segments as f32;
+    ( 0..segments )
+        .map( |i| point_on_circle( center, radius, i as f32 * step ) )
+        .collect()
+}
+
+/// Points evenly spaced on an arc from `start_angle` to `end_angle`
+/// 
+/// Angles are in **Radians**, arc goes clockwise if `end_angle` is less than `start_angle`
+/// 
+/// `segments` is at least **1**
+/// 
+/// Returns: `Vec` with `segments` + **1** points, including both end points
+pub fn arc_points(
+    center:&Vector2, radius:f32,
+    start_angle:f32, end_angle:f32, segments:usize
+) -> Vec<Vector2> {
+    let segments = segments.max( 1 );
+    let step = ( end_angle - start_angle ) / segments as f32;
+    ( 0..=segments )
+        .map( |i| point_on_circle( center, radius, start_angle + ( i as f32 * step ) ) )
+        .collect()
+}
+
+/// Points on an *Archimedean* spiral, counter-clockwise starting at **+x**
+/// 
+/// `spacing`: distance between consecutive turns
+/// 
+/// `turns`: number of full revolutions
+/// 
+/// Returns: `Vec` with `turns` x `segments_per_turn` + **1** points
+pub fn spiral_points(
+    center:&Vector2, start_radius:f32, spacing:f32,
+    turns:f32, segments_per_turn:usize
+) -> Vec<Vector2> {
+    let count = ( turns.max( 0.0 ) * segments_per_turn as f32 ) as usize;
+    let step  = ( 2.0 * PI ) / segments_per_turn as f32;
+    ( 0..=count )
+        .map( |i| {
+            let angle  = i as f32 * step;
+            let radius = start_radius + ( spacing * angle / ( 2.0 * PI ) );
+            point_on_circle( center, radius, angle )
+        } )
+        .collect()
+}
+
+/// Outline of a rectangle with rounded corners, counter-clockwise
+/// starting at the end of the bottom-right corner
+/// 
+/// `corner_radius` is clamped to half of the smaller side,
+/// `corner_segments` is at least **1**
+/// 
+/// Returns: `Vec` with **4** x ( `corner_segments` + **1** ) points
+pub fn rounded_rectangle_points(
+    center:&Vector2, size:&Vector2, corner_radius:f32, corner_segments:usize
+) -> Vec<Vector2> {
+    let half_width  = size[0] / 2.0;
+    let half_height = size[1] / 2.0;
+    let radius = corner_radius.clamp( 0.0, f32::min( half_width, half_height ) );
+
+    let inner_x = half_width  - radius;
+    let inner_y = half_height - radius;
+
+    // corner centers counter-clockwise, starting at bottom right
+    let corners = [
+        Vector2::new( center[0] + inner_x, center[1] - inner_y ),
+        Vector2::new( center[0] + inner_x, center[1] + inner_y ),
+        Vector2::new( center[0] - inner_x, center[1] + inner_y ),
+        Vector2::new( center[0] - inner_x, center[1] - inner_y ),
+    ];
+
+    let mut result = Vec::with_capacity( 4 * ( corner_segments + 1 ) );
+    for ( i, corner ) in corners.iter().enumerate() {
+        let start_angle = ( i as f32 * FRAC_PI_2 ) - FRAC_PI_2;
+        result.extend( arc_points(
+            corner, radius, start_angle, start_angle + FRAC_PI_2, corner_segments
+        ) );
+    }
+    result
+}
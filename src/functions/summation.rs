@@ -0,0 +1,40 @@
+use crate::types::KahanAccumulator;
+
+/// Sum `values` with *Kahan* compensated summation
+/// 
+/// Returns: `f32` sum, far more accurate than a naive loop for long slices
+pub fn kahan_sum( values:&[f32] ) -> f32 {
+    values.iter().copied().collect::<KahanAccumulator>().sum()
+}
+
+/// **Dot** product of `a` and `b` with compensated summation of the products
+/// 
+/// Only the first `min( a.len(), b.len() )` components are used
+/// 
+/// Returns: `f32`
+pub fn dot_compensated( a:&[f32], b:&[f32] ) -> f32 {
+    a.iter().zip( b.iter() ).map( | ( a, b ) | a * b ).collect::<KahanAccumulator>().sum()
+}
+
+/// Sum `values` accumulating in `f64`
+/// 
+/// Returns: `f64` sum
+pub fn sum_f64( values:&[f32] ) -> f64 {
+    values.iter().map( | value | *value as f64 ).sum()
+}
+
+/// **Dot** product of `a` and `b` accumulating in `f64`
+/// 
+/// Only the first `min( a.len(), b.len() )` components are used
+/// 
+/// Returns: `f64`
+pub fn dot_f64( a:&[f32], b:&[f32] ) -> f64 {
+    a.iter().zip( b.iter() ).map( | ( a, b ) | ( *a as f64 ) * ( *b as f64 ) ).sum()
+}
+
+/// Squared magnitude of `values` accumulating in `f64`
+/// 
+/// Returns: `f64`
+pub fn sqr_magnitude_f64( values:&[f32] ) -> f64 {
+    dot_f64( values, values )
+}
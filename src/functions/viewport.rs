@@ -0,0 +1,71 @@
+use crate::types::{
+    Vector2, Matrix4x4,
+};
+
+/// Scale and offset that fit `virtual_resolution` inside `window`
+/// while keeping its aspect ratio ( *letterbox/pillarbox* )
+/// 
+/// Returns: `scale` and `offset` in window pixels
+pub fn fit_aspect( virtual_resolution:&Vector2, window:&Vector2 ) -> ( Vector2, Vector2 ) {
+    let scale = f32::min(
+        window[0] / virtual_resolution[0],
+        window[1] / virtual_resolution[1],
+    );
+    uniform_scale_offset( virtual_resolution, window, scale )
+}
+
+/// Scale and offset that fill `window` with `virtual_resolution`
+/// while keeping its aspect ratio, cropping the overflow
+/// 
+/// Returns: `scale` and `offset` in window pixels, `offset` can be negative
+pub fn fill_aspect( virtual_resolution:&Vector2, window:&Vector2 ) -> ( Vector2, Vector2 ) {
+    let scale = f32::max(
+        window[0] / virtual_resolution[0],
+        window[1] / virtual_resolution[1],
+    );
+    uniform_scale_offset( virtual_resolution, window, scale )
+}
+
+/// Scale and offset that stretch `virtual_resolution` over `window`
+/// ignoring its aspect ratio
+/// 
+/// Returns: `scale` and `offset` in window pixels, `offset` is always **0.0**
+pub fn stretch_aspect( virtual_resolution:&Vector2, window:&Vector2 ) -> ( Vector2, Vector2 ) {
+    (
+        Vector2::new(
+            window[0] / virtual_resolution[0],
+            window[1] / virtual_resolution[1],
+        ),
+        Vector2::new_zero()
+    )
+}
+
+fn uniform_scale_offset(
+    virtual_resolution:&Vector2, window:&Vector2, scale:f32
+) -> ( Vector2, Vector2 ) {
+    let scaled = *virtual_resolution * scale;
+    ( Vector2::new( scale, scale ), ( *window - scaled ) / 2.0 )
+}
+
+/// Map `point` in window pixels ( *e.g. mouse position* ) back into virtual resolution
+/// 
+/// `scale` and `offset` come from `fit_aspect`, `fill_aspect` or `stretch_aspect`
+pub fn window_to_virtual( point:&Vector2, scale:&Vector2, offset:&Vector2 ) -> Vector2 {
+    Vector2::new(
+        ( point[0] - offset[0] ) / scale[0],
+        ( point[1] - offset[1] ) / scale[1],
+    )
+}
+
+/// Map `point` in virtual resolution into window pixels
+/// 
+/// `scale` and `offset` come from `fit_aspect`, `fill_aspect` or `stretch_aspect`
+pub fn virtual_to_window( point:&Vector2, scale:&Vector2, offset:&Vector2 ) -> Vector2 {
+    Vector2::scale( point, scale ) + *offset
+}
+
+/// Returns: `Matrix4x4` that applies `scale` then `offset`
+pub fn viewport_matrix( scale:&Vector2, offset:&Vector2 ) -> Matrix4x4 {
+    Matrix4x4::new_translate( &[ offset[0], offset[1], 0.0 ] ) *
+    Matrix4x4::new_scale( &[ scale[0], scale[1], 1.0 ] )
+}
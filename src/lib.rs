@@ -2,15 +2,38 @@
 //! 
 //! Provides various math-related types and functions that are relevant to my projects
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)]
 /// Vectors, Matrix4x4, colors
 /// 
-/// - `Vector2` `Vector3` `Vector4`
-/// - `Matrix4x4`
+/// - `Vector2` `Vector3` `Vector4` `VectorN`
+/// - `Vector2Bool` `Vector3Bool` `Vector4Bool`
+/// - `Matrix3x3` `Matrix4x4`
 /// - Colors
 ///     - `RGB`
 ///     - `HSV`
-/// - `Quaternion` ***work in progress***
+/// - `Quaternion`
+/// - `UnitQuaternion`
+/// - `Transform`, `TransformHierarchy` and `TransformTrack`
+/// - `UnitScale`
+/// - `Pcg32` random number generator and `AliasTable`
+/// - `Endianness` and `MatrixLayout` for byte conversions
+/// - `MathWriter` and `MathReader` byte streams
+/// - `Interval`
+/// - `KahanAccumulator`
+/// - `TwoF32` `TwoF64` extended precision scalars and `Vector3Large`
+/// - `Aabb` and `Aabb2d`
+/// - `SummedAreaTable`
+/// - `FlowField`
+/// - `Plane`
+/// - `Ray` and `Ray2d`
+/// - `Frustum`
+/// - `Frame`
+/// - `FixedTimestep`
+/// - `BezierPatch`
+/// - `OrbitCamera` and `FlyCamera`
+/// - `UvChart` and `AtlasLayout`
+/// - `MeshAdjacency`
 pub mod types;
 
 /// Various helper functions
@@ -23,8 +46,81 @@ pub mod types;
 ///     - degrees -> radians
 ///     - radians -> degrees
 ///     - degree overflow ( wrap value between 0.0-360.0 )
+/// - mapping
+///     - cubemap direction <-> face and uv
+///     - equirectangular direction <-> uv
+///     - octahedral direction <-> uv
+///     - stereographic and azimuthal equidistant projections
+///     - per-texel directions and solid angles of equirectangular and octahedral maps
+/// - addressing
+///     - repeat, mirrored repeat and clamp to edge for uvs and texels
+/// - grid
+///     - bilinear and bicubic sampling of scalar and color grids
+/// - probe
+///     - parallax corrected reflection probe lookups
+/// - batch
+///     - point transforms and skinning palettes, parallel with the `rayon` feature
+/// - character
+///     - velocity sliding and iterative move and slide
+/// - interpolation
+///     - `Lerp` trait implemented for scalars, vectors, rotations, matrices, colors and `Transform`
+/// - mesh
+///     - area weighted surface sampling of triangle meshes
+///     - generalized winding number and point inside mesh test
+/// - pathfinding
+///     - A* over caller supplied neighbors with manhattan and euclidean grid heuristics
+/// - poisson
+///     - Poisson, implicit diffusion and Laplace fill solvers on scalar grids
+/// - convolution
+///     - separable, gaussian and Sobel convolution of scalar and color grids
+///     - normal maps from height maps
+/// - sampling
+///     - halton sequence
+///     - temporal anti-aliasing jitter
+///     - cosine weighted hemisphere and GGX sampling with pdfs
+/// - float
+///     - next after, ulp distance
+///     - sortable bit representation
+/// - random
+///     - weighted picking and shuffling
+/// - hash
+///     - stable integer hashes for procedural generation
+/// - viewport
+///     - fit, fill and stretch aspect ratio
+/// - shapes
+///     - circle, arc, spiral and rounded rectangle points
+/// - physics
+///     - center of mass, torque and angular momentum
+/// - eigen
+///     - symmetric 3x3 eigen decomposition
+/// - polygon
+///     - Newell normal, signed area and winding
+/// - billboard
+///     - batched spherical and cylindrical billboard orientation
+/// - summation
+///     - compensated sum and dot product, f64 accumulated reductions
+/// - origin
+///     - origin rebasing for camera relative rendering
+/// - quantize
+///     - snorm16 position quantization with decode matrix
+/// - deterministic
+///     - table based sine and cosine
 pub mod functions;
 
+/// Determinism checks for lockstep simulations
+/// 
+/// - `checksum` of fixed quaternion, matrix and vector operations
+/// - `verify` against `EXPECTED_CHECKSUM`
+pub mod conformance;
+
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!( "fmath without the std feature requires the libm feature" );
+
+mod math;
+mod simd;
+
 /// π
 pub use core::f32::consts::PI;
 /// π / 2.0
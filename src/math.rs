@@ -0,0 +1,51 @@
+//! Float functions that are not available in `core`
+//! 
+//! Routed through the `libm` crate when the `libm` feature is enabled,
+//! otherwise through `std`
+
+#[cfg(feature = "libm")]
+mod backend {
+    pub fn sin( x:f32 ) -> f32 { libm::sinf( x ) }
+    pub fn cos( x:f32 ) -> f32 { libm::cosf( x ) }
+    pub fn tan( x:f32 ) -> f32 { libm::tanf( x ) }
+    pub fn sin_cos( x:f32 ) -> ( f32, f32 ) { libm::sincosf( x ) }
+    pub fn asin( x:f32 ) -> f32 { libm::asinf( x ) }
+    pub fn acos( x:f32 ) -> f32 { libm::acosf( x ) }
+    pub fn atan2( y:f32, x:f32 ) -> f32 { libm::atan2f( y, x ) }
+    pub fn sqrt( x:f32 ) -> f32 { libm::sqrtf( x ) }
+    pub fn cbrt( x:f32 ) -> f32 { libm::cbrtf( x ) }
+    pub fn powf( x:f32, n:f32 ) -> f32 { libm::powf( x, n ) }
+    pub fn floor( x:f32 ) -> f32 { libm::floorf( x ) }
+    pub fn ceil( x:f32 ) -> f32 { libm::ceilf( x ) }
+    pub fn round( x:f32 ) -> f32 { libm::roundf( x ) }
+    pub fn trunc( x:f32 ) -> f32 { libm::truncf( x ) }
+    pub fn exp( x:f32 ) -> f32 { libm::expf( x ) }
+    pub fn ln( x:f32 ) -> f32 { libm::logf( x ) }
+    pub fn rem_euclid( x:f32, rhs:f32 ) -> f32 {
+        let r = libm::fmodf( x, rhs );
+        if r < 0.0 { r + rhs.abs() } else { r }
+    }
+}
+
+#[cfg(not(feature = "libm"))]
+mod backend {
+    pub fn sin( x:f32 ) -> f32 { x.sin() }
+    pub fn cos( x:f32 ) -> f32 { x.cos() }
+    pub fn tan( x:f32 ) -> f32 { x.tan() }
+    pub fn sin_cos( x:f32 ) -> ( f32, f32 ) { x.sin_cos() }
+    pub fn asin( x:f32 ) -> f32 { x.asin() }
+    pub fn acos( x:f32 ) -> f32 { x.acos() }
+    pub fn atan2( y:f32, x:f32 ) -> f32 { y.atan2( x ) }
+    pub fn sqrt( x:f32 ) -> f32 { x.sqrt() }
+    pub fn cbrt( x:f32 ) -> f32 { x.cbrt() }
+    pub fn powf( x:f32, n:f32 ) -> f32 { x.powf( n ) }
+    pub fn floor( x:f32 ) -> f32 { x.floor() }
+    pub fn ceil( x:f32 ) -> f32 { x.ceil() }
+    pub fn round( x:f32 ) -> f32 { x.round() }
+    pub fn trunc( x:f32 ) -> f32 { x.trunc() }
+    pub fn exp( x:f32 ) -> f32 { x.exp() }
+    pub fn ln( x:f32 ) -> f32 { x.ln() }
+    pub fn rem_euclid( x:f32, rhs:f32 ) -> f32 { x.rem_euclid( rhs ) }
+}
+
+pub(crate) use backend::*;
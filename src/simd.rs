@@ -0,0 +1,155 @@
+//! Matrix4x4 and Vector4 kernels
+//! 
+//! Routed through `core::arch` intrinsics when the `simd` feature is enabled
+//! on *x86_64* ( *SSE* ) or *aarch64* ( *NEON* ), otherwise plain scalar code
+//! 
+//! Matrices are column-major `[f32;16]`
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod backend {
+    use core::arch::x86_64::*;
+
+    pub fn mul_matrix4x4( a:&[f32;16], b:&[f32;16] ) -> [f32;16] {
+        let mut result = [0.0;16];
+        // SAFETY: SSE is part of the x86_64 baseline, loads and stores are unaligned
+        // and stay within the 16 element arrays
+        unsafe {
+            let columns = [
+                _mm_loadu_ps( a.as_ptr() ),
+                _mm_loadu_ps( a.as_ptr().add( 4 ) ),
+                _mm_loadu_ps( a.as_ptr().add( 8 ) ),
+                _mm_loadu_ps( a.as_ptr().add( 12 ) ),
+            ];
+            for column in 0..4 {
+                let b = &b[( column * 4 )..( column * 4 ) + 4];
+                let sum = _mm_add_ps(
+                    _mm_add_ps(
+                        _mm_mul_ps( columns[0], _mm_set1_ps( b[0] ) ),
+                        _mm_mul_ps( columns[1], _mm_set1_ps( b[1] ) ),
+                    ),
+                    _mm_add_ps(
+                        _mm_mul_ps( columns[2], _mm_set1_ps( b[2] ) ),
+                        _mm_mul_ps( columns[3], _mm_set1_ps( b[3] ) ),
+                    ),
+                );
+                _mm_storeu_ps( result.as_mut_ptr().add( column * 4 ), sum );
+            }
+        }
+        result
+    }
+
+    pub fn mul_vector4( m:&[f32;16], v:&[f32;4] ) -> [f32;4] {
+        let mut result = [0.0;4];
+        // SAFETY: see mul_matrix4x4
+        unsafe {
+            let sum = _mm_add_ps(
+                _mm_add_ps(
+                    _mm_mul_ps( _mm_loadu_ps( m.as_ptr() ),          _mm_set1_ps( v[0] ) ),
+                    _mm_mul_ps( _mm_loadu_ps( m.as_ptr().add( 4 ) ), _mm_set1_ps( v[1] ) ),
+                ),
+                _mm_add_ps(
+                    _mm_mul_ps( _mm_loadu_ps( m.as_ptr().add( 8 ) ),  _mm_set1_ps( v[2] ) ),
+                    _mm_mul_ps( _mm_loadu_ps( m.as_ptr().add( 12 ) ), _mm_set1_ps( v[3] ) ),
+                ),
+            );
+            _mm_storeu_ps( result.as_mut_ptr(), sum );
+        }
+        result
+    }
+
+    pub fn dot4( a:&[f32;4], b:&[f32;4] ) -> f32 {
+        // SAFETY: see mul_matrix4x4
+        unsafe {
+            let product = _mm_mul_ps( _mm_loadu_ps( a.as_ptr() ), _mm_loadu_ps( b.as_ptr() ) );
+            // ( x + z, y + w, .. ) then add the two halves
+            let pairs = _mm_add_ps( product, _mm_movehl_ps( product, product ) );
+            let sum   = _mm_add_ss( pairs, _mm_shuffle_ps( pairs, pairs, 0b01 ) );
+            _mm_cvtss_f32( sum )
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+mod backend {
+    use core::arch::aarch64::*;
+
+    pub fn mul_matrix4x4( a:&[f32;16], b:&[f32;16] ) -> [f32;16] {
+        let mut result = [0.0;16];
+        // SAFETY: NEON is part of the aarch64 baseline, loads and stores
+        // stay within the 16 element arrays
+        unsafe {
+            let columns = [
+                vld1q_f32( a.as_ptr() ),
+                vld1q_f32( a.as_ptr().add( 4 ) ),
+                vld1q_f32( a.as_ptr().add( 8 ) ),
+                vld1q_f32( a.as_ptr().add( 12 ) ),
+            ];
+            for column in 0..4 {
+                let b = &b[( column * 4 )..( column * 4 ) + 4];
+                let sum = vaddq_f32(
+                    vaddq_f32( vmulq_n_f32( columns[0], b[0] ), vmulq_n_f32( columns[1], b[1] ) ),
+                    vaddq_f32( vmulq_n_f32( columns[2], b[2] ), vmulq_n_f32( columns[3], b[3] ) ),
+                );
+                vst1q_f32( result.as_mut_ptr().add( column * 4 ), sum );
+            }
+        }
+        result
+    }
+
+    pub fn mul_vector4( m:&[f32;16], v:&[f32;4] ) -> [f32;4] {
+        let mut result = [0.0;4];
+        // SAFETY: see mul_matrix4x4
+        unsafe {
+            let sum = vaddq_f32(
+                vaddq_f32(
+                    vmulq_n_f32( vld1q_f32( m.as_ptr() ),          v[0] ),
+                    vmulq_n_f32( vld1q_f32( m.as_ptr().add( 4 ) ), v[1] ),
+                ),
+                vaddq_f32(
+                    vmulq_n_f32( vld1q_f32( m.as_ptr().add( 8 ) ),  v[2] ),
+                    vmulq_n_f32( vld1q_f32( m.as_ptr().add( 12 ) ), v[3] ),
+                ),
+            );
+            vst1q_f32( result.as_mut_ptr(), sum );
+        }
+        result
+    }
+
+    pub fn dot4( a:&[f32;4], b:&[f32;4] ) -> f32 {
+        // SAFETY: see mul_matrix4x4
+        unsafe {
+            vaddvq_f32( vmulq_f32( vld1q_f32( a.as_ptr() ), vld1q_f32( b.as_ptr() ) ) )
+        }
+    }
+}
+
+#[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+mod backend {
+    pub fn mul_matrix4x4( a:&[f32;16], b:&[f32;16] ) -> [f32;16] {
+        let mut result = [0.0;16];
+        for ( column, result ) in result.chunks_exact_mut( 4 ).enumerate() {
+            let b = &b[( column * 4 )..( column * 4 ) + 4];
+            for ( row, cell ) in result.iter_mut().enumerate() {
+                *cell =
+                    ( a[row] * b[0] ) + ( a[row + 4] * b[1] ) +
+                    ( a[row + 8] * b[2] ) + ( a[row + 12] * b[3] );
+            }
+        }
+        result
+    }
+
+    pub fn mul_vector4( m:&[f32;16], v:&[f32;4] ) -> [f32;4] {
+        [
+            ( m[0] * v[0] ) + ( m[4] * v[1] ) + ( m[8]  * v[2] ) + ( m[12] * v[3] ),
+            ( m[1] * v[0] ) + ( m[5] * v[1] ) + ( m[9]  * v[2] ) + ( m[13] * v[3] ),
+            ( m[2] * v[0] ) + ( m[6] * v[1] ) + ( m[10] * v[2] ) + ( m[14] * v[3] ),
+            ( m[3] * v[0] ) + ( m[7] * v[1] ) + ( m[11] * v[2] ) + ( m[15] * v[3] ),
+        ]
+    }
+
+    pub fn dot4( a:&[f32;4], b:&[f32;4] ) -> f32 {
+        ( a[0] * b[0] ) + ( a[1] * b[1] ) + ( a[2] * b[2] ) + ( a[3] * b[3] )
+    }
+}
+
+pub(crate) use backend::*;
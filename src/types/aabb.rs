@@ -0,0 +1,111 @@
+use core::fmt::Display;
+
+use super::Vector3;
+
+/// Axis-aligned bounding box between `min` and `max`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Aabb {
+    min:Vector3,
+    max:Vector3,
+}
+
+impl Aabb {
+
+    /// Create new `Aabb` that contains both `a` and `b`
+    /// 
+    /// Components are sorted so that `min` is never larger than `max`
+    pub fn new( a:Vector3, b:Vector3 ) -> Self {
+        Self {
+            min:a.zip_map( &b, | a, b | a.min( b ) ),
+            max:a.zip_map( &b, | a, b | a.max( b ) ),
+        }
+    }
+
+    /// Create smallest `Aabb` that contains all `points`
+    /// 
+    /// Returns: `None` if `points` is empty
+    pub fn from_points( points:&[Vector3] ) -> Option<Self> {
+        let first = points.first()?;
+        let mut result = Self { min:*first, max:*first };
+        for point in points.iter().skip( 1 ) {
+            result.min = result.min.zip_map( point, | a, b | a.min( b ) );
+            result.max = result.max.zip_map( point, | a, b | a.max( b ) );
+        }
+        Some( result )
+    }
+
+    /// Returns: `reference` to smallest corner
+    pub fn min(&self) -> &Vector3 {
+        &self.min
+    }
+
+    /// Returns: `reference` to largest corner
+    pub fn max(&self) -> &Vector3 {
+        &self.max
+    }
+
+    /// Returns: point halfway between `min` and `max`
+    pub fn center(&self) -> Vector3 {
+        ( self.min + self.max ) / 2.0
+    }
+
+    /// Returns: distance between `min` and `max` on each axis
+    pub fn size(&self) -> Vector3 {
+        self.max - self.min
+    }
+
+    /// Returns: half of `size`
+    pub fn half_extents(&self) -> Vector3 {
+        self.size() / 2.0
+    }
+
+    /// Returns: `true` if `point` is inside box ( *inclusive* )
+    pub fn contains( &self, point:&Vector3 ) -> bool {
+        point.cmp_ge( &self.min ).all() && point.cmp_le( &self.max ).all()
+    }
+
+    /// Returns: `true` if boxes overlap or touch
+    pub fn intersects( &self, other:&Self ) -> bool {
+        self.min.cmp_le( &other.max ).all() && other.min.cmp_le( &self.max ).all()
+    }
+
+    /// Returns: point inside box closest to `point`
+    pub fn closest_point( &self, point:&Vector3 ) -> Vector3 {
+        point.zip_map( &self.min, f32::max ).zip_map( &self.max, f32::min )
+    }
+
+    /// Returns: distance from `point` to box, **0.0** if `point` is inside
+    pub fn distance( &self, point:&Vector3 ) -> f32 {
+        ( self.closest_point( point ) - *point ).magnitude()
+    }
+
+    /// Grow box so that it contains `point`
+    pub fn encapsulate( &mut self, point:&Vector3 ) {
+        self.min = self.min.zip_map( point, f32::min );
+        self.max = self.max.zip_map( point, f32::max );
+    }
+
+    /// Grow box so that it contains `other`
+    pub fn encapsulate_aabb( &mut self, other:&Self ) {
+        self.encapsulate( &other.min );
+        self.encapsulate( &other.max );
+    }
+
+    /// Move every side of box outwards by `amount`, negative `amount` shrinks box
+    /// 
+    /// Shrinking never moves `min` past `max`, sides meet at `center` instead
+    pub fn expand( &mut self, amount:f32 ) {
+        let center = self.center();
+        self.min = ( self.min - Vector3::new( amount, amount, amount ) ).zip_map( &center, f32::min );
+        self.max = ( self.max + Vector3::new( amount, amount, amount ) ).zip_map( &center, f32::max );
+    }
+
+}
+
+impl Display for Aabb {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Aabb: min: {}, max: {}", self.min, self.max )
+    }
+}
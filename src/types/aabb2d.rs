@@ -0,0 +1,111 @@
+use core::fmt::Display;
+
+use super::Vector2;
+
+/// 2D axis-aligned bounding rectangle between `min` and `max`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Aabb2d {
+    min:Vector2,
+    max:Vector2,
+}
+
+impl Aabb2d {
+
+    /// Create new `Aabb2d` that contains both `a` and `b`
+    /// 
+    /// Components are sorted so that `min` is never larger than `max`
+    pub fn new( a:Vector2, b:Vector2 ) -> Self {
+        Self {
+            min:a.zip_map( &b, | a, b | a.min( b ) ),
+            max:a.zip_map( &b, | a, b | a.max( b ) ),
+        }
+    }
+
+    /// Create smallest `Aabb2d` that contains all `points`
+    /// 
+    /// Returns: `None` if `points` is empty
+    pub fn from_points( points:&[Vector2] ) -> Option<Self> {
+        let first = points.first()?;
+        let mut result = Self { min:*first, max:*first };
+        for point in points.iter().skip( 1 ) {
+            result.min = result.min.zip_map( point, | a, b | a.min( b ) );
+            result.max = result.max.zip_map( point, | a, b | a.max( b ) );
+        }
+        Some( result )
+    }
+
+    /// Returns: `reference` to smallest corner
+    pub fn min(&self) -> &Vector2 {
+        &self.min
+    }
+
+    /// Returns: `reference` to largest corner
+    pub fn max(&self) -> &Vector2 {
+        &self.max
+    }
+
+    /// Returns: point halfway between `min` and `max`
+    pub fn center(&self) -> Vector2 {
+        ( self.min + self.max ) / 2.0
+    }
+
+    /// Returns: distance between `min` and `max` on each axis
+    pub fn size(&self) -> Vector2 {
+        self.max - self.min
+    }
+
+    /// Returns: half of `size`
+    pub fn half_extents(&self) -> Vector2 {
+        self.size() / 2.0
+    }
+
+    /// Returns: `true` if `point` is inside rectangle ( *inclusive* )
+    pub fn contains( &self, point:&Vector2 ) -> bool {
+        point.cmp_ge( &self.min ).all() && point.cmp_le( &self.max ).all()
+    }
+
+    /// Returns: `true` if rectangles overlap or touch
+    pub fn intersects( &self, other:&Self ) -> bool {
+        self.min.cmp_le( &other.max ).all() && other.min.cmp_le( &self.max ).all()
+    }
+
+    /// Returns: point inside rectangle closest to `point`
+    pub fn closest_point( &self, point:&Vector2 ) -> Vector2 {
+        point.zip_map( &self.min, f32::max ).zip_map( &self.max, f32::min )
+    }
+
+    /// Returns: distance from `point` to rectangle, **0.0** if `point` is inside
+    pub fn distance( &self, point:&Vector2 ) -> f32 {
+        ( self.closest_point( point ) - *point ).magnitude()
+    }
+
+    /// Grow rectangle so that it contains `point`
+    pub fn encapsulate( &mut self, point:&Vector2 ) {
+        self.min = self.min.zip_map( point, f32::min );
+        self.max = self.max.zip_map( point, f32::max );
+    }
+
+    /// Grow rectangle so that it contains `other`
+    pub fn encapsulate_aabb2d( &mut self, other:&Self ) {
+        self.encapsulate( &other.min );
+        self.encapsulate( &other.max );
+    }
+
+    /// Move every side of rectangle outwards by `amount`, negative `amount` shrinks rectangle
+    /// 
+    /// Shrinking never moves `min` past `max`, sides meet at `center` instead
+    pub fn expand( &mut self, amount:f32 ) {
+        let center = self.center();
+        self.min = ( self.min - Vector2::new( amount, amount ) ).zip_map( &center, f32::min );
+        self.max = ( self.max + Vector2::new( amount, amount ) ).zip_map( &center, f32::max );
+    }
+
+}
+
+impl Display for Aabb2d {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Aabb2d: min: {}, max: {}", self.min, self.max )
+    }
+}
@@ -0,0 +1,191 @@
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+
+use crate::math;
+
+use super::{
+    Vector2,
+    Matrix3x3,
+};
+
+/// Placement of a single *uv* chart inside an atlas
+/// 
+/// Chart *uv* coordinates between **0.0** and **1.0** are rotated around the chart center,
+/// scaled by `size` and moved by `offset`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct UvChart {
+    offset:Vector2,
+    size:Vector2,
+    rotation:Matrix3x3,
+    padding:f32,
+}
+
+impl UvChart {
+
+    /// Create new `UvChart`
+    /// 
+    /// `offset`: bottom-left corner of chart inside atlas, in atlas *uv*
+    /// 
+    /// `size`: size of chart inside atlas, in atlas *uv*, after rotation
+    /// 
+    /// `rotation`: counter-clockwise rotation around chart center in **Radians**,
+    /// packers that rotate charts use `FRAC_PI_2`
+    /// 
+    /// `padding`: empty border around chart in atlas *uv*, only recorded for tools
+    pub fn new( offset:Vector2, size:Vector2, rotation:f32, padding:f32 ) -> Self {
+        Self {
+            offset,
+            size,
+            rotation:Matrix3x3::new_uv_transform( &Vector2::new_zero(), rotation, &Vector2::new_one() ),
+            padding,
+        }
+    }
+
+    /// Returns: bottom-left corner of chart inside atlas
+    pub fn offset(&self) -> &Vector2 {
+        &self.offset
+    }
+
+    /// Returns: size of chart inside atlas
+    pub fn size(&self) -> &Vector2 {
+        &self.size
+    }
+
+    /// Returns: `Matrix3x3` that rotates chart *uv* around chart center
+    pub fn rotation(&self) -> &Matrix3x3 {
+        &self.rotation
+    }
+
+    /// Returns: empty border around chart in atlas *uv*
+    pub fn padding(&self) -> f32 {
+        self.padding
+    }
+
+    /// Returns: new `Matrix3x3` that maps chart *uv* into atlas *uv*
+    pub fn transform(&self) -> Matrix3x3 {
+        Matrix3x3::new_translate_2d( self.offset.as_array() ) *
+        Matrix3x3::new_scale_2d( self.size.as_array() ) *
+        self.rotation
+    }
+
+    /// Map chart `uv` into atlas *uv*
+    pub fn remap( &self, uv:&Vector2 ) -> Vector2 {
+        self.transform().mul_vector2( uv )
+    }
+
+}
+
+impl Display for UvChart {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "UvChart: offset: {}, size: {}, rotation: {}, padding: {}",
+            self.offset, self.size,
+            math::atan2( self.rotation[1], self.rotation[0] ), self.padding
+        )
+    }
+}
+
+/// Collection of `UvChart` placements that make up an atlas
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`, `Default`
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct AtlasLayout {
+    charts:Vec<UvChart>,
+}
+
+impl AtlasLayout {
+
+    /// Create new empty `AtlasLayout`
+    pub fn new() -> Self {
+        Self { charts:Vec::new() }
+    }
+
+    /// Add `chart` to layout
+    /// 
+    /// Returns: index of added chart
+    pub fn push( &mut self, chart:UvChart ) -> usize {
+        self.charts.push( chart );
+        self.charts.len() - 1
+    }
+
+    /// Returns: `reference` to chart at `index` if it exists
+    pub fn chart( &self, index:usize ) -> Option<&UvChart> {
+        self.charts.get( index )
+    }
+
+    /// Returns: `slice` of every chart
+    pub fn charts(&self) -> &[UvChart] {
+        &self.charts
+    }
+
+    /// Returns: number of charts
+    pub fn len(&self) -> usize {
+        self.charts.len()
+    }
+
+    /// Returns: `true` if layout has no charts
+    pub fn is_empty(&self) -> bool {
+        self.charts.is_empty()
+    }
+
+    /// Map `uv` of chart at `index` into atlas *uv*
+    /// 
+    /// Returns: `None` if chart does not exist
+    pub fn remap( &self, index:usize, uv:&Vector2 ) -> Option<Vector2> {
+        Some( self.chart( index )?.remap( uv ) )
+    }
+
+    /// Map every *uv* of chart at `index` into atlas *uv* in place
+    /// 
+    /// Returns: `false` if chart does not exist, `uvs` are left untouched
+    pub fn remap_slice( &self, index:usize, uvs:&mut [Vector2] ) -> bool {
+        let transform = match self.chart( index ) {
+            Some( chart ) => chart.transform(),
+            None => return false,
+        };
+        for uv in uvs.iter_mut() {
+            *uv = transform.mul_vector2( uv );
+        }
+        true
+    }
+
+}
+
+impl Display for AtlasLayout {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "AtlasLayout: {} charts", self.charts.len() )?;
+        for chart in self.charts.iter() {
+            write!( f, "\n    {}", chart )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FRAC_PI_2;
+
+    fn assert_close( a:Vector2, b:Vector2 ) {
+        assert!( ( a - b ).magnitude() < 1e-6, "{} != {}", a, b );
+    }
+
+    #[test]
+    fn remap_unrotated() {
+        let chart = UvChart::new( Vector2::new( 0.5, 0.25 ), Vector2::new( 0.25, 0.5 ), 0.0, 0.0 );
+        assert_close( chart.remap( &Vector2::new( 0.0, 0.0 ) ), Vector2::new( 0.5, 0.25 ) );
+        assert_close( chart.remap( &Vector2::new( 1.0, 1.0 ) ), Vector2::new( 0.75, 0.75 ) );
+    }
+
+    #[test]
+    fn remap_rotated() {
+        let chart = UvChart::new( Vector2::new( 0.5, 0.25 ), Vector2::new( 0.25, 0.5 ), FRAC_PI_2, 0.0 );
+        assert_close( chart.remap( &Vector2::new( 0.0, 0.0 ) ), Vector2::new( 0.75, 0.25 ) );
+        assert_close( chart.remap( &Vector2::new( 1.0, 0.0 ) ), Vector2::new( 0.75, 0.75 ) );
+        assert_close( chart.remap( &Vector2::new( 0.0, 1.0 ) ), Vector2::new( 0.5, 0.25 ) );
+    }
+
+}
@@ -0,0 +1,141 @@
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+
+use super::Vector3;
+
+/// Cubic *Bernstein* basis weights at `t`
+fn bernstein( t:f32 ) -> [f32;4] {
+    let s = 1.0 - t;
+    [
+        s * s * s,
+        3.0 * s * s * t,
+        3.0 * s * t * t,
+        t * t * t,
+    ]
+}
+
+/// Derivatives of cubic *Bernstein* basis weights at `t`
+fn bernstein_derivative( t:f32 ) -> [f32;4] {
+    let s = 1.0 - t;
+    [
+        -3.0 * s * s,
+        3.0 * s * ( s - ( 2.0 * t ) ),
+        3.0 * t * ( ( 2.0 * s ) - t ),
+        3.0 * t * t,
+    ]
+}
+
+/// Bicubic *Bezier* surface defined by 4 by 4 control points
+/// 
+/// Control points are stored row by row,
+/// `u` runs along a row and `v` runs across rows
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BezierPatch {
+    control_points:[Vector3;16]
+}
+
+impl BezierPatch {
+
+    /// Create new `BezierPatch`
+    /// 
+    /// `control_points`: index is `u_index` + ( `v_index` * **4** )
+    pub fn new( control_points:[Vector3;16] ) -> Self {
+        Self { control_points }
+    }
+
+    /// Returns: `reference` to control points
+    pub fn control_points(&self) -> &[Vector3;16] {
+        &self.control_points
+    }
+
+    /// Returns: `mutable reference` to control points
+    pub fn control_points_mut(&mut self) -> &mut [Vector3;16] {
+        &mut self.control_points
+    }
+
+    /// Returns: `reference` to control point at `u_index` and `v_index`
+    pub fn control_point( &self, u_index:usize, v_index:usize ) -> &Vector3 {
+        debug_assert!( u_index < 4 && v_index < 4 );
+        &self.control_points[u_index + ( v_index * 4 )]
+    }
+
+    fn weighted_sum( &self, u_weights:&[f32;4], v_weights:&[f32;4] ) -> Vector3 {
+        let mut result = Vector3::new_zero();
+        for ( v_index, v_weight ) in v_weights.iter().enumerate() {
+            for ( u_index, u_weight ) in u_weights.iter().enumerate() {
+                result = result +
+                    ( *self.control_point( u_index, v_index ) * ( u_weight * v_weight ) );
+            }
+        }
+        result
+    }
+
+    /// Evaluate surface position
+    /// 
+    /// `u` and `v` should be between **0.0** and **1.0**
+    /// 
+    /// Returns: `Vector3` position on surface
+    pub fn evaluate( &self, u:f32, v:f32 ) -> Vector3 {
+        self.weighted_sum( &bernstein( u ), &bernstein( v ) )
+    }
+
+    /// Returns: partial derivative of surface along `u`
+    pub fn derivative_u( &self, u:f32, v:f32 ) -> Vector3 {
+        self.weighted_sum( &bernstein_derivative( u ), &bernstein( v ) )
+    }
+
+    /// Returns: partial derivative of surface along `v`
+    pub fn derivative_v( &self, u:f32, v:f32 ) -> Vector3 {
+        self.weighted_sum( &bernstein( u ), &bernstein_derivative( v ) )
+    }
+
+    /// Evaluate surface normal
+    /// 
+    /// Returns: normalized `Vector3`,
+    /// **zero** if the surface is degenerate at `u`, `v`
+    pub fn normal( &self, u:f32, v:f32 ) -> Vector3 {
+        let normal = Vector3::cross( &self.derivative_u( u, v ), &self.derivative_v( u, v ) );
+        if normal.sqr_magnitude() == 0.0 {
+            return normal;
+        }
+        normal.normal()
+    }
+
+    /// Tessellate surface into a grid of positions and normals
+    /// 
+    /// `segments_u` and `segments_v` are at least **1**
+    /// 
+    /// Returns: positions and normals, ( `segments_u` + **1** ) x ( `segments_v` + **1** )
+    /// each, stored row by row like control points
+    pub fn tessellate( &self, segments_u:usize, segments_v:usize ) -> ( Vec<Vector3>, Vec<Vector3> ) {
+        let segments_u = segments_u.max( 1 );
+        let segments_v = segments_v.max( 1 );
+        let count = ( segments_u + 1 ) * ( segments_v + 1 );
+
+        let mut positions = Vec::with_capacity( count );
+        let mut normals   = Vec::with_capacity( count );
+        for v_index in 0..=segments_v {
+            let v = v_index as f32 / segments_v as f32;
+            for u_index in 0..=segments_u {
+                let u = u_index as f32 / segments_u as f32;
+                positions.push( self.evaluate( u, v ) );
+                normals.push( self.normal( u, v ) );
+            }
+        }
+        ( positions, normals )
+    }
+
+}
+
+impl Display for BezierPatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "BezierPatch:" )?;
+        for row in self.control_points.chunks_exact( 4 ) {
+            write!( f, "\n    {}, {}, {}, {}", row[0], row[1], row[2], row[3] )?;
+        }
+        Ok(())
+    }
+}
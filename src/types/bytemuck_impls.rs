@@ -0,0 +1,66 @@
+//! *bytemuck* `Pod` and `Zeroable` implementations
+//! 
+//! Slices of vectors, matrices, quaternions and colors can be cast to `&[u8]`
+//! with `bytemuck::cast_slice` for uniform and vertex buffers
+//! 
+//! Matrices are column-major, `RGB` is a native endian `u32`
+//! ( *R G B A bytes on little endian targets* ),
+//! `UnitQuaternion` and `HSV` are only `NoUninit` because not every bit pattern
+//! is normalized or in range, `HSV` is still `Zeroable`
+
+use bytemuck::{ Pod, Zeroable, NoUninit };
+
+use super::{
+    Vector2, Vector3, Vector4,
+    Matrix3x3, Matrix4x4,
+    Quaternion, UnitQuaternion,
+    color::{
+        RGB, HSV,
+    },
+};
+
+// SAFETY: every type is repr(transparent) over an f32/u32 array or
+// repr(C) over f32 fields, has no padding and every bit pattern is valid
+unsafe impl Zeroable for Vector2 {}
+unsafe impl Pod for Vector2 {}
+
+unsafe impl Zeroable for Vector3 {}
+unsafe impl Pod for Vector3 {}
+
+unsafe impl Zeroable for Vector4 {}
+unsafe impl Pod for Vector4 {}
+
+unsafe impl Zeroable for Matrix3x3 {}
+unsafe impl Pod for Matrix3x3 {}
+
+unsafe impl Zeroable for Matrix4x4 {}
+unsafe impl Pod for Matrix4x4 {}
+
+unsafe impl Zeroable for Quaternion {}
+unsafe impl Pod for Quaternion {}
+
+unsafe impl Zeroable for RGB {}
+unsafe impl Pod for RGB {}
+
+// SAFETY: repr(transparent) over Quaternion, no padding or uninit bytes
+unsafe impl NoUninit for UnitQuaternion {}
+
+// SAFETY: repr(C) over f32 fields, no padding or uninit bytes,
+// all zeroes is a valid color ( *black* ) but other bit patterns can leave hue, saturation or value out of range
+unsafe impl Zeroable for HSV {}
+unsafe impl NoUninit for HSV {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsv_casts_to_bytes() {
+        let colors = [ HSV::new( 120.0, 0.5, 0.25 ) ];
+        let bytes:&[u8] = bytemuck::cast_slice( &colors );
+        assert_eq!( bytes.len(), 12 );
+        assert_eq!( &bytes[0..4], &120.0_f32.to_ne_bytes() );
+        assert_eq!( HSV::zeroed(), HSV::new( 0.0, 0.0, 0.0 ) );
+    }
+
+}
@@ -0,0 +1,83 @@
+use crate::types::{
+    Vector3, Matrix3x3,
+};
+
+/// Standard illuminant white points
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WhitePoint {
+    /// Incandescent light
+    A,
+    /// Horizon light, used by *ICC* profiles
+    D50,
+    /// Noon daylight, used by *sRGB* and *Rec. 709*
+    D65,
+    /// Equal energy
+    E,
+}
+
+impl WhitePoint {
+    /// Returns: *CIE XYZ* of white point with `Y` of **1.0**
+    pub fn xyz(&self) -> Vector3 {
+        match self {
+            WhitePoint::A   => Vector3::new( 1.09850, 1.0, 0.35585 ),
+            WhitePoint::D50 => Vector3::new( 0.96422, 1.0, 0.82521 ),
+            WhitePoint::D65 => Vector3::new( 0.95047, 1.0, 1.08883 ),
+            WhitePoint::E   => Vector3::new( 1.0, 1.0, 1.0 ),
+        }
+    }
+}
+
+/// Returns: new `Matrix3x3` that converts linear *sRGB* into *CIE XYZ* ( *D65* )
+pub fn linear_srgb_to_xyz() -> Matrix3x3 {
+    Matrix3x3::from_array_row_major([
+        0.4124564, 0.3575761, 0.1804375,
+        0.2126729, 0.7151522, 0.0721750,
+        0.0193339, 0.119192,  0.9503041,
+    ])
+}
+
+/// Returns: new `Matrix3x3` that converts *CIE XYZ* ( *D65* ) into linear *sRGB*
+pub fn xyz_to_linear_srgb() -> Matrix3x3 {
+    Matrix3x3::from_array_row_major([
+         3.2404542, -1.5371385, -0.4985314,
+        -0.969266,   1.8760108,  0.041556,
+         0.0556434, -0.2040259,  1.0572252,
+    ])
+}
+
+fn bradford() -> Matrix3x3 {
+    Matrix3x3::from_array_row_major([
+         0.8951,  0.2664, -0.1614,
+        -0.7502,  1.7135,  0.0367,
+         0.0389, -0.0685,  1.0296,
+    ])
+}
+
+/// *Bradford* chromatic adaptation between `source` and `target` white points
+/// 
+/// Returns: new `Matrix3x3` that converts *CIE XYZ* colors
+pub fn bradford_adaptation( source:WhitePoint, target:WhitePoint ) -> Matrix3x3 {
+    bradford_adaptation_xyz( &source.xyz(), &target.xyz() )
+}
+
+/// *Bradford* chromatic adaptation between arbitrary
+/// `source` and `target` white points given in *CIE XYZ*
+/// 
+/// Returns: new `Matrix3x3` that converts *CIE XYZ* colors
+pub fn bradford_adaptation_xyz( source:&Vector3, target:&Vector3 ) -> Matrix3x3 {
+    let bradford = bradford();
+    // Bradford matrix has a known non-zero determinant
+    let inverse  = bradford.inverse().unwrap_or( Matrix3x3::new_identity() );
+
+    let source_cone = bradford.mul_vector3( source );
+    let target_cone = bradford.mul_vector3( target );
+    let scale = Matrix3x3::from_array([
+        target_cone[0] / source_cone[0], 0.0, 0.0,
+        0.0, target_cone[1] / source_cone[1], 0.0,
+        0.0, 0.0, target_cone[2] / source_cone[2],
+    ]);
+
+    inverse * scale * bradford
+}
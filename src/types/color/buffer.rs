@@ -0,0 +1,257 @@
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    types::Vector4,
+    functions::batch::for_each_chunk_zip,
+};
+
+use super::{
+    RGB,
+    srgb_to_linear,
+    linear_to_srgb,
+    color_byte_to_color_float,
+    color_float_to_color_byte,
+};
+
+/// Convert `colors` into interleaved *RGBA8* bytes
+/// 
+/// Returns: `Vec` with **4** bytes per color
+pub fn rgb_slice_to_rgba8( colors:&[RGB] ) -> Vec<u8> {
+    let mut result = vec![ 0; colors.len() * 4 ];
+
+    for_each_chunk_zip( colors, 1, &mut result, 4, | _, colors, bytes | {
+        for ( color, pixel ) in colors.iter().zip( bytes.chunks_exact_mut( 4 ) ) {
+            pixel.copy_from_slice( &color.as_rgba_array() );
+        }
+    } );
+
+    result
+}
+
+/// Convert interleaved *RGBA8* `bytes` into colors
+/// 
+/// Returns: `Vec` of `RGB` if `bytes` length is a multiple of **4**
+/// 
+/// Returns: `String` error if `bytes` length is **not** a multiple of **4**
+pub fn rgba8_to_rgb_vec( bytes:&[u8] ) -> Result<Vec<RGB>, String> {
+    validate_rgba8( bytes )?;
+
+    let mut result = vec![ RGB::new_rgba( 0, 0, 0, 0 ); bytes.len() / 4 ];
+
+    for_each_chunk_zip( bytes, 4, &mut result, 1, | _, bytes, colors | {
+        for ( pixel, color ) in bytes.chunks_exact( 4 ).zip( colors.iter_mut() ) {
+            *color = RGB::new_rgba( pixel[0], pixel[1], pixel[2], pixel[3] );
+        }
+    } );
+
+    Ok( result )
+}
+
+/// Convert float `colors` into interleaved *RGBA8* bytes
+/// 
+/// `colors`: `R` `G` `B` `A` between **0.0** and **1.0**, values outside are clamped
+/// 
+/// `encode_srgb`: treat `colors` as linear and encode them as *sRGB*
+/// 
+/// `premultiply`: multiply color channels by alpha ( *in linear space* )
+/// 
+/// Returns: `Vec` with **4** bytes per color
+pub fn vector4_slice_to_rgba8( colors:&[Vector4], encode_srgb:bool, premultiply:bool ) -> Vec<u8> {
+    let mut result = vec![ 0; colors.len() * 4 ];
+
+    for_each_chunk_zip( colors, 1, &mut result, 4, | _, colors, bytes | {
+        for ( color, pixel ) in colors.iter().zip( bytes.chunks_exact_mut( 4 ) ) {
+            pixel.copy_from_slice( &encode_rgba8( *color.as_array(), encode_srgb, premultiply ) );
+        }
+    } );
+
+    result
+}
+
+/// Convert interleaved *RGBA8* `bytes` into float colors
+/// 
+/// Colors stay in floating point so the dark range of *sRGB* survives decoding
+/// 
+/// `decode_srgb`: treat `bytes` as *sRGB* and decode them into linear colors
+/// 
+/// `unpremultiply`: divide color channels by alpha ( *in linear space* )
+/// 
+/// Returns: `Vec` of `Vector4` `R` `G` `B` `A` if `bytes` length is a multiple of **4**
+/// 
+/// Returns: `String` error if `bytes` length is **not** a multiple of **4**
+pub fn rgba8_to_vector4_vec( bytes:&[u8], decode_srgb:bool, unpremultiply:bool ) -> Result<Vec<Vector4>, String> {
+    validate_rgba8( bytes )?;
+
+    let mut result = vec![ Vector4::new_zero(); bytes.len() / 4 ];
+
+    for_each_chunk_zip( bytes, 4, &mut result, 1, | _, bytes, colors | {
+        for ( pixel, color ) in bytes.chunks_exact( 4 ).zip( colors.iter_mut() ) {
+            *color = Vector4::from_array( decode_rgba8( pixel, decode_srgb, unpremultiply ) );
+        }
+    } );
+
+    Ok( result )
+}
+
+/// Convert planar float channels into interleaved *RGBA8* bytes
+/// 
+/// `planes`: `R` `G` `B` `A` planes of equal length, values between **0.0** and **1.0**
+/// 
+/// `encode_srgb`: treat `planes` as linear and encode them as *sRGB*
+/// 
+/// `premultiply`: multiply color channels by alpha ( *in linear space* )
+/// 
+/// Returns: `Vec` with **4** bytes per color if every plane has the same length
+/// 
+/// Returns: `String` error if plane lengths differ
+pub fn planar_to_rgba8( planes:[&[f32];4], encode_srgb:bool, premultiply:bool ) -> Result<Vec<u8>, String> {
+    let [ r, g, b, a ] = planes;
+    if g.len() != r.len() || b.len() != r.len() || a.len() != r.len() {
+        return Err(
+            format!(
+                "RGBA8 CONVERSION ERROR: Planes should have equal length, got {}, {}, {} and {}!",
+                r.len(), g.len(), b.len(), a.len()
+            )
+        );
+    }
+
+    let mut result = vec![ 0; r.len() * 4 ];
+    for ( i, pixel ) in result.chunks_exact_mut( 4 ).enumerate() {
+        pixel.copy_from_slice( &encode_rgba8( [ r[i], g[i], b[i], a[i] ], encode_srgb, premultiply ) );
+    }
+
+    Ok( result )
+}
+
+/// Convert interleaved *RGBA8* `bytes` into planar float channels
+/// 
+/// `decode_srgb`: treat `bytes` as *sRGB* and decode them into linear colors
+/// 
+/// `unpremultiply`: divide color channels by alpha ( *in linear space* )
+/// 
+/// Returns: `R` `G` `B` `A` planes if `bytes` length is a multiple of **4**
+/// 
+/// Returns: `String` error if `bytes` length is **not** a multiple of **4**
+pub fn rgba8_to_planar( bytes:&[u8], decode_srgb:bool, unpremultiply:bool ) -> Result<[Vec<f32>;4], String> {
+    validate_rgba8( bytes )?;
+
+    let count = bytes.len() / 4;
+    let mut planes = [
+        Vec::with_capacity( count ),
+        Vec::with_capacity( count ),
+        Vec::with_capacity( count ),
+        Vec::with_capacity( count ),
+    ];
+    for pixel in bytes.chunks_exact( 4 ) {
+        let color = decode_rgba8( pixel, decode_srgb, unpremultiply );
+        for ( plane, channel ) in planes.iter_mut().zip( color ) {
+            plane.push( channel );
+        }
+    }
+
+    Ok( planes )
+}
+
+fn validate_rgba8( bytes:&[u8] ) -> Result<(), String> {
+    if !bytes.len().is_multiple_of( 4 ) {
+        return Err(
+            String::from("RGBA8 CONVERSION ERROR: Input byte length should be a multiple of 4!")
+        );
+    }
+    Ok(())
+}
+
+fn encode_rgba8( color:[f32;4], encode_srgb:bool, premultiply:bool ) -> [u8;4] {
+    let [ mut r, mut g, mut b, a ] = color;
+    if premultiply {
+        r *= a;
+        g *= a;
+        b *= a;
+    }
+    if encode_srgb {
+        r = linear_to_srgb( r.clamp( 0.0, 1.0 ) );
+        g = linear_to_srgb( g.clamp( 0.0, 1.0 ) );
+        b = linear_to_srgb( b.clamp( 0.0, 1.0 ) );
+    }
+    [
+        color_float_to_color_byte( r ),
+        color_float_to_color_byte( g ),
+        color_float_to_color_byte( b ),
+        color_float_to_color_byte( a ),
+    ]
+}
+
+fn decode_rgba8( pixel:&[u8], decode_srgb:bool, unpremultiply:bool ) -> [f32;4] {
+    let [ mut r, mut g, mut b, a ] = [
+        color_byte_to_color_float( pixel[0] ),
+        color_byte_to_color_float( pixel[1] ),
+        color_byte_to_color_float( pixel[2] ),
+        color_byte_to_color_float( pixel[3] ),
+    ];
+    if decode_srgb {
+        r = srgb_to_linear( r );
+        g = srgb_to_linear( g );
+        b = srgb_to_linear( b );
+    }
+    if unpremultiply && a > 0.0 {
+        r /= a;
+        g /= a;
+        b /= a;
+    }
+    [ r, g, b, a ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb_round_trip() {
+        let bytes:Vec<u8> = ( 0..=255 ).collect();
+        let colors = rgba8_to_rgb_vec( &bytes ).unwrap();
+        assert_eq!( rgb_slice_to_rgba8( &colors ), bytes );
+    }
+
+    #[test]
+    fn srgb_round_trip_keeps_dark_range() {
+        let bytes:Vec<u8> = ( 0..=255 ).flat_map( | v | [ v, v, v, 255 ] ).collect();
+        let linear = rgba8_to_vector4_vec( &bytes, true, false ).unwrap();
+        assert!( linear[1][0] > 0.0 && linear[1][0] < 1.0 / 255.0 );
+        assert_eq!( vector4_slice_to_rgba8( &linear, true, false ), bytes );
+    }
+
+    #[test]
+    fn premultiplied_round_trip() {
+        let bytes = [ 200, 100, 50, 128, 10, 20, 30, 255 ];
+        let straight = rgba8_to_vector4_vec( &bytes, false, true ).unwrap();
+        assert_eq!( vector4_slice_to_rgba8( &straight, false, true ), bytes );
+    }
+
+    #[test]
+    fn planar_matches_interleaved() {
+        let bytes:Vec<u8> = ( 0..64 ).map( | v | v * 4 ).collect();
+        let planes = rgba8_to_planar( &bytes, true, true ).unwrap();
+        let interleaved = rgba8_to_vector4_vec( &bytes, true, true ).unwrap();
+        for ( i, color ) in interleaved.iter().enumerate() {
+            for ( channel, plane ) in planes.iter().enumerate() {
+                assert_eq!( plane[i], color[channel] );
+            }
+        }
+        let [ r, g, b, a ] = &planes;
+        assert_eq!( planar_to_rgba8( [ r, g, b, a ], true, true ).unwrap(), bytes );
+    }
+
+    #[test]
+    fn invalid_lengths() {
+        assert!( rgba8_to_rgb_vec( &[ 0, 0, 0 ] ).is_err() );
+        assert!( rgba8_to_vector4_vec( &[ 0, 0, 0 ], false, false ).is_err() );
+        assert!( rgba8_to_planar( &[ 0, 0, 0 ], false, false ).is_err() );
+        assert!( planar_to_rgba8( [ &[ 0.0 ], &[ 0.0 ], &[ 0.0 ], &[] ], false, false ).is_err() );
+    }
+
+}
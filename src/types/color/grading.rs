@@ -0,0 +1,46 @@
+use crate::{
+    types::Matrix4x4,
+    functions::batch::for_each_chunk,
+};
+
+use super::{
+    RGB, HSV, Lut3d,
+};
+
+/// Shift hue, saturation and value of every color in place, alpha is kept
+/// 
+/// `hue_shift`: degrees added to hue, wraps around
+/// 
+/// `saturation_shift` and `value_shift`: added and clamped between **0.0** and **1.0**
+pub fn adjust_hsv( colors:&mut [RGB], hue_shift:f32, saturation_shift:f32, value_shift:f32 ) {
+    for_each_chunk( colors, | chunk | {
+        for color in chunk.iter_mut() {
+            let mut hsv = HSV::from_rgb( *color );
+            hsv.set_hue( *hsv.hue() + hue_shift );
+            hsv.set_saturation( *hsv.saturation() + saturation_shift );
+            hsv.set_value( *hsv.value() + value_shift );
+            *color = hsv.as_rgb_with_alpha( color.a() );
+        }
+    } );
+}
+
+/// Transform every color in place by color `matrix`, alpha is kept
+/// 
+/// Colors are multiplied as ( `R`, `G`, `B`, **1.0** ) so
+/// the translation column of `matrix` offsets channels
+pub fn apply_color_matrix( colors:&mut [RGB], matrix:&Matrix4x4 ) {
+    for_each_chunk( colors, | chunk | {
+        for color in chunk.iter_mut() {
+            *color = matrix.transform_color( *color );
+        }
+    } );
+}
+
+/// Replace every color in place with `lut` sample, alpha is kept
+pub fn apply_lut3d( colors:&mut [RGB], lut:&Lut3d ) {
+    for_each_chunk( colors, | chunk | {
+        for color in chunk.iter_mut() {
+            *color = lut.sample_rgb( *color );
+        }
+    } );
+}
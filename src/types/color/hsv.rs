@@ -4,6 +4,8 @@ use crate::functions::{
     max, min,
 };
 
+use crate::math;
+
 use super::RGB;
 
 /// Color representation using `Hue`, `Saturation` and `Value`
@@ -13,6 +15,10 @@ use super::RGB;
 /// `Saturation`: **0.0**-**1.0**
 /// 
 /// `Value`: **0.0**-**1.0**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
 pub struct HSV {
     hue:f32,
     saturation:f32,
@@ -27,6 +33,11 @@ impl HSV {
         Self::from_rgb_array(&rgb)
     }
 
+    /// Create `HSV` from **0**-**255** `r`, `g`, `b` bytes
+    pub fn from_rgb8( r:u8, g:u8, b:u8 ) -> Self {
+        Self::from_rgb( RGB::new_rgb( r, g, b ) )
+    }
+
     /// Create new `HSV` from `hue`, `saturation` and `value`
     /// 
     /// `hue` is overflowed between **0.0** and **360.0**
@@ -45,13 +56,16 @@ impl HSV {
     /// Creates new `HSV` from **0.0**-**1.0** RGB array
     pub(crate) fn from_rgb_array( rgb:&[f32;3] ) -> Self {
 
+        // NaN channels are treated as 0.0 so comparisons below stay meaningful
+        let rgb = &rgb.map( | c | if c.is_nan() { 0.0 } else { c.clamp( 0.0, 1.0 ) } );
+
         let x_max = max( rgb ); // value
         let x_min = min( rgb ); // value - chroma
 
         let chroma = x_max - x_min;
 
         let hue:f32 = {
-            if chroma == 0.0 {
+            if chroma <= 0.0 {
                 0.0
             } else if x_max == rgb[0] {
                 60.0 * ( 0.0 + ( ( rgb[1] - rgb[2] ) / chroma ) )
@@ -74,17 +88,19 @@ impl HSV {
     }
 
     /// Converts `HSV` to 3 component `f32` `array`
+    #[allow(clippy::clone_on_copy)]
     pub(crate) fn as_rgb_array(&self) -> [f32;3] {
 
         let value = self.value().clone();
 
         let chroma = value * self.saturation();
 
+        // hue is kept between 0.0 and 360.0 so sector is between 0 and 5
         let hue = self.hue() / 60.0;
-        let hue_index = f32::floor(hue) as i32;
+        let hue_index = ( math::floor( hue ) as i32 ).clamp( 0, 5 );
 
         // second largest component of color
-        let x = chroma * ( 1.0 - ( ( ( hue ) % 2.0 ) - 1.0 ).abs() );
+        let x = chroma * ( 1.0 - ( math::rem_euclid( hue, 2.0 ) - 1.0 ).abs() );
 
         let ( r, g, b ) = {
             if hue_index < 1 {
@@ -112,6 +128,17 @@ impl HSV {
         RGB::from_float_array_rgb( self.as_rgb_array() )
     }
 
+    /// Returns: **0**-**255** `R`, `G`, `B` bytes from `HSV`
+    pub fn as_rgb8(&self) -> [u8;3] {
+        let rgb = self.as_rgb();
+        [ rgb.r(), rgb.g(), rgb.b() ]
+    }
+
+    /// Returns: new `RGB` from `HSV` with given `alpha`
+    pub fn as_rgb_with_alpha( &self, alpha:u8 ) -> RGB {
+        self.as_rgb().with_alpha( alpha )
+    }
+
     /// Returns: `reference` to `hue` component
     pub fn hue(&self) -> &f32 {
         &self.hue
@@ -151,11 +178,59 @@ impl HSV {
 }
 
 impl Display for HSV {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "HSV: Hue: {}°, Saturation: {}%, Value: {}%",
             self.hue(), self.saturation() * 100.0, self.value() * 100.0
         )
     }
-}
\ No newline at end of file
+}
+
+impl From<RGB> for HSV {
+    fn from( rgb:RGB ) -> Self {
+        Self::from_rgb( rgb )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb8_round_trip_exhaustive() {
+        for c in 0..( 1_u32 << 24 ) {
+            let rgb = [ c as u8, ( c >> 8 ) as u8, ( c >> 16 ) as u8 ];
+            assert_eq!( HSV::from_rgb8( rgb[0], rgb[1], rgb[2] ).as_rgb8(), rgb );
+        }
+    }
+
+    #[test]
+    fn hue_360_wraps_to_red() {
+        assert_eq!( HSV::new( 360.0, 1.0, 1.0 ).as_rgb8(), [ 255, 0, 0 ] );
+        assert_eq!( HSV::new( 720.0, 1.0, 1.0 ).as_rgb8(), [ 255, 0, 0 ] );
+        assert_eq!( HSV::new( -360.0, 1.0, 1.0 ).as_rgb8(), [ 255, 0, 0 ] );
+        assert_eq!( *HSV::new( 360.0, 1.0, 1.0 ).hue(), 0.0 );
+    }
+
+    #[test]
+    fn zero_saturation_is_gray() {
+        for hue in [ 0.0, 59.9, 180.0, 359.9 ] {
+            assert_eq!( HSV::new( hue, 0.0, 0.5 ).as_rgb8(), [ 128, 128, 128 ] );
+        }
+        let gray = HSV::from_rgb8( 77, 77, 77 );
+        assert_eq!( *gray.saturation(), 0.0 );
+        assert_eq!( *gray.hue(), 0.0 );
+    }
+
+    #[test]
+    fn zero_value_is_black() {
+        for hue in [ 0.0, 120.0, 240.0, 359.9 ] {
+            assert_eq!( HSV::new( hue, 1.0, 0.0 ).as_rgb8(), [ 0, 0, 0 ] );
+        }
+        let black = HSV::from_rgb8( 0, 0, 0 );
+        assert_eq!( *black.saturation(), 0.0 );
+        assert_eq!( *black.value(), 0.0 );
+    }
+
+}
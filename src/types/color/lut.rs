@@ -0,0 +1,258 @@
+use core::fmt::Display;
+
+use alloc::{
+    format,
+    string::String,
+    vec::Vec,
+};
+
+use crate::types::Vector3;
+
+use super::RGB;
+
+/// 3D color lookup table with `size` x `size` x `size` entries
+/// 
+/// Entries are ordered with *red* changing fastest, then *green*, then *blue*
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Lut3d {
+    size:usize,
+    data:Vec<Vector3>,
+    domain_min:Vector3,
+    domain_max:Vector3,
+}
+
+impl Lut3d {
+
+    /// Create new `Lut3d` from `data`
+    /// 
+    /// Returns: `Lut3d` if `size` is at least **2** and `data` has `size`³ entries
+    /// 
+    /// Returns: `String` error otherwise
+    pub fn new( size:usize, data:Vec<Vector3> ) -> Result<Self, String> {
+        if size < 2 {
+            return Err( format!("LUT3D ERROR: Size must be at least 2, got {}!", size) );
+        }
+        let entries = match size.checked_mul( size ).and_then( | square | square.checked_mul( size ) ) {
+            Some( entries ) => entries,
+            None => return Err( format!("LUT3D ERROR: Size {} is too large!", size) ),
+        };
+        if data.len() != entries {
+            return Err(
+                format!(
+                    "LUT3D ERROR: Expected {} entries, got {}!",
+                    entries, data.len()
+                )
+            );
+        }
+        Ok( Self { size, data, domain_min:Vector3::new_zero(), domain_max:Vector3::new_one() } )
+    }
+
+    /// Create new `Lut3d` that leaves colors unchanged
+    /// 
+    /// `size` is at least **2**
+    pub fn new_identity( size:usize ) -> Self {
+        let size = size.max( 2 );
+        let max  = ( size - 1 ) as f32;
+        let mut data = Vec::with_capacity( size * size * size );
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    data.push( Vector3::new( r as f32 / max, g as f32 / max, b as f32 / max ) );
+                }
+            }
+        }
+        Self { size, data, domain_min:Vector3::new_zero(), domain_max:Vector3::new_one() }
+    }
+
+    /// Parse `Lut3d` from *.cube* file `source`
+    /// 
+    /// Supports `LUT_3D_SIZE`, `DOMAIN_MIN`, `DOMAIN_MAX`,
+    /// `TITLE` is ignored and `#` starts a comment
+    /// 
+    /// Returns: `String` error if `source` is malformed or describes a *1D* table
+    pub fn from_cube( source:&str ) -> Result<Self, String> {
+        let mut size:Option<usize> = None;
+        let mut domain_min = Vector3::new_zero();
+        let mut domain_max = Vector3::new_one();
+        let mut data = Vec::new();
+
+        for ( index, line ) in source.lines().enumerate() {
+            let line = match line.find( '#' ) {
+                Some( comment ) => &line[..comment],
+                None => line,
+            }.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap_or_default();
+            match keyword {
+                "TITLE" => continue,
+                "LUT_1D_SIZE" => {
+                    return Err( format!("LUT3D ERROR: Line {}: 1D tables are not supported!", index + 1) );
+                },
+                "LUT_3D_SIZE" => {
+                    let value = words.next().and_then( | word | word.parse::<usize>().ok() );
+                    match value {
+                        Some( value ) => size = Some( value ),
+                        None => return Err( format!("LUT3D ERROR: Line {}: Invalid LUT_3D_SIZE!", index + 1) ),
+                    }
+                },
+                "DOMAIN_MIN" => domain_min = parse_cube_triplet( words, index )?,
+                "DOMAIN_MAX" => domain_max = parse_cube_triplet( words, index )?,
+                _ => data.push( parse_cube_triplet( line.split_whitespace(), index )? ),
+            }
+        }
+
+        let size = match size {
+            Some( size ) => size,
+            None => return Err( String::from("LUT3D ERROR: Missing LUT_3D_SIZE!") ),
+        };
+        let mut result = Self::new( size, data )?;
+        result.set_domain( domain_min, domain_max )?;
+        Ok( result )
+    }
+
+    /// Write table in *.cube* format
+    /// 
+    /// Returns: new `String`
+    pub fn to_cube(&self) -> String {
+        let mut result = format!( "LUT_3D_SIZE {}\n", self.size );
+        if self.domain_min != Vector3::new_zero() || self.domain_max != Vector3::new_one() {
+            result.push_str( &format!(
+                "DOMAIN_MIN {} {} {}\nDOMAIN_MAX {} {} {}\n",
+                self.domain_min[0], self.domain_min[1], self.domain_min[2],
+                self.domain_max[0], self.domain_max[1], self.domain_max[2],
+            ) );
+        }
+        for entry in self.data.iter() {
+            result.push_str( &format!( "{} {} {}\n", entry[0], entry[1], entry[2] ) );
+        }
+        result
+    }
+
+    /// Returns: number of entries along each axis
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns: `reference` to lower bound of input domain
+    pub fn domain_min(&self) -> &Vector3 {
+        &self.domain_min
+    }
+
+    /// Returns: `reference` to upper bound of input domain
+    pub fn domain_max(&self) -> &Vector3 {
+        &self.domain_max
+    }
+
+    /// Set input domain, colors are remapped from `min`..`max` before sampling
+    /// 
+    /// Returns: `String` error if any component of `max` is not greater than `min`
+    pub fn set_domain( &mut self, min:Vector3, max:Vector3 ) -> Result<(), String> {
+        if min[0] >= max[0] || min[1] >= max[1] || min[2] >= max[2] {
+            return Err( format!("LUT3D ERROR: Domain maximum {} must be greater than minimum {}!", max, min) );
+        }
+        self.domain_min = min;
+        self.domain_max = max;
+        Ok( () )
+    }
+
+    /// Returns: `slice` of entries
+    pub fn data(&self) -> &[Vector3] {
+        &self.data
+    }
+
+    /// Returns: `mutable slice` of entries
+    pub fn data_mut(&mut self) -> &mut [Vector3] {
+        &mut self.data
+    }
+
+    /// Returns: `reference` to entry at given `r`, `g`, `b` indices
+    pub fn entry( &self, r:usize, g:usize, b:usize ) -> &Vector3 {
+        &self.data[r + ( g * self.size ) + ( b * self.size * self.size )]
+    }
+
+    /// Sample table with *trilinear* interpolation
+    /// 
+    /// `color` components are remapped from domain and clamped between **0.0** and **1.0**
+    /// 
+    /// Returns: new `Vector3`
+    pub fn sample( &self, color:&Vector3 ) -> Vector3 {
+        let max = ( self.size - 1 ) as f32;
+
+        let mut low  = [0usize;3];
+        let mut high = [0usize;3];
+        let mut t    = [0.0f32;3];
+        for i in 0..3 {
+            let normalized = ( color[i] - self.domain_min[i] ) / ( self.domain_max[i] - self.domain_min[i] );
+            let position = normalized.clamp( 0.0, 1.0 ) * max;
+            low[i]  = ( position as usize ).min( self.size - 2 );
+            high[i] = low[i] + 1;
+            t[i]    = position - low[i] as f32;
+        }
+
+        let corner = | r:usize, g:usize, b:usize | *self.entry( r, g, b );
+
+        let c00 = Vector3::lerp_unclamped( &corner( low[0], low[1],  low[2]  ), &corner( high[0], low[1],  low[2]  ), t[0] );
+        let c10 = Vector3::lerp_unclamped( &corner( low[0], high[1], low[2]  ), &corner( high[0], high[1], low[2]  ), t[0] );
+        let c01 = Vector3::lerp_unclamped( &corner( low[0], low[1],  high[2] ), &corner( high[0], low[1],  high[2] ), t[0] );
+        let c11 = Vector3::lerp_unclamped( &corner( low[0], high[1], high[2] ), &corner( high[0], high[1], high[2] ), t[0] );
+
+        let c0 = Vector3::lerp_unclamped( &c00, &c10, t[1] );
+        let c1 = Vector3::lerp_unclamped( &c01, &c11, t[1] );
+
+        Vector3::lerp_unclamped( &c0, &c1, t[2] )
+    }
+
+    /// Sample table with *trilinear* interpolation, alpha is kept
+    /// 
+    /// Returns: new `RGB`
+    pub fn sample_rgb( &self, color:RGB ) -> RGB {
+        self.sample( &color.as_vector3() ).as_color().with_alpha( color.a() )
+    }
+
+}
+
+impl Display for Lut3d {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Lut3d: {0}x{0}x{0}", self.size )
+    }
+}
+
+fn parse_cube_triplet<'a>( mut words:impl Iterator<Item = &'a str>, index:usize ) -> Result<Vector3, String> {
+    let mut result = Vector3::new_zero();
+    for i in 0..3 {
+        let value = words.next().and_then( | word | word.parse::<f32>().ok() );
+        match value {
+            Some( value ) => result[i] = value,
+            None => return Err( format!("LUT3D ERROR: Line {}: Expected 3 numbers!", index + 1) ),
+        }
+    }
+    if words.next().is_some() {
+        return Err( format!("LUT3D ERROR: Line {}: Expected 3 numbers!", index + 1) );
+    }
+    Ok( result )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn new_rejects_overflowing_size() {
+        assert!( Lut3d::new( usize::MAX / 2, Vec::new() ).is_err() );
+        assert!( Lut3d::from_cube( "LUT_3D_SIZE 18446744073709551615\n" ).is_err() );
+    }
+
+    #[test]
+    fn new_checks_entry_count() {
+        assert!( Lut3d::new( 2, vec![ Vector3::new_zero(); 8 ] ).is_ok() );
+        assert!( Lut3d::new( 2, vec![ Vector3::new_zero(); 7 ] ).is_err() );
+    }
+
+}
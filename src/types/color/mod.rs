@@ -1,20 +1,49 @@
-#[allow(dead_code)]
+#[allow(dead_code, clippy::empty_line_after_outer_attr)]
 
 mod rgb;
-pub use rgb::RGB;
+pub use rgb::{
+    RGB, ChannelOrder,
+};
 
 mod hsv;
 pub use hsv::HSV;
 
+mod transfer;
+pub use transfer::{
+    srgb_to_linear, linear_to_srgb,
+    rec709_to_linear, linear_to_rec709,
+    pq_to_linear, linear_to_pq,
+    hlg_to_linear, linear_to_hlg,
+    TransferFunction,
+};
+
+mod adaptation;
+pub use adaptation::{
+    WhitePoint,
+    linear_srgb_to_xyz, xyz_to_linear_srgb,
+    bradford_adaptation, bradford_adaptation_xyz,
+};
+
+mod lut;
+pub use lut::Lut3d;
+
+mod grading;
+pub use grading::{
+    adjust_hsv, apply_color_matrix, apply_lut3d,
+};
+
+mod buffer;
+pub use buffer::{
+    rgb_slice_to_rgba8, rgba8_to_rgb_vec,
+    vector4_slice_to_rgba8, rgba8_to_vector4_vec,
+    planar_to_rgba8, rgba8_to_planar,
+};
+
 pub(crate) fn color_byte_to_color_float( byte:u8 ) -> f32 {
     ( byte as f32 ) / 255.0
 }
 
 pub(crate) fn color_float_to_color_byte( f:f32 ) -> u8 {
-    let result = f * 255.0;
-    if result > 255.0 {
-        return 255_u8;
-    } else {
-        return result as u8
-    }
+    // round to nearest so byte -> float -> byte is lossless, NaN becomes 0
+    ( ( f.clamp( 0.0, 1.0 ) * 255.0 ) + 0.5 ) as u8
 }
\ No newline at end of file
@@ -1,23 +1,50 @@
+
 use core::fmt::Display;
 use core::ops::{
     Add, Sub, Mul, Div
 };
 
+use alloc::{
+    format,
+    string::String,
+};
+
 use crate::functions::hexadecimal::{
     decode_hex_rgb, encode_hex
 };
 
+use crate::types::{
+    Vector3, Vector4,
+};
+
 use super::{
+    HSV,
     color_float_to_color_byte,
     color_byte_to_color_float
 };
 
+/// Order of color channels packed into a `u32`,
+/// from the **most** significant byte to the **least** significant byte
+/// 
+/// `Rgba`: *0xRRGGBBAA*, `Argb`: *0xAARRGGBB* ( *Win32, Java* ),
+/// `Bgra`: *0xBBGGRRAA*, `Abgr`: *0xAABBGGRR* ( *RGBA8 bytes read as little endian u32* )
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChannelOrder {
+    Rgba,
+    Argb,
+    Bgra,
+    Abgr,
+}
+
 /// 32-bit RGB color (*little endian*)
 /// 
 /// A B G R
 /// 
 /// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
 pub struct RGB {
     c:u32
 }
@@ -30,6 +57,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with alpha
+    #[allow(clippy::identity_op)]
     pub fn new_rgba( r:u8, g:u8, b:u8, a:u8 ) -> Self {
         Self {
             c:
@@ -60,6 +88,37 @@ impl RGB {
         Self::from_float_rgba(rgba[0], rgba[1], rgba[2], rgba[3] )
     }
 
+    /// Create new `RGB` from `u32` packed with given channel `order`
+    pub fn from_u32( value:u32, order:ChannelOrder ) -> Self {
+        let [ b0, b1, b2, b3 ] = value.to_be_bytes();
+        match order {
+            ChannelOrder::Rgba => Self::new_rgba( b0, b1, b2, b3 ),
+            ChannelOrder::Argb => Self::new_rgba( b1, b2, b3, b0 ),
+            ChannelOrder::Bgra => Self::new_rgba( b2, b1, b0, b3 ),
+            ChannelOrder::Abgr => Self::new_rgba( b3, b2, b1, b0 ),
+        }
+    }
+
+    /// Create new `RGB` from *0xRRGGBBAA* `u32`
+    pub fn from_u32_rgba( value:u32 ) -> Self {
+        Self::from_u32( value, ChannelOrder::Rgba )
+    }
+
+    /// Create new `RGB` from *0xAARRGGBB* `u32`
+    pub fn from_u32_argb( value:u32 ) -> Self {
+        Self::from_u32( value, ChannelOrder::Argb )
+    }
+
+    /// Create new `RGB` from *0xBBGGRRAA* `u32`
+    pub fn from_u32_bgra( value:u32 ) -> Self {
+        Self::from_u32( value, ChannelOrder::Bgra )
+    }
+
+    /// Create new `RGB` from *0xAABBGGRR* `u32`
+    pub fn from_u32_abgr( value:u32 ) -> Self {
+        Self::from_u32( value, ChannelOrder::Abgr )
+    }
+
     /// Create new `RGB` from hexadecimal code
     /// 
     /// Returns: `RGB` if provided hex was valid
@@ -88,6 +147,78 @@ impl RGB {
         )
     }
 
+    /// Returns: new `RGB` with same `R` `G` `B` and given `alpha`
+    pub fn with_alpha( &self, alpha:u8 ) -> Self {
+        Self::new_rgba( self.r(), self.g(), self.b(), alpha )
+    }
+
+    /// Returns: new `RGB` with same `R` `G` `B` and given **0.0**-**1.0** `alpha`
+    pub fn with_alpha_f32( &self, alpha:f32 ) -> Self {
+        self.with_alpha( color_float_to_color_byte( alpha ) )
+    }
+
+    /// Clamp `R` `G` `B` between **0.0**-**1.0** `min` and `max`, `A` is unchanged
+    /// 
+    /// Panics if `min` is greater than `max` or either is *NaN*
+    /// 
+    /// Returns: new `RGB`
+    pub fn clamp_scalar( &self, min:f32, max:f32 ) -> Self {
+        self.map_rgb_f32( | c | c.clamp( min, max ) )
+    }
+
+    /// Returns: new `RGB` with `R` `G` `B` no greater than **0.0**-**1.0** `max`, `A` is unchanged
+    pub fn min_scalar( &self, max:f32 ) -> Self {
+        self.map_rgb_f32( | c | c.min( max ) )
+    }
+
+    /// Returns: new `RGB` with `R` `G` `B` no smaller than **0.0**-**1.0** `min`, `A` is unchanged
+    pub fn max_scalar( &self, min:f32 ) -> Self {
+        self.map_rgb_f32( | c | c.max( min ) )
+    }
+
+    fn map_rgb_f32<F>( &self, f:F ) -> Self
+    where F:Fn( f32 ) -> f32
+    {
+        Self::new_rgba(
+            color_float_to_color_byte( f( self.r_f32() ) ),
+            color_float_to_color_byte( f( self.g_f32() ) ),
+            color_float_to_color_byte( f( self.b_f32() ) ),
+            self.a(),
+        )
+    }
+
+    /// Returns: `u32` packed with given channel `order`
+    pub fn to_u32( &self, order:ChannelOrder ) -> u32 {
+        let ( r, g, b, a ) = self.as_rgba_tuple();
+        let bytes = match order {
+            ChannelOrder::Rgba => [ r, g, b, a ],
+            ChannelOrder::Argb => [ a, r, g, b ],
+            ChannelOrder::Bgra => [ b, g, r, a ],
+            ChannelOrder::Abgr => [ a, b, g, r ],
+        };
+        u32::from_be_bytes( bytes )
+    }
+
+    /// Returns: *0xRRGGBBAA* `u32`
+    pub fn to_u32_rgba(&self) -> u32 {
+        self.to_u32( ChannelOrder::Rgba )
+    }
+
+    /// Returns: *0xAARRGGBB* `u32`
+    pub fn to_u32_argb(&self) -> u32 {
+        self.to_u32( ChannelOrder::Argb )
+    }
+
+    /// Returns: *0xBBGGRRAA* `u32`
+    pub fn to_u32_bgra(&self) -> u32 {
+        self.to_u32( ChannelOrder::Bgra )
+    }
+
+    /// Returns: *0xAABBGGRR* `u32`
+    pub fn to_u32_abgr(&self) -> u32 {
+        self.to_u32( ChannelOrder::Abgr )
+    }
+
     /// Returns `R` `G` `B` as `[u8;3]`
     pub fn as_rgb_array(&self) -> [u8;3] {
         [ self.r(), self.g(), self.b() ]
@@ -154,7 +285,18 @@ impl RGB {
         )
     }
 
+    /// Returns: new `Vector3` with `R` `G` `B` as **0.0**-**1.0** floats
+    pub fn as_vector3(&self) -> Vector3 {
+        Vector3::from_array( self.as_float_rgb_array() )
+    }
+
+    /// Returns: new `Vector4` with `R` `G` `B` `A` as **0.0**-**1.0** floats
+    pub fn as_vector4(&self) -> Vector4 {
+        Vector4::from_array( self.as_float_rgba_array() )
+    }
+
     /// Returns `R` as `u8`
+    #[allow(clippy::identity_op)]
     pub fn r(&self) -> u8 {
         (self.c >> 0) as u8
     }
@@ -176,6 +318,7 @@ impl RGB {
 
 
     /// Set `R` using `u8` value
+    #[allow(clippy::identity_op)]
     pub fn set_r(&mut self, r:u8) {
         // clear bits first
         self.c &= !(255_u32 << 0);
@@ -209,6 +352,7 @@ impl RGB {
 
 
     /// Returns `R` as `f32`
+    #[allow(clippy::identity_op)]
     pub fn r_f32(&self) -> f32 {
         color_byte_to_color_float((self.c >> 0) as u8)
     }
@@ -271,6 +415,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_red() -> Self {
         Self {
             c:
@@ -282,6 +427,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `G` and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_green() -> Self {
         Self {
             c:
@@ -293,6 +439,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `B` and `A` set to **255** 
+    #[allow(clippy::eq_op, clippy::identity_op)]
     pub fn new_blue() -> Self {
         Self {
             c:
@@ -304,6 +451,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `G` and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_yellow() -> Self {
         Self {
             c:
@@ -315,6 +463,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `G` `B` and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_cyan() -> Self {
         Self {
             c:
@@ -326,6 +475,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `B` and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_magenta() -> Self {
         Self {
             c:
@@ -337,6 +487,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `G` `B` and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_white() -> Self {
         Self {
             c:
@@ -348,6 +499,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `G` `B` set to **0** and `A` set to **255** 
+    #[allow(clippy::eq_op, clippy::identity_op)]
     pub fn new_black() -> Self {
         Self {
             c:
@@ -359,6 +511,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `G` `B` set to **127** and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_gray() -> Self {
         Self {
             c:
@@ -370,6 +523,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `G` `B` set to **127** and `A` set to **255** 
+    #[allow(clippy::identity_op)]
     pub fn new_grey() -> Self {
         Self {
             c:
@@ -381,6 +535,7 @@ impl RGB {
     }
 
     /// Create new `RGB` with `R` `G` `B` and `A` set to **0** 
+    #[allow(clippy::eq_op, clippy::identity_op)]
     pub fn new_clear() -> Self {
         Self {
             c:
@@ -393,7 +548,7 @@ impl RGB {
 }
 
 impl Display for RGB {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "RGB: ( {:0>3}, {:0>3}, {:0>3}, {:0>3} )",
@@ -485,3 +640,77 @@ impl Div<f32> for RGB {
         )
     }
 }
+
+impl From<[u8;3]> for RGB {
+    fn from( rgb:[u8;3] ) -> Self {
+        Self::from_array_rgb( rgb )
+    }
+}
+
+impl From<[u8;4]> for RGB {
+    fn from( rgba:[u8;4] ) -> Self {
+        Self::from_array_rgba( rgba )
+    }
+}
+
+impl From<[f32;3]> for RGB {
+    fn from( rgb:[f32;3] ) -> Self {
+        Self::from_float_array_rgb( rgb )
+    }
+}
+
+impl From<[f32;4]> for RGB {
+    fn from( rgba:[f32;4] ) -> Self {
+        Self::from_float_array_rgba( rgba )
+    }
+}
+
+impl From<HSV> for RGB {
+    fn from( hsv:HSV ) -> Self {
+        hsv.as_rgb()
+    }
+}
+
+impl From<RGB> for [u8;3] {
+    fn from( rgb:RGB ) -> Self {
+        rgb.as_rgb_array()
+    }
+}
+
+impl From<RGB> for [u8;4] {
+    fn from( rgb:RGB ) -> Self {
+        rgb.as_rgba_array()
+    }
+}
+
+impl From<RGB> for [f32;3] {
+    fn from( rgb:RGB ) -> Self {
+        rgb.as_float_rgb_array()
+    }
+}
+
+impl From<RGB> for [f32;4] {
+    fn from( rgb:RGB ) -> Self {
+        rgb.as_float_rgba_array()
+    }
+}
+
+/// Component-wise multiply `R` `G` `B` by **0.0**-**1.0** floats, alpha is kept
+impl Mul<Vector3> for RGB {
+    type Output = RGB;
+    fn mul(self, rhs: Vector3) -> Self {
+        Vector3::scale( &self.as_vector3(), &rhs ).as_color().with_alpha( self.a() )
+    }
+}
+
+impl From<Vector3> for RGB {
+    fn from(v:Vector3) -> Self {
+        v.as_color()
+    }
+}
+
+impl From<Vector4> for RGB {
+    fn from(v:Vector4) -> Self {
+        v.as_color_rgba()
+    }
+}
@@ -0,0 +1,157 @@
+use crate::math;
+
+use super::RGB;
+
+/// Decode *sRGB* encoded channel into linear space
+/// 
+/// `c`: channel between **0.0** and **1.0**
+pub fn srgb_to_linear( c:f32 ) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        math::powf( ( c + 0.055 ) / 1.055, 2.4 )
+    }
+}
+
+/// Encode linear channel into *sRGB*
+/// 
+/// `c`: channel between **0.0** and **1.0**
+pub fn linear_to_srgb( c:f32 ) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        ( 1.055 * math::powf( c, 1.0 / 2.4 ) ) - 0.055
+    }
+}
+
+/// Decode *Rec. 709* encoded channel into linear space
+/// 
+/// `c`: channel between **0.0** and **1.0**
+pub fn rec709_to_linear( c:f32 ) -> f32 {
+    if c < 0.081 {
+        c / 4.5
+    } else {
+        math::powf( ( c + 0.099 ) / 1.099, 1.0 / 0.45 )
+    }
+}
+
+/// Encode linear channel into *Rec. 709*
+/// 
+/// `c`: channel between **0.0** and **1.0**
+pub fn linear_to_rec709( c:f32 ) -> f32 {
+    if c < 0.018 {
+        c * 4.5
+    } else {
+        ( 1.099 * math::powf( c, 0.45 ) ) - 0.099
+    }
+}
+
+// SMPTE ST 2084 constants
+const PQ_M1:f32 = 2610.0 / 16384.0;
+const PQ_M2:f32 = ( 2523.0 / 4096.0 ) * 128.0;
+const PQ_C1:f32 = 3424.0 / 4096.0;
+const PQ_C2:f32 = ( 2413.0 / 4096.0 ) * 32.0;
+const PQ_C3:f32 = ( 2392.0 / 4096.0 ) * 32.0;
+
+/// Decode *PQ* ( *SMPTE ST 2084* ) encoded channel into linear space
+/// 
+/// `c`: channel between **0.0** and **1.0**
+/// 
+/// Returns: linear value where **1.0** is **10000** cd/m²
+pub fn pq_to_linear( c:f32 ) -> f32 {
+    let p = math::powf( c.max( 0.0 ), 1.0 / PQ_M2 );
+    let numerator   = ( p - PQ_C1 ).max( 0.0 );
+    let denominator = PQ_C2 - ( PQ_C3 * p );
+    math::powf( numerator / denominator, 1.0 / PQ_M1 )
+}
+
+/// Encode linear channel into *PQ* ( *SMPTE ST 2084* )
+/// 
+/// `c`: linear value where **1.0** is **10000** cd/m²
+pub fn linear_to_pq( c:f32 ) -> f32 {
+    let y = math::powf( c.max( 0.0 ), PQ_M1 );
+    math::powf( ( PQ_C1 + ( PQ_C2 * y ) ) / ( 1.0 + ( PQ_C3 * y ) ), PQ_M2 )
+}
+
+// ARIB STD-B67 constants
+const HLG_A:f32 = 0.178_832_77;
+const HLG_B:f32 = 1.0 - ( 4.0 * HLG_A );
+const HLG_C:f32 = 0.559_910_7;
+
+/// Decode *HLG* ( *Hybrid Log-Gamma* ) encoded channel into linear scene light
+/// 
+/// `c`: channel between **0.0** and **1.0**
+pub fn hlg_to_linear( c:f32 ) -> f32 {
+    if c <= 0.5 {
+        ( c * c ) / 3.0
+    } else {
+        ( math::exp( ( c - HLG_C ) / HLG_A ) + HLG_B ) / 12.0
+    }
+}
+
+/// Encode linear scene light into *HLG* ( *Hybrid Log-Gamma* )
+/// 
+/// `c`: channel between **0.0** and **1.0**
+pub fn linear_to_hlg( c:f32 ) -> f32 {
+    if c <= 1.0 / 12.0 {
+        math::sqrt( 3.0 * c.max( 0.0 ) )
+    } else {
+        ( HLG_A * math::ln( ( 12.0 * c ) - HLG_B ) ) + HLG_C
+    }
+}
+
+/// Transfer curve used to encode linear channels
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferFunction {
+    Linear,
+    Srgb,
+    Rec709,
+    Pq,
+    Hlg,
+}
+
+impl TransferFunction {
+
+    /// Encode linear channel `c`
+    pub fn encode( &self, c:f32 ) -> f32 {
+        match self {
+            TransferFunction::Linear => c,
+            TransferFunction::Srgb   => linear_to_srgb( c ),
+            TransferFunction::Rec709 => linear_to_rec709( c ),
+            TransferFunction::Pq     => linear_to_pq( c ),
+            TransferFunction::Hlg    => linear_to_hlg( c ),
+        }
+    }
+
+    /// Decode encoded channel `c` into linear space
+    pub fn decode( &self, c:f32 ) -> f32 {
+        match self {
+            TransferFunction::Linear => c,
+            TransferFunction::Srgb   => srgb_to_linear( c ),
+            TransferFunction::Rec709 => rec709_to_linear( c ),
+            TransferFunction::Pq     => pq_to_linear( c ),
+            TransferFunction::Hlg    => hlg_to_linear( c ),
+        }
+    }
+
+    /// Encode `R` `G` `B` of linear `color`, alpha is kept
+    /// 
+    /// Returns: new encoded `RGB`
+    pub fn encode_rgb( &self, color:RGB ) -> RGB {
+        let [ r, g, b ] = color.as_float_rgb_array();
+        RGB::from_float_rgb( self.encode( r ), self.encode( g ), self.encode( b ) )
+            .with_alpha( color.a() )
+    }
+
+    /// Decode `R` `G` `B` of encoded `color` into linear space, alpha is kept
+    /// 
+    /// Returns: new linear `RGB`
+    pub fn decode_rgb( &self, color:RGB ) -> RGB {
+        let [ r, g, b ] = color.as_float_rgb_array();
+        RGB::from_float_rgb( self.decode( r ), self.decode( g ), self.decode( b ) )
+            .with_alpha( color.a() )
+    }
+
+}
@@ -0,0 +1,158 @@
+use core::fmt::Display;
+use alloc::string::String;
+
+use super::{
+    Vector3,
+    Quaternion,
+    Transform,
+};
+
+/// Accumulates variable frame time into fixed simulation steps
+/// 
+/// Call `advance` once per frame, run the returned number of fixed steps,
+/// then blend previous and current simulation state with `alpha`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FixedTimestep {
+    step:f32,
+    accumulator:f32,
+    max_steps:u32,
+}
+
+impl FixedTimestep {
+
+    /// Create new `FixedTimestep`
+    /// 
+    /// `step`: fixed step duration in **seconds**
+    /// 
+    /// `max_steps`: maximum number of steps per frame,
+    /// time that would require more steps is dropped to avoid a *spiral of death*
+    /// 
+    /// Returns: `FixedTimestep` if `step` is finite and larger than **0.0**
+    /// 
+    /// Returns: `String` error if `step` is not finite or not larger than **0.0**
+    pub fn new( step:f32, max_steps:u32 ) -> Result<Self, String> {
+        if !step.is_finite() || step <= 0.0 {
+            return Err( String::from("FIXED TIMESTEP ERROR: Step must be finite and larger than 0.0!") );
+        }
+        Ok( Self { step, accumulator:0.0, max_steps } )
+    }
+
+    /// Returns: fixed step duration in **seconds**
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns: maximum number of steps per frame
+    pub fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+
+    /// Returns: time left over after the last `advance`, always less than `step`
+    pub fn accumulator(&self) -> f32 {
+        self.accumulator
+    }
+
+    /// Add `frame_delta` seconds to accumulator
+    /// 
+    /// Negative and non-finite `frame_delta` add no time
+    /// 
+    /// Returns: number of fixed steps to run this frame
+    pub fn advance( &mut self, frame_delta:f32 ) -> u32 {
+        if frame_delta.is_finite() {
+            self.accumulator += frame_delta.max( 0.0 );
+        }
+
+        let mut steps = 0;
+        while self.accumulator >= self.step {
+            if steps == self.max_steps {
+                // drop the time we cannot catch up with
+                self.accumulator %= self.step;
+                break;
+            }
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Returns: how far between the previous and current step the frame is,
+    /// between **0.0** and **1.0**
+    pub fn alpha(&self) -> f32 {
+        ( self.accumulator / self.step ).clamp( 0.0, 1.0 )
+    }
+
+    /// Clear accumulated time
+    pub fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+
+    /// Blend `previous` and `current` simulation state using `alpha`
+    /// 
+    /// Returns: new `Vector3` for rendering
+    pub fn interpolate_vector3( &self, previous:&Vector3, current:&Vector3 ) -> Vector3 {
+        Vector3::lerp( previous, current, self.alpha() )
+    }
+
+    /// Blend `previous` and `current` simulation state using `alpha`
+    /// 
+    /// Returns: new `Quaternion` for rendering
+    pub fn interpolate_quaternion( &self, previous:&Quaternion, current:&Quaternion ) -> Quaternion {
+        Quaternion::slerp( previous, current, self.alpha() )
+    }
+
+    /// Blend `previous` and `current` simulation state using `alpha`
+    /// 
+    /// Returns: new `Transform` for rendering
+    pub fn interpolate_transform( &self, previous:&Transform, current:&Transform ) -> Transform {
+        Transform::lerp( previous, current, self.alpha() )
+    }
+
+}
+
+impl Display for FixedTimestep {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "FixedTimestep: step: {}s, accumulator: {}s, max steps: {}",
+            self.step, self.accumulator, self.max_steps
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_invalid_step() {
+        for step in [ 0.0, -0.5, f32::NAN, f32::INFINITY ] {
+            assert!( FixedTimestep::new( step, 4 ).is_err(), "{}", step );
+        }
+        assert!( FixedTimestep::new( 0.25, 4 ).is_ok() );
+    }
+
+    #[test]
+    fn advance() {
+        let mut timestep = FixedTimestep::new( 0.25, 4 ).unwrap();
+        assert_eq!( timestep.advance( 0.625 ), 2 );
+        assert_eq!( timestep.accumulator(), 0.125 );
+        assert_eq!( timestep.alpha(), 0.5 );
+
+        // time past max steps is dropped
+        assert_eq!( timestep.advance( 2.0 ), 4 );
+        assert!( timestep.accumulator() < timestep.step() );
+    }
+
+    #[test]
+    fn advance_ignores_invalid_frame_delta() {
+        let mut timestep = FixedTimestep::new( 0.25, 4 ).unwrap();
+        timestep.advance( 0.125 );
+        for frame_delta in [ -1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY ] {
+            assert_eq!( timestep.advance( frame_delta ), 0 );
+            assert_eq!( timestep.accumulator(), 0.125 );
+            assert_eq!( timestep.alpha(), 0.5 );
+        }
+    }
+
+}
@@ -0,0 +1,231 @@
+use alloc::{
+    collections::BinaryHeap,
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    types::Vector2,
+    functions::pathfinding::MinCost,
+};
+
+/// Cost of a diagonal step
+const DIAGONAL_COST:f32 = core::f32::consts::SQRT_2;
+
+/// Flow field ( *Dijkstra map* ) over a `width` x `height` occupancy grid
+/// 
+/// Every open cell stores its integration cost ( *distance to the nearest goal* )
+/// and a normalized direction towards the neighbor that is closest to a goal
+/// 
+/// Cells move to any of their **8** neighbors, straight steps cost **1.0**, diagonal steps **√2**,
+/// diagonal steps never cut blocked corners
+/// 
+/// Directions are in grid space: **+x** is the next column, **+y** is the next row
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct FlowField {
+    width:u32,
+    height:u32,
+    costs:Vec<f32>,
+    directions:Vec<Vector2>,
+}
+
+impl FlowField {
+
+    /// Build `FlowField` from `blocked` grid stored row by row towards `goals`
+    /// 
+    /// Goals that are blocked are ignored
+    /// 
+    /// Returns: `FlowField` if `blocked` has `width` * `height` cells and every goal is inside the grid
+    /// 
+    /// Returns: `String` error otherwise
+    pub fn new( blocked:&[bool], width:u32, height:u32, goals:&[[u32;2]] ) -> Result<Self, String> {
+        let ( w, h ) = ( width as usize, height as usize );
+        if blocked.len() != w * h {
+            return Err(
+                format!(
+                    "FLOW FIELD ERROR: Expected {} cells, got {}!",
+                    w * h, blocked.len()
+                )
+            );
+        }
+        if let Some( goal ) = goals.iter().find( | goal | goal[0] >= width || goal[1] >= height ) {
+            return Err(
+                format!(
+                    "FLOW FIELD ERROR: Goal [{}, {}] is outside of {}x{} grid!",
+                    goal[0], goal[1], width, height
+                )
+            );
+        }
+
+        let mut costs = vec![ f32::INFINITY; w * h ];
+        let mut frontier = BinaryHeap::new();
+        for goal in goals {
+            let index = ( goal[1] as usize * w ) + goal[0] as usize;
+            if !blocked[index] && costs[index] != 0.0 {
+                costs[index] = 0.0;
+                frontier.push( MinCost { cost:0.0, node:index } );
+            }
+        }
+
+        let open = | x:isize, y:isize | {
+            x >= 0 && y >= 0 && ( x as usize ) < w && ( y as usize ) < h &&
+            !blocked[ ( y as usize * w ) + x as usize ]
+        };
+
+        while let Some( MinCost { cost, node:index } ) = frontier.pop() {
+            if cost > costs[index] {
+                continue;
+            }
+            let ( x, y ) = ( ( index % w ) as isize, ( index / w ) as isize );
+            for ( dx, dy, step ) in Self::steps( x, y, &open ) {
+                let neighbor = ( ( y + dy ) as usize * w ) + ( x + dx ) as usize;
+                let next_cost = cost + step;
+                if next_cost < costs[neighbor] {
+                    costs[neighbor] = next_cost;
+                    frontier.push( MinCost { cost:next_cost, node:neighbor } );
+                }
+            }
+        }
+
+        let mut directions = vec![ Vector2::new_zero(); w * h ];
+        for ( index, direction ) in directions.iter_mut().enumerate() {
+            let cost = costs[index];
+            if cost == 0.0 || !cost.is_finite() {
+                continue;
+            }
+            let ( x, y ) = ( ( index % w ) as isize, ( index / w ) as isize );
+            let mut lowest = cost;
+            for ( dx, dy, _ ) in Self::steps( x, y, &open ) {
+                let neighbor_cost = costs[ ( ( y + dy ) as usize * w ) + ( x + dx ) as usize ];
+                if neighbor_cost < lowest {
+                    lowest = neighbor_cost;
+                    *direction = Vector2::new( dx as f32, dy as f32 ).normal();
+                }
+            }
+        }
+
+        Ok( Self { width, height, costs, directions } )
+    }
+
+    /// Open neighbors of cell `x` `y` with their step cost
+    fn steps<'a, F>( x:isize, y:isize, open:&'a F ) -> impl Iterator<Item = ( isize, isize, f32 )> + 'a
+    where F:Fn( isize, isize ) -> bool
+    {
+        const OFFSETS:[( isize, isize );8] = [
+            ( 1, 0 ), ( -1, 0 ), ( 0, 1 ), ( 0, -1 ),
+            ( 1, 1 ), ( -1, 1 ), ( 1, -1 ), ( -1, -1 ),
+        ];
+        OFFSETS.iter().filter_map( move | &( dx, dy ) | {
+            if !open( x + dx, y + dy ) {
+                return None;
+            }
+            if dx != 0 && dy != 0 {
+                if !open( x + dx, y ) || !open( x, y + dy ) {
+                    return None;
+                }
+                return Some( ( dx, dy, DIAGONAL_COST ) );
+            }
+            Some( ( dx, dy, 1.0 ) )
+        } )
+    }
+
+    /// Returns: width of grid
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns: height of grid
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Integration cost of `cell`
+    /// 
+    /// Returns: distance to the nearest goal, `None` if `cell` is outside the grid, blocked or unreachable
+    pub fn cost( &self, cell:[u32;2] ) -> Option<f32> {
+        self.index( cell ).map( | index | self.costs[index] ).filter( | cost | cost.is_finite() )
+    }
+
+    /// Flow direction of `cell`
+    /// 
+    /// Returns: normalized `Vector2`, **zero** for goals and cells outside the grid, blocked or unreachable
+    pub fn direction( &self, cell:[u32;2] ) -> Vector2 {
+        self.index( cell ).map_or( Vector2::new_zero(), | index | self.directions[index] )
+    }
+
+    /// Returns: integration costs stored row by row, `f32::INFINITY` for blocked or unreachable cells
+    pub fn costs(&self) -> &[f32] {
+        &self.costs
+    }
+
+    /// Returns: flow directions stored row by row
+    pub fn directions(&self) -> &[Vector2] {
+        &self.directions
+    }
+
+    fn index( &self, cell:[u32;2] ) -> Option<usize> {
+        if cell[0] < self.width && cell[1] < self.height {
+            Some( ( cell[1] as usize * self.width as usize ) + cell[0] as usize )
+        } else {
+            None
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close( a:f32, b:f32 ) {
+        assert!( ( a - b ).abs() < 1e-6, "{} != {}", a, b );
+    }
+
+    #[test]
+    fn open_grid_costs_and_directions() {
+        let field = FlowField::new( &[ false; 9 ], 3, 3, &[ [ 1, 1 ] ] ).unwrap();
+        assert_eq!( field.cost( [ 1, 1 ] ), Some( 0.0 ) );
+        assert_eq!( field.cost( [ 1, 0 ] ), Some( 1.0 ) );
+        assert_close( field.cost( [ 0, 0 ] ).unwrap(), DIAGONAL_COST );
+        assert_eq!( field.direction( [ 1, 1 ] ), Vector2::new_zero() );
+        assert_eq!( field.direction( [ 1, 0 ] ), Vector2::new( 0.0, 1.0 ) );
+        let diagonal = field.direction( [ 2, 2 ] );
+        assert_close( diagonal[0], -core::f32::consts::FRAC_1_SQRT_2 );
+        assert_close( diagonal[1], -core::f32::consts::FRAC_1_SQRT_2 );
+    }
+
+    #[test]
+    fn diagonal_steps_do_not_cut_corners() {
+        // . #
+        // . G
+        let blocked = [ false, true, false, false ];
+        let field = FlowField::new( &blocked, 2, 2, &[ [ 1, 1 ] ] ).unwrap();
+        assert_eq!( field.cost( [ 0, 0 ] ), Some( 2.0 ) );
+        assert_eq!( field.direction( [ 0, 0 ] ), Vector2::new( 0.0, 1.0 ) );
+        assert_eq!( field.cost( [ 1, 0 ] ), None );
+    }
+
+    #[test]
+    fn walls_and_unreachable_cells() {
+        // G # .
+        // . # .
+        let blocked = [ false, true, false, false, true, false ];
+        let field = FlowField::new( &blocked, 3, 2, &[ [ 0, 0 ] ] ).unwrap();
+        assert_eq!( field.cost( [ 0, 1 ] ), Some( 1.0 ) );
+        assert_eq!( field.cost( [ 2, 0 ] ), None );
+        assert_eq!( field.direction( [ 2, 1 ] ), Vector2::new_zero() );
+        assert_eq!( field.cost( [ 5, 5 ] ), None );
+        assert!( field.costs()[2].is_infinite() );
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!( FlowField::new( &[ false; 5 ], 3, 2, &[] ).is_err() );
+        assert!( FlowField::new( &[ false; 6 ], 3, 2, &[ [ 3, 0 ] ] ).is_err() );
+    }
+
+}
@@ -0,0 +1,113 @@
+use core::fmt::Display;
+
+use super::{
+    Vector2, Vector3, Matrix4x4,
+    orbit_camera::{
+        PITCH_LIMIT,
+        yaw_pitch_to_forward,
+    },
+};
+
+/// First-person ( *free-fly* ) camera
+/// 
+/// `yaw` rotates around the **+y** axis, `pitch` tilts up and down,
+/// angles are in **Radians**
+/// 
+/// Camera never rolls, *right* and *up* are recomputed from world **+y**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FlyCamera {
+    position:Vector3,
+    yaw:f32,
+    pitch:f32,
+}
+
+impl FlyCamera {
+
+    /// Create new `FlyCamera`
+    /// 
+    /// `pitch` is clamped just short of straight up and down
+    pub fn new( position:Vector3, yaw:f32, pitch:f32 ) -> Self {
+        Self { position, yaw, pitch:pitch.clamp( -PITCH_LIMIT, PITCH_LIMIT ) }
+    }
+
+    /// Returns: camera position in world space
+    pub fn position(&self) -> &Vector3 {
+        &self.position
+    }
+
+    /// Set camera position
+    pub fn set_position( &mut self, position:Vector3 ) {
+        self.position = position;
+    }
+
+    /// Returns: rotation around **+y** axis in **Radians**
+    pub fn yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    /// Returns: tilt in **Radians**
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    /// Rotate by `delta` ( *yaw, pitch* ) in **Radians**
+    /// 
+    /// Mouse deltas should be scaled by sensitivity beforehand,
+    /// pitch is clamped just short of straight up and down
+    pub fn rotate( &mut self, delta:&Vector2 ) {
+        self.yaw  += delta[0];
+        self.pitch = ( self.pitch + delta[1] ).clamp( -PITCH_LIMIT, PITCH_LIMIT );
+    }
+
+    /// Move camera along its local axes
+    /// 
+    /// `delta`: **x** = right, **y** = up, **z** = forward
+    pub fn translate_local( &mut self, delta:&Vector3 ) {
+        self.position = self.position +
+            ( self.right() * delta[0] ) +
+            ( self.up() * delta[1] ) +
+            ( self.forward() * delta[2] );
+    }
+
+    /// Move camera along local **x** and **z** axes, keeping height
+    /// 
+    /// `delta`: **x** = right, **y** = forward
+    pub fn translate_horizontal( &mut self, delta:&Vector2 ) {
+        let forward = yaw_pitch_to_forward( self.yaw, 0.0 );
+        self.position = self.position +
+            ( self.right() * delta[0] ) +
+            ( forward * delta[1] );
+    }
+
+    /// Returns: normalized direction camera is looking at
+    pub fn forward(&self) -> Vector3 {
+        yaw_pitch_to_forward( self.yaw, self.pitch )
+    }
+
+    /// Returns: normalized camera *right* direction, always horizontal
+    pub fn right(&self) -> Vector3 {
+        Vector3::cross( &self.forward(), &Vector3::new_up() ).normal()
+    }
+
+    /// Returns: normalized camera *up* direction
+    pub fn up(&self) -> Vector3 {
+        Vector3::cross( &self.right(), &self.forward() )
+    }
+
+    /// Returns: new **view** `Matrix4x4`
+    pub fn view_matrix(&self) -> Matrix4x4 {
+        Matrix4x4::new_view( &self.position, &self.forward(), &Vector3::new_up() )
+    }
+
+}
+
+impl Display for FlyCamera {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "FlyCamera: position: {}, yaw: {}, pitch: {}",
+            self.position, self.yaw, self.pitch
+        )
+    }
+}
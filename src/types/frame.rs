@@ -0,0 +1,128 @@
+use core::fmt::Display;
+
+use super::{
+    Vector3,
+    Matrix3x3,
+};
+
+/// Orthonormal `tangent`, `bitangent`, `normal` basis ( *TBN* )
+/// 
+/// Local coordinates are ( *tangent*, *bitangent*, *normal* ) so
+/// local **+z** is the normal
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Frame {
+    tangent:Vector3,
+    bitangent:Vector3,
+    normal:Vector3,
+}
+
+impl Frame {
+
+    /// Create new `Frame` from `tangent`, `bitangent` and `normal`
+    /// 
+    /// Axes should be orthonormal, use `orthonormalize` otherwise
+    pub fn new( tangent:Vector3, bitangent:Vector3, normal:Vector3 ) -> Self {
+        Self { tangent, bitangent, normal }
+    }
+
+    /// Create new `Frame` around `normal` with an arbitrary but continuous tangent
+    /// 
+    /// `normal` is normalized
+    /// 
+    /// Uses branchless basis construction by *Duff et al.*
+    pub fn from_normal( normal:&Vector3 ) -> Self {
+        let n = normal.normal();
+        let sign = if n[2] >= 0.0 { 1.0 } else { -1.0 };
+        let a = -1.0 / ( sign + n[2] );
+        let b = n[0] * n[1] * a;
+        Self {
+            tangent:Vector3::new( 1.0 + ( sign * n[0] * n[0] * a ), sign * b, -sign * n[0] ),
+            bitangent:Vector3::new( b, sign + ( n[1] * n[1] * a ), -n[1] ),
+            normal:n,
+        }
+    }
+
+    /// Create new `Frame` from `normal` and approximate `tangent`
+    /// 
+    /// `tangent` is made orthogonal to `normal` ( *Gram-Schmidt* ),
+    /// bitangent is `cross( normal, tangent )`
+    pub fn from_tangent_normal( tangent:&Vector3, normal:&Vector3 ) -> Self {
+        let normal = normal.normal();
+        let tangent = ( *tangent - ( normal * Vector3::dot( &normal, tangent ) ) ).normal();
+        Self { tangent, bitangent:Vector3::cross( &normal, &tangent ), normal }
+    }
+
+    /// Returns: `reference` to tangent
+    pub fn tangent(&self) -> &Vector3 {
+        &self.tangent
+    }
+
+    /// Returns: `reference` to bitangent
+    pub fn bitangent(&self) -> &Vector3 {
+        &self.bitangent
+    }
+
+    /// Returns: `reference` to normal
+    pub fn normal(&self) -> &Vector3 {
+        &self.normal
+    }
+
+    /// Transform world space `v` into local space of `Frame`
+    /// 
+    /// Returns: new `Vector3`
+    pub fn to_local( &self, v:&Vector3 ) -> Vector3 {
+        Vector3::new(
+            Vector3::dot( v, &self.tangent ),
+            Vector3::dot( v, &self.bitangent ),
+            Vector3::dot( v, &self.normal ),
+        )
+    }
+
+    /// Transform local space `v` of `Frame` into world space
+    /// 
+    /// Returns: new `Vector3`
+    pub fn to_world( &self, v:&Vector3 ) -> Vector3 {
+        ( self.tangent * v[0] ) + ( self.bitangent * v[1] ) + ( self.normal * v[2] )
+    }
+
+    /// Returns: new `Matrix3x3` with tangent, bitangent and normal as columns
+    /// 
+    /// Transforms local space into world space
+    pub fn as_matrix3x3(&self) -> Matrix3x3 {
+        Matrix3x3::from_array([
+            self.tangent[0],   self.tangent[1],   self.tangent[2],
+            self.bitangent[0], self.bitangent[1], self.bitangent[2],
+            self.normal[0],    self.normal[1],    self.normal[2],
+        ])
+    }
+
+    // =============================== &mut self =====================================
+    /// Re-orthonormalize axes with *Gram-Schmidt*
+    /// 
+    /// Normal keeps its direction, tangent is made orthogonal to it and
+    /// bitangent is rebuilt keeping its handedness
+    pub fn orthonormalize(&mut self) {
+        self.normal  = self.normal.normal();
+        self.tangent = ( self.tangent - ( self.normal * Vector3::dot( &self.normal, &self.tangent ) ) ).normal();
+
+        let bitangent = Vector3::cross( &self.normal, &self.tangent );
+        self.bitangent = if Vector3::dot( &bitangent, &self.bitangent ) < 0.0 {
+            -bitangent
+        } else {
+            bitangent
+        };
+    }
+
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Frame:\n    tangent: {}\n    bitangent: {}\n    normal: {}",
+            self.tangent, self.bitangent, self.normal
+        )
+    }
+}
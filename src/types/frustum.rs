@@ -0,0 +1,199 @@
+use core::fmt::Display;
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::math;
+
+use super::{
+    Vector3, Matrix4x4, Plane,
+};
+
+/// View frustum described by **6** planes facing inwards
+/// 
+/// Order: *left*, *right*, *bottom*, *top*, *near*, *far*
+/// 
+/// Planes are also kept as separate component arrays so
+/// batch culling loops compile to vector instructions
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Frustum {
+    normal_x:[f32;6],
+    normal_y:[f32;6],
+    normal_z:[f32;6],
+    distance:[f32;6],
+}
+
+impl Frustum {
+
+    /// Create new `Frustum` from `planes` with normalized normals facing inwards
+    pub fn from_planes( planes:&[Plane;6] ) -> Self {
+        let mut result = Self {
+            normal_x:[0.0;6],
+            normal_y:[0.0;6],
+            normal_z:[0.0;6],
+            distance:[0.0;6],
+        };
+        for ( i, plane ) in planes.iter().enumerate() {
+            result.normal_x[i] = plane.normal()[0];
+            result.normal_y[i] = plane.normal()[1];
+            result.normal_z[i] = plane.normal()[2];
+            result.distance[i] = plane.distance();
+        }
+        result
+    }
+
+    /// Extract `Frustum` from `view_projection` matrix ( *Gribb-Hartmann* )
+    /// 
+    /// Expects *OpenGL* clip space ( *z* between **-w** and **w** ),
+    /// use `from_matrix_depth_range` for *Direct3D*, *Vulkan* or *reversed-z* projections
+    /// 
+    /// Planes are in the space `view_projection` transforms from
+    pub fn from_matrix( view_projection:&Matrix4x4 ) -> Self {
+        Self::from_matrix_depth_range( view_projection, -1.0, 1.0 )
+    }
+
+    /// Extract `Frustum` from `view_projection` matrix ( *Gribb-Hartmann* )
+    /// 
+    /// `near_depth`, `far_depth`: normalized device *z* of near and far planes,
+    /// **-1.0**, **1.0** for `new_perspective`,
+    /// **0.0**, **1.0** for *Direct3D* and *Vulkan*,
+    /// **1.0**, **0.0** for `new_perspective_reversed_z`
+    /// 
+    /// Planes are in the space `view_projection` transforms from
+    pub fn from_matrix_depth_range( view_projection:&Matrix4x4, near_depth:f32, far_depth:f32 ) -> Self {
+        let m = view_projection;
+        let row = | i:usize | [ m[i], m[i + 4], m[i + 8], m[i + 12] ];
+        let ( x, y, z, w ) = ( row( 0 ), row( 1 ), row( 2 ), row( 3 ) );
+
+        let plane = | a:[f32;4], b:[f32;4], sign:f32 | {
+            let v = [
+                a[0] + ( b[0] * sign ),
+                a[1] + ( b[1] * sign ),
+                a[2] + ( b[2] * sign ),
+                a[3] + ( b[3] * sign ),
+            ];
+            let length = math::sqrt( ( v[0] * v[0] ) + ( v[1] * v[1] ) + ( v[2] * v[2] ) );
+            Plane::new( Vector3::new( v[0] / length, v[1] / length, v[2] / length ), v[3] / length )
+        };
+
+        // depth planes: z between near_depth * w and far_depth * w
+        let direction = if far_depth < near_depth { -1.0 } else { 1.0 };
+        let depth_plane = | depth:f32, sign:f32 | plane( w.map( | c | c * -depth * sign ), z, sign );
+
+        Self::from_planes( &[
+            plane( w, x,  1.0 ),
+            plane( w, x, -1.0 ),
+            plane( w, y,  1.0 ),
+            plane( w, y, -1.0 ),
+            depth_plane( near_depth,  direction ),
+            depth_plane( far_depth,  -direction ),
+        ] )
+    }
+
+    /// Returns: `index` plane, see `Frustum` for order
+    pub fn plane( &self, index:usize ) -> Plane {
+        Plane::new(
+            Vector3::new( self.normal_x[index], self.normal_y[index], self.normal_z[index] ),
+            self.distance[index]
+        )
+    }
+
+    /// Returns: `true` if `point` is inside frustum ( *inclusive* )
+    pub fn contains_point( &self, point:&Vector3 ) -> bool {
+        self.min_signed_distance( point ) >= 0.0
+    }
+
+    /// Returns: `true` if sphere at `center` with `radius` is at least partially inside frustum
+    /// 
+    /// Conservative, spheres near frustum corners may pass
+    pub fn intersects_sphere( &self, center:&Vector3, radius:f32 ) -> bool {
+        self.min_signed_distance( center ) >= -radius
+    }
+
+    /// Test every point in `points` against frustum
+    /// 
+    /// Returns: `Vec` with `true` for every point inside frustum
+    pub fn cull_points( &self, points:&[Vector3] ) -> Vec<bool> {
+        points.iter().map( | point | self.contains_point( point ) ).collect()
+    }
+
+    /// Test every sphere ( *center, radius* ) in `spheres` against frustum
+    /// 
+    /// Returns: `Vec` with `true` for every sphere at least partially inside frustum
+    pub fn cull_spheres( &self, spheres:&[( Vector3, f32 )] ) -> Vec<bool> {
+        spheres.iter().map( | ( center, radius ) | self.intersects_sphere( center, *radius ) ).collect()
+    }
+
+    /// Test every point in `points` against frustum
+    /// 
+    /// Returns: `Vec` of `u64` words, bit `i % 64` of word `i / 64` is set for every point inside frustum
+    pub fn cull_points_bitmask( &self, points:&[Vector3] ) -> Vec<u64> {
+        let mut result = vec![ 0_u64; points.len().div_ceil( 64 ) ];
+        for ( word, chunk ) in result.iter_mut().zip( points.chunks( 64 ) ) {
+            for ( bit, point ) in chunk.iter().enumerate() {
+                *word |= ( self.contains_point( point ) as u64 ) << bit;
+            }
+        }
+        result
+    }
+
+    /// Smallest signed distance from `point` to any plane,
+    /// fixed size loop over component arrays so it vectorizes
+    fn min_signed_distance( &self, point:&Vector3 ) -> f32 {
+        let mut distances = [0.0;6];
+        for ( i, distance ) in distances.iter_mut().enumerate() {
+            *distance =
+                ( self.normal_x[i] * point[0] ) +
+                ( self.normal_y[i] * point[1] ) +
+                ( self.normal_z[i] * point[2] ) +
+                self.distance[i];
+        }
+        distances.iter().copied().fold( f32::INFINITY, f32::min )
+    }
+
+}
+
+impl Display for Frustum {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Frustum:" )?;
+        for i in 0..6 {
+            write!( f, "\n    {}", self.plane( i ) )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_culls_depth( frustum:&Frustum ) {
+        // near 0.5, far 50.0, looking down -z
+        assert!( frustum.contains_point( &Vector3::new( 0.0, 0.0, -0.6 ) ) );
+        assert!( frustum.contains_point( &Vector3::new( 0.0, 0.0, -49.0 ) ) );
+        assert!( !frustum.contains_point( &Vector3::new( 0.0, 0.0, -0.4 ) ) );
+        assert!( !frustum.contains_point( &Vector3::new( 0.0, 0.0, -51.0 ) ) );
+        assert!( !frustum.contains_point( &Vector3::new( 0.0, 0.0, 1.0 ) ) );
+        assert!( !frustum.contains_point( &Vector3::new( 20.0, 0.0, -10.0 ) ) );
+
+        assert!( ( frustum.plane( 4 ).normal()[2] + 1.0 ).abs() < 1e-5 );
+        assert!( ( frustum.plane( 5 ).normal()[2] - 1.0 ).abs() < 1e-5 );
+    }
+
+    #[test]
+    fn from_matrix() {
+        let projection = Matrix4x4::new_perspective( 1.2, 1.5, 0.5, 50.0 );
+        assert_culls_depth( &Frustum::from_matrix( &projection ) );
+    }
+
+    #[test]
+    fn from_matrix_depth_range_reversed_z() {
+        let projection = Matrix4x4::new_perspective_reversed_z( 1.2, 1.5, 0.5, 50.0 );
+        assert_culls_depth( &Frustum::from_matrix_depth_range( &projection, 1.0, 0.0 ) );
+    }
+
+}
@@ -0,0 +1,121 @@
+use core::fmt::Display;
+
+use crate::functions::{
+    lerp_unclamped,
+    inverse_lerp,
+};
+
+/// Closed range of `f32` values between `min` and `max`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Interval {
+    min:f32,
+    max:f32,
+}
+
+impl Interval {
+
+    /// Create new `Interval`
+    /// 
+    /// `a` and `b` are swapped if `a` is larger than `b`
+    pub fn new( a:f32, b:f32 ) -> Self {
+        if a > b {
+            Self { min:b, max:a }
+        } else {
+            Self { min:a, max:b }
+        }
+    }
+
+    /// Create new `Interval` from **0.0** to **1.0**
+    pub fn new_unit() -> Self {
+        Self::new( 0.0, 1.0 )
+    }
+
+    /// Returns: smallest value in interval
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns: largest value in interval
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns: distance between `min` and `max`
+    pub fn length(&self) -> f32 {
+        self.max - self.min
+    }
+
+    /// Returns: value halfway between `min` and `max`
+    pub fn center(&self) -> f32 {
+        ( self.min + self.max ) / 2.0
+    }
+
+    /// Returns: `true` if `value` is within interval ( *inclusive* )
+    pub fn contains( &self, value:f32 ) -> bool {
+        value >= self.min && value <= self.max
+    }
+
+    /// Returns: `true` if `other` overlaps this interval
+    pub fn overlaps( &self, other:&Self ) -> bool {
+        self.min <= other.max && other.min <= self.max
+    }
+
+    /// Returns: `value` clamped between `min` and `max`
+    pub fn clamp( &self, value:f32 ) -> f32 {
+        value.clamp( self.min, self.max )
+    }
+
+    /// Returns: new `Interval` grown by `amount` on both sides
+    pub fn expand( &self, amount:f32 ) -> Self {
+        Self::new( self.min - amount, self.max + amount )
+    }
+
+    /// Linearly interpolate from `min` to `max`
+    /// 
+    /// Does **not** clamp `t`
+    pub fn lerp( &self, t:f32 ) -> f32 {
+        lerp_unclamped( self.min, self.max, t )
+    }
+
+    /// Returns: `t` for `value` between `min` and `max`
+    pub fn inverse_lerp( &self, value:f32 ) -> f32 {
+        inverse_lerp( self.min, self.max, value )
+    }
+
+    /// Takes `value` within this interval into `other` interval
+    pub fn map_to( &self, other:&Self, value:f32 ) -> f32 {
+        other.lerp( self.inverse_lerp( value ) )
+    }
+
+    // =============================== STATIC =====================================
+    /// Returns: overlapping part of `a` and `b`
+    /// 
+    /// Returns: `None` if `a` and `b` do not overlap
+    pub fn intersect( a:&Self, b:&Self ) -> Option<Self> {
+        if a.overlaps( b ) {
+            Some( Self {
+                min:a.min.max( b.min ),
+                max:a.max.min( b.max ),
+            } )
+        } else {
+            None
+        }
+    }
+
+    /// Returns: smallest `Interval` that contains both `a` and `b`
+    pub fn union( a:&Self, b:&Self ) -> Self {
+        Self {
+            min:a.min.min( b.min ),
+            max:a.max.max( b.max ),
+        }
+    }
+
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "[ {:7.3}, {:7.3} ]", self.min, self.max )
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt::Display;
+use core::ops::AddAssign;
+
+/// Running `f32` sum with compensation for lost low-order bits
+/// 
+/// Uses *Kahan* summation with *Neumaier's* magnitude check,
+/// compensation is fed back into every added value so it never grows large
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`, `Default`
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct KahanAccumulator {
+    sum:f32,
+    compensation:f32,
+}
+
+impl KahanAccumulator {
+
+    /// Create new `KahanAccumulator` with sum of **0.0**
+    pub fn new() -> Self {
+        Self { sum:0.0, compensation:0.0 }
+    }
+
+    /// Returns: compensated sum of all added values
+    pub fn sum(&self) -> f32 {
+        self.sum - self.compensation
+    }
+
+    // =============================== &mut self =====================================
+    /// Add `value` to sum
+    pub fn add( &mut self, value:f32 ) {
+        let value = value - self.compensation;
+        let total = self.sum + value;
+        // error of the addition, taken from the smaller operand
+        self.compensation = if self.sum.abs() >= value.abs() {
+            ( total - self.sum ) - value
+        } else {
+            ( total - value ) - self.sum
+        };
+        self.sum = total;
+    }
+
+    /// Reset sum to **0.0**
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+}
+
+impl AddAssign<f32> for KahanAccumulator {
+    fn add_assign( &mut self, rhs:f32 ) {
+        self.add( rhs );
+    }
+}
+
+impl FromIterator<f32> for KahanAccumulator {
+    fn from_iter<I>( iter:I ) -> Self
+    where I:IntoIterator<Item = f32>
+    {
+        let mut result = Self::new();
+        for value in iter {
+            result.add( value );
+        }
+        result
+    }
+}
+
+impl Display for KahanAccumulator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "{}", self.sum() )
+    }
+}
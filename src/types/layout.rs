@@ -0,0 +1,69 @@
+use alloc::{
+    format,
+    string::String,
+};
+
+/// Byte order used when converting types to and from bytes
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// Returns: byte order of the target platform
+    pub fn native() -> Self {
+        if cfg!( target_endian = "big" ) {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+}
+
+/// Order of matrix cells when converting matrices to and from bytes
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MatrixLayout {
+    /// Cells ordered column by column ( *OpenGL, Vulkan* )
+    ColumnMajor,
+    /// Cells ordered row by row ( *DirectX* )
+    RowMajor,
+}
+
+pub(crate) fn f32_to_bytes( f:f32, endianness:Endianness ) -> [u8;4] {
+    match endianness {
+        Endianness::Little => f.to_le_bytes(),
+        Endianness::Big    => f.to_be_bytes(),
+    }
+}
+
+pub(crate) fn f32_from_bytes( bytes:[u8;4], endianness:Endianness ) -> f32 {
+    match endianness {
+        Endianness::Little => f32::from_le_bytes( bytes ),
+        Endianness::Big    => f32::from_be_bytes( bytes ),
+    }
+}
+
+/// Reads `N` `f32`s from `bytes`
+pub(crate) fn f32_array_from_bytes<const N:usize>(
+    bytes:&[u8], endianness:Endianness
+) -> Result<[f32;N], String> {
+    if bytes.len() != N * 4 {
+        return Err(
+            format!(
+                "FROM BYTES ERROR: Expected {} bytes, got {}!",
+                N * 4, bytes.len()
+            )
+        );
+    }
+
+    let mut result = [0.0; N];
+    for ( value, chunk ) in result.iter_mut().zip( bytes.chunks_exact( 4 ) ) {
+        *value = f32_from_bytes( [chunk[0], chunk[1], chunk[2], chunk[3]], endianness );
+    }
+    Ok( result )
+}
@@ -0,0 +1,469 @@
+use core::fmt::Display;
+use core::ops::{
+    Add, Sub, Mul, Div, Index, IndexMut
+};
+
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+use crate::{
+    math,
+    functions::angles::degrees_to_radians,
+    types::layout::{
+        Endianness,
+        MatrixLayout,
+        f32_to_bytes,
+        f32_array_from_bytes,
+    },
+    types::vector::{
+        add_components,
+        sub_components,
+        scale_components,
+        cross_components,
+        Vector3,
+        Vector2,
+    },
+};
+
+use super::Matrix4x4;
+
+/// 3 by 3 Matrix in *column-major* order
+/// 
+/// Indexable with **[ ]** (*as 1D array*)
+/// 
+/// Use associated fn's `nm_index` and `nm_mut_index` to
+/// index with 2D coordinates (*column major*)
+/// 
+/// The following table shows what cell each index corresponds to
+/// 
+/// `0`&nbsp;&nbsp;`3`&nbsp;&nbsp;`6`
+/// 
+/// `1`&nbsp;&nbsp;`4`&nbsp;&nbsp;`7`
+/// 
+/// `2`&nbsp;&nbsp;`5`&nbsp;&nbsp;`8`
+/// 
+/// Implements `Copy`, `Clone`, `PartialEq`, `Debug`
+/// 
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct Matrix3x3 {
+    data:[f32;9]
+}
+
+impl Matrix3x3 {
+
+    /// Creates a new `Matrix3x3` with all cells set to **0.0**
+    pub fn new_zero() -> Self {
+        Self {
+            data: [
+                0.0,0.0,0.0,
+                0.0,0.0,0.0,
+                0.0,0.0,0.0,
+            ]
+        }
+    }
+
+    /// Creates a new `Matrix3x3` with diagonal cells set to **1.0**
+    pub fn new_identity() -> Self {
+        Self {
+            data: [
+                1.0,0.0,0.0,
+                0.0,1.0,0.0,
+                0.0,0.0,1.0,
+            ]
+        }
+    }
+
+    /// Creates a new `Matrix3x3` for **translating** 2D coordinates
+    pub fn new_translate_2d( t:&[f32;2] ) -> Self {
+        let mut result = Self::new_identity();
+
+        // data indeces( 6, 7 ) are where translation values go
+        result.data[6] = t[0];
+        result.data[7] = t[1];
+
+        result
+    }
+
+    /// Creates a new `Matrix3x3` for **rotating** 2D coordinates
+    /// 
+    /// Angle is in **Radians**, positive angles rotate counter-clockwise
+    pub fn new_rotate_2d( theta_rad:f32 ) -> Self {
+        let mut result = Self::new_identity();
+
+        result.data[0] =  math::cos( theta_rad );
+        result.data[1] =  math::sin( theta_rad );
+        result.data[3] = -math::sin( theta_rad );
+        result.data[4] =  math::cos( theta_rad );
+
+        result
+    }
+
+    /// Creates a new `Matrix3x3` for **rotating** 2D coordinates
+    /// 
+    /// Angle is in **Degrees**, positive angles rotate counter-clockwise
+    pub fn new_rotate_2d_deg( theta_deg:f32 ) -> Self {
+        Self::new_rotate_2d( degrees_to_radians( theta_deg ) )
+    }
+
+    /// Creates a new `Matrix3x3` for **rotating** 2D coordinates around `pivot`
+    /// 
+    /// Angle is in **Radians**, positive angles rotate counter-clockwise
+    pub fn new_rotate_around_2d( pivot:&Vector2, theta_rad:f32 ) -> Self {
+        Self::new_translate_2d( pivot.as_array() ) *
+        Self::new_rotate_2d( theta_rad ) *
+        Self::new_translate_2d( &[ -pivot[0], -pivot[1] ] )
+    }
+
+    /// Creates a new `Matrix3x3` for **rotating** 2D coordinates around `pivot`
+    /// 
+    /// Angle is in **Degrees**, positive angles rotate counter-clockwise
+    pub fn new_rotate_around_2d_deg( pivot:&Vector2, theta_deg:f32 ) -> Self {
+        Self::new_rotate_around_2d( pivot, degrees_to_radians( theta_deg ) )
+    }
+
+    /// Creates a new `Matrix3x3` for **scaling** 2D coordinates
+    pub fn new_scale_2d( s:&[f32;2] ) -> Self {
+        let mut result = Self::new_identity();
+
+        result.data[0] = s[0];
+        result.data[4] = s[1];
+
+        result
+    }
+
+    /// Creates a new `Matrix3x3` for animating **texture coordinates**
+    /// 
+    /// Rotation and scale are applied around the center of the texture ( *0.5, 0.5* ),
+    /// then coordinates are offset
+    /// 
+    /// `rotation` is in **Radians**
+    pub fn new_uv_transform( offset:&Vector2, rotation:f32, scale:&Vector2 ) -> Self {
+        Self::new_translate_2d( &[ offset[0] + 0.5, offset[1] + 0.5 ] ) *
+        Self::new_rotate_2d( rotation ) *
+        Self::new_scale_2d( scale.as_array() ) *
+        Self::new_translate_2d( &[ -0.5, -0.5 ] )
+    }
+
+    /// Creates `Matrix3x3` from upper-left 3x3 cells of `Matrix4x4`
+    /// 
+    /// Translation and projection cells are **lost** in conversion!
+    pub fn from_matrix4x4( m:&Matrix4x4 ) -> Self {
+        Self {
+            data:[
+                m[0], m[1], m[2],
+                m[4], m[5], m[6],
+                m[8], m[9], m[10],
+            ]
+        }
+    }
+
+    /// Creates `Matrix3x3` from `array` in *column-major* order
+    pub fn from_array(array:[f32;9]) -> Self {
+        Self { data:array }
+    }
+
+    /// Creates `Matrix3x3` from `array` in *row-major* order
+    /// 
+    /// *Column-major* order is preferred as it's how the underlying
+    /// data is actually ordered.
+    pub fn from_array_row_major(array:[f32;9]) -> Self {
+        Self{
+            data:[
+                array[0], array[3], array[6],
+                array[1], array[4], array[7],
+                array[2], array[5], array[8],
+            ]
+        }
+    }
+
+    /// Creates `Matrix3x3` from `bytes` ordered with given `layout` and `endianness`
+    /// 
+    /// `padded`: every column ( *or row* ) is followed by **4** bytes of padding ( *std140* ),
+    /// **48** bytes are expected instead of **36**
+    /// 
+    /// Returns: `Matrix3x3` if `bytes` has correct length
+    /// 
+    /// Returns: `String` error if `bytes` has incorrect length
+    pub fn from_bytes(
+        bytes:&[u8], layout:MatrixLayout, endianness:Endianness, padded:bool
+    ) -> Result<Self, String> {
+        let array = if padded {
+            let padded_array = f32_array_from_bytes::<12>( bytes, endianness )?;
+            [
+                padded_array[0], padded_array[1], padded_array[2],
+                padded_array[4], padded_array[5], padded_array[6],
+                padded_array[8], padded_array[9], padded_array[10],
+            ]
+        } else {
+            f32_array_from_bytes::<9>( bytes, endianness )?
+        };
+
+        match layout {
+            MatrixLayout::ColumnMajor => Ok( Self::from_array( array ) ),
+            MatrixLayout::RowMajor    => Ok( Self::from_array_row_major( array ) ),
+        }
+    }
+
+    /// Returns: **new** `Vec` of bytes ordered with given `layout` and `endianness`
+    /// 
+    /// `padded`: every column ( *or row* ) is followed by **4** bytes of zeroes ( *std140* )
+    pub fn to_bytes( &self, layout:MatrixLayout, endianness:Endianness, padded:bool ) -> Vec<u8> {
+        let array = match layout {
+            MatrixLayout::ColumnMajor => self.data,
+            MatrixLayout::RowMajor    => self.as_array_row_major(),
+        };
+
+        let mut result = Vec::with_capacity( if padded { 48 } else { 36 } );
+        for line in array.chunks_exact( 3 ) {
+            for value in line.iter() {
+                result.extend_from_slice( &f32_to_bytes( *value, endianness ) );
+            }
+            if padded {
+                result.extend_from_slice( &[0, 0, 0, 0] );
+            }
+        }
+        result
+    }
+
+    /// Returns: `reference` to data `array` in *column-major* order
+    pub fn as_array(&self) -> &[f32;9] {
+        &self.data
+    }
+
+    /// Returns: `mutable reference` to data `array` in *column-major* order
+    pub fn as_mut_array(&mut self) -> &mut[f32;9] {
+        &mut self.data
+    }
+
+    /// Returns: **new** `array` of data in *row-major* order
+    /// 
+    /// *Column-major* ordering is preferred as that is how the underlying data is ordered.
+    pub fn as_array_row_major(&self) -> [f32;9] {
+        [
+            self.data[0], self.data[3], self.data[6],
+            self.data[1], self.data[4], self.data[7],
+            self.data[2], self.data[5], self.data[8],
+        ]
+    }
+
+    /// Index `Matrix3x3` with `row` and `column` index instead of 1D index
+    /// 
+    /// Returns: `reference` to value at given index
+    pub fn nm_index( &self, row:usize, column:usize ) -> &f32 {
+        debug_assert!(row < 3 && column < 3);
+        &self[row + (column * 3)]
+    }
+
+    /// Index `Matrix3x3` with `row` and `column` index instead of 1D index
+    /// 
+    /// Returns: `mutable reference` to value at given index
+    pub fn nm_mut_index( &mut self, row:usize, column:usize ) -> &mut f32 {
+        debug_assert!(row < 3 && column < 3);
+        &mut self[row + (column * 3)]
+    }
+
+    /// Returns: new *transposed* `Matrix3x3`
+    pub fn transpose(&self) -> Self {
+        Self::from_array( self.as_array_row_major() )
+    }
+
+    /// Returns: *determinant* of `Matrix3x3`
+    pub fn determinant(&self) -> f32 {
+        ( self[0] * ( ( self[4] * self[8] ) - ( self[7] * self[5] ) ) ) -
+        ( self[3] * ( ( self[1] * self[8] ) - ( self[7] * self[2] ) ) ) +
+        ( self[6] * ( ( self[1] * self[5] ) - ( self[4] * self[2] ) ) )
+    }
+
+    /// Returns: new *inverted* `Matrix3x3`
+    /// 
+    /// Returns: `String` error if `Matrix3x3` is **not** invertible ( *determinant is 0.0* )
+    pub fn inverse(&self) -> Result<Self, String> {
+        let determinant = self.determinant();
+        if determinant == 0.0 {
+            return Err( String::from("MATRIX3X3 ERROR: Matrix with determinant of 0.0 cannot be inverted!") );
+        }
+
+        let c0 = [ self[0], self[1], self[2] ];
+        let c1 = [ self[3], self[4], self[5] ];
+        let c2 = [ self[6], self[7], self[8] ];
+
+        // rows of the inverse are cross products of the columns
+        let r0 = cross_components( &c1, &c2 );
+        let r1 = cross_components( &c2, &c0 );
+        let r2 = cross_components( &c0, &c1 );
+
+        Ok( Self::from_array_row_major([
+            r0[0], r0[1], r0[2],
+            r1[0], r1[1], r1[2],
+            r2[0], r2[1], r2[2],
+        ]) / determinant )
+    }
+
+    /// Multiply `Vector3` by `Matrix3x3`
+    /// 
+    /// Returns: `Vector3`
+    pub fn mul_vector3( &self, v:&Vector3 ) -> Vector3 {
+        Vector3::from_array([
+            ( self[0] * v[0] ) + ( self[3] * v[1] ) + ( self[6] * v[2] ),
+            ( self[1] * v[0] ) + ( self[4] * v[1] ) + ( self[7] * v[2] ),
+            ( self[2] * v[0] ) + ( self[5] * v[1] ) + ( self[8] * v[2] ),
+        ])
+    }
+
+    /// Multiply 2D point `Vector2` by `Matrix3x3`
+    /// 
+    /// Adds **1.0** to end of `Vector2` when calculating result ( *homogenous coordinate* )
+    /// 
+    /// Returns: `Vector2`
+    pub fn mul_vector2( &self, v:&Vector2 ) -> Vector2 {
+        Vector2::from_array([
+            ( self[0] * v[0] ) + ( self[3] * v[1] ) + self[6],
+            ( self[1] * v[0] ) + ( self[4] * v[1] ) + self[7],
+        ])
+    }
+
+}
+
+impl Display for Matrix3x3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Matrix3x3:\n   {:7.2} {:7.2} {:7.2}\n   {:7.2} {:7.2} {:7.2}\n   {:7.2} {:7.2} {:7.2}",
+            self[0], self[3], self[6],
+            self[1], self[4], self[7],
+            self[2], self[5], self[8],
+        )
+    }
+}
+
+/// Create new `Matrix4x4` from `Matrix3x3`
+/// 
+/// Translation cells are set to **0.0** and last diagonal cell to **1.0**
+impl From<Matrix3x3> for Matrix4x4 {
+    fn from( m:Matrix3x3 ) -> Self {
+        Matrix4x4::from_array([
+            m[0], m[1], m[2], 0.0,
+            m[3], m[4], m[5], 0.0,
+            m[6], m[7], m[8], 0.0,
+             0.0,  0.0,  0.0, 1.0,
+        ])
+    }
+}
+
+impl Index<usize> for Matrix3x3 {
+    type Output = f32;
+
+    fn index( &self, index:usize ) -> &f32 {
+        &self.data[index]
+    }
+}
+
+impl IndexMut<usize> for Matrix3x3 {
+    fn index_mut( &mut self, index:usize ) -> &mut f32 {
+        &mut self.data[index]
+    }
+}
+
+impl AsRef<[f32]> for Matrix3x3 {
+    fn as_ref( &self ) -> &[f32] {
+        &self.data
+    }
+}
+
+impl AsMut<[f32]> for Matrix3x3 {
+    fn as_mut( &mut self ) -> &mut [f32] {
+        &mut self.data
+    }
+}
+
+/// Create new `Matrix3x3` from `array` in *column-major* order
+impl From<[f32;9]> for Matrix3x3 {
+    fn from( data:[f32;9] ) -> Self {
+        Self::from_array( data )
+    }
+}
+
+/// Returns: `array` in *column-major* order
+impl From<Matrix3x3> for [f32;9] {
+    fn from( m:Matrix3x3 ) -> Self {
+        m.data
+    }
+}
+
+impl From<&Matrix3x3> for [f32;9] {
+    fn from( m:&Matrix3x3 ) -> Self {
+        m.data
+    }
+}
+
+impl Add for Matrix3x3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = Self::new_zero();
+        add_components(self.as_array(), rhs.as_array(), result.as_mut_array() );
+        result
+    }
+}
+
+impl Sub for Matrix3x3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = Self::new_zero();
+        sub_components(self.as_array(), rhs.as_array(), result.as_mut_array() );
+        result
+    }
+}
+
+impl Mul<f32> for Matrix3x3 {
+
+    type Output = Self;
+
+    fn mul( self, scalar:f32 ) -> Self::Output {
+        let mut result = Self::new_zero();
+        scale_components(self.as_array(), scalar, result.as_mut_array());
+        result
+    }
+
+}
+
+impl Div<f32> for Matrix3x3 {
+
+    type Output = Self;
+
+    fn div( self, scalar:f32 ) -> Self::Output {
+        let mut result = Self::new_zero();
+        scale_components(self.as_array(), 1.0 / scalar, result.as_mut_array());
+        result
+    }
+
+}
+
+impl Mul<Self> for Matrix3x3 {
+
+    type Output = Self;
+
+    fn mul( self, _rhs:Self ) -> Self::Output {
+        Self {
+            data:[
+                // column - 0
+                ( self[0] * _rhs[0] ) + ( self[3] * _rhs[1] ) + ( self[6] * _rhs[2] ),
+                ( self[1] * _rhs[0] ) + ( self[4] * _rhs[1] ) + ( self[7] * _rhs[2] ),
+                ( self[2] * _rhs[0] ) + ( self[5] * _rhs[1] ) + ( self[8] * _rhs[2] ),
+                // column - 1
+                ( self[0] * _rhs[3] ) + ( self[3] * _rhs[4] ) + ( self[6] * _rhs[5] ),
+                ( self[1] * _rhs[3] ) + ( self[4] * _rhs[4] ) + ( self[7] * _rhs[5] ),
+                ( self[2] * _rhs[3] ) + ( self[5] * _rhs[4] ) + ( self[8] * _rhs[5] ),
+                // column - 2
+                ( self[0] * _rhs[6] ) + ( self[3] * _rhs[7] ) + ( self[6] * _rhs[8] ),
+                ( self[1] * _rhs[6] ) + ( self[4] * _rhs[7] ) + ( self[7] * _rhs[8] ),
+                ( self[2] * _rhs[6] ) + ( self[5] * _rhs[7] ) + ( self[8] * _rhs[8] ),
+            ]
+        }
+
+    }
+
+}
@@ -2,16 +2,55 @@ use core::fmt::Display;
 use core::ops::{
     Add, Sub, Mul, Div, Index, IndexMut
 };
+
+use alloc::{
+    string::String,
+    vec::Vec,
+};
 use crate::{
+    math,
+    simd,
+    types::layout::{
+        Endianness,
+        MatrixLayout,
+        f32_to_bytes,
+        f32_array_from_bytes,
+    },
+    types::{ Plane, Quaternion, Transform },
+    functions::angles::degrees_to_radians,
+    types::color::RGB,
     types::vector::{
         add_components,
         sub_components,
         scale_components,
         Vector4,
         Vector3,
+        Vector2,
     },
 };
 
+/// Most specific class of transformation a `Matrix4x4` performs
+/// 
+/// Variants are ordered from most to least specific,
+/// every class is also a member of the classes after it
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum MatrixKind {
+    /// No transformation
+    Identity,
+    /// Translation only
+    Translation,
+    /// Rotation and translation, inverse is the transposed rotation
+    RigidBody,
+    /// Rotation, uniform scale and translation, may mirror
+    Similarity,
+    /// Any combination of rotation, scale, shear and translation
+    Affine,
+    /// Bottom row is **not** 0, 0, 0, 1
+    Projective,
+}
+
 /// 4 by 4 Matrix in *column-major* order
 /// 
 /// Indexable with **[ ]** (*as 1D array*)
@@ -32,6 +71,7 @@ use crate::{
 /// Implements `Copy`, `Clone`, `Debug`
 /// 
 #[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
 pub struct Matrix4x4 {
     data:[f32;16]
 }
@@ -69,6 +109,7 @@ impl Matrix4x4 {
     /// `r`: rotation in **Radians**
     /// 
     /// `s`: scale
+    #[allow(clippy::needless_return)]
     pub fn new_trs( t:&[f32;3], r:&[f32;3], s:&[f32;3] ) -> Self {
         return 
             Self::new_translate(t) *
@@ -76,6 +117,13 @@ impl Matrix4x4 {
             Self::new_scale(s);
     }
 
+    /// Creates a new `Matrix4x4` for **transforming** coordinates
+    /// 
+    /// `r`: rotation in **Degrees**
+    pub fn new_trs_deg( t:&[f32;3], r:&[f32;3], s:&[f32;3] ) -> Self {
+        Self::new_trs( t, &r.map( degrees_to_radians ), s )
+    }
+
     /// Creates a new `Matrix4x4` for **transforming** coordinates with default values
     pub fn new_default_trs() -> Self {
         let zero = [0.0, 0.0, 0.0];
@@ -84,6 +132,7 @@ impl Matrix4x4 {
     }
 
     /// Creates a new `Matrix4x4` for **translating** coordinates
+    #[allow(clippy::needless_return)]
     pub fn new_translate( t:&[f32;3] ) -> Self {
         let mut result = Self::new_identity();
 
@@ -104,49 +153,81 @@ impl Matrix4x4 {
         Self::new_z_rotate(r[2])
     }
 
+    /// Creates a new `Matrix4x4` for **rotating** coordinates from *euler angles*
+    /// 
+    /// Angles are in **Degrees**
+    pub fn new_rotate_deg( r:&[f32;3] ) -> Self {
+        Self::new_rotate( &r.map( degrees_to_radians ) )
+    }
+
     /// Creates a new `Matrix4x4` for **rotating** coordinates around *x axis*
     /// 
     /// Angle is in **Radians**
+    #[allow(clippy::needless_return)]
     pub fn new_x_rotate( theta_rad:f32 ) -> Self {
         let mut result = Self::new_identity();
 
-        result.data[5]  =  theta_rad.cos();
-        result.data[6]  =  theta_rad.sin();
-        result.data[9]  = -theta_rad.sin();
-        result.data[10] =  theta_rad.cos();
+        result.data[5]  =  math::cos( theta_rad );
+        result.data[6]  =  math::sin( theta_rad );
+        result.data[9]  = -math::sin( theta_rad );
+        result.data[10] =  math::cos( theta_rad );
 
         return result;
     }
 
+    /// Creates a new `Matrix4x4` for **rotating** coordinates around *x axis*
+    /// 
+    /// Angle is in **Degrees**
+    pub fn new_x_rotate_deg( theta_deg:f32 ) -> Self {
+        Self::new_x_rotate( degrees_to_radians( theta_deg ) )
+    }
+
     /// Creates a new `Matrix4x4` for **rotating** coordinates around *y axis*
     /// 
     /// Angle is in **Radians**
+    #[allow(clippy::needless_return)]
     pub fn new_y_rotate( theta_rad:f32 ) -> Self {
         let mut result = Self::new_identity();
 
-        result.data[0]  =  theta_rad.cos();
-        result.data[2]  = -theta_rad.sin();
-        result.data[8]  =  theta_rad.sin();
-        result.data[10] =  theta_rad.cos();
+        result.data[0]  =  math::cos( theta_rad );
+        result.data[2]  = -math::sin( theta_rad );
+        result.data[8]  =  math::sin( theta_rad );
+        result.data[10] =  math::cos( theta_rad );
 
         return result;
     }
 
+    /// Creates a new `Matrix4x4` for **rotating** coordinates around *y axis*
+    /// 
+    /// Angle is in **Degrees**
+    pub fn new_y_rotate_deg( theta_deg:f32 ) -> Self {
+        Self::new_y_rotate( degrees_to_radians( theta_deg ) )
+    }
+
     /// Creates a new `Matrix4x4` for **rotating** coordinates around *z axis*
     /// 
     /// Angle is in **Radians**
+    #[allow(clippy::needless_return)]
     pub fn new_z_rotate( theta_rad:f32 ) -> Self {
         let mut result = Self::new_identity();
 
-        result.data[0] =  theta_rad.cos();
-        result.data[1] =  theta_rad.sin();
-        result.data[4] = -theta_rad.sin();
-        result.data[5] =  theta_rad.cos();
+        result.data[0] =  math::cos( theta_rad );
+        result.data[1] =  math::sin( theta_rad );
+        result.data[4] = -math::sin( theta_rad );
+        result.data[5] =  math::cos( theta_rad );
 
         return result;
     }
 
+    /// Creates a new `Matrix4x4` for **rotating** coordinates around *z axis*
+    /// 
+    /// Angle is in **Degrees**
+    pub fn new_z_rotate_deg( theta_deg:f32 ) -> Self {
+        Self::new_z_rotate( degrees_to_radians( theta_deg ) )
+    }
+
     /// Creates a new `Matrix4x4` for scaling coordinates
+    #[allow(clippy::needless_return)]
     pub fn new_scale( s:&[f32;3] ) -> Self {
         let mut result = Self::new_identity();
 
@@ -158,7 +239,363 @@ impl Matrix4x4 {
         return result;
     }
 
+    /// Creates a new `Matrix4x4` for transforming coordinates into **view space**
+    /// 
+    /// View space is *right-handed* and looks down the **-z** axis
+    /// 
+    /// `position`: position of the viewer
+    /// 
+    /// `direction`: direction the viewer is looking at
+    /// 
+    /// `up`: approximate up direction, must **not** be parallel to `direction`
+    pub fn new_view( position:&Vector3, direction:&Vector3, up:&Vector3 ) -> Self {
+        let forward = direction.normal();
+        let side    = Vector3::cross( &forward, up ).normal();
+        let up      = Vector3::cross( &side, &forward );
+
+        Self {
+            data:[
+                side[0], up[0], -forward[0], 0.0,
+                side[1], up[1], -forward[1], 0.0,
+                side[2], up[2], -forward[2], 0.0,
+
+                -Vector3::dot( &side, position ),
+                -Vector3::dot( &up, position ),
+                 Vector3::dot( &forward, position ),
+                1.0,
+            ]
+        }
+    }
+
+    /// Creates a new *model-view* `Matrix4x4` without large intermediate translations
+    /// 
+    /// `camera_world`: *camera to world* matrix, must be rigid ( rotation and translation only )
+    /// 
+    /// `object_world`: *object to world* matrix
+    /// 
+    /// Camera translation is subtracted from object translation **before**
+    /// rotating into view space, so objects near a camera far from the origin
+    /// keep their precision
+    pub fn new_view_relative( camera_world:&Matrix4x4, object_world:&Matrix4x4 ) -> Self {
+        let mut relative = *object_world;
+        relative.data[12] -= camera_world.data[12];
+        relative.data[13] -= camera_world.data[13];
+        relative.data[14] -= camera_world.data[14];
+
+        // inverse of rigid rotation is its transpose
+        let mut view_rotation = Self::new_identity();
+        for col in 0..3 {
+            for row in 0..3 {
+                view_rotation.data[row + ( col * 4 )] = camera_world.data[col + ( row * 4 )];
+            }
+        }
+
+        view_rotation * relative
+    }
+
+    /// Creates a new *orthographic* **projection** `Matrix4x4`
+    /// 
+    /// Maps view space box into normalized device coordinates
+    /// with all axes between **-1.0** and **1.0**
+    pub fn new_orthographic(
+        left:f32, right:f32,
+        bottom:f32, top:f32,
+        near:f32, far:f32
+    ) -> Self {
+        let mut result = Self::new_identity();
+
+        result.data[0]  =  2.0 / ( right - left );
+        result.data[5]  =  2.0 / ( top - bottom );
+        result.data[10] = -2.0 / ( far - near );
+
+        result.data[12] = -( right + left ) / ( right - left );
+        result.data[13] = -( top + bottom ) / ( top - bottom );
+        result.data[14] = -( far + near ) / ( far - near );
+
+        result
+    }
+
+    /// Creates a new *perspective* **projection** `Matrix4x4`
+    /// 
+    /// Maps view space frustum into normalized device coordinates
+    /// with all axes between **-1.0** and **1.0**
+    /// 
+    /// `fov`: vertical field of view in **Radians**
+    /// 
+    /// `aspect`: width / height
+    pub fn new_perspective( fov:f32, aspect:f32, near:f32, far:f32 ) -> Self {
+        let focal_length = 1.0 / math::tan( fov / 2.0 );
+        let mut result = Self::new_zero();
+
+        result.data[0]  = focal_length / aspect;
+        result.data[5]  = focal_length;
+        result.data[10] = ( far + near ) / ( near - far );
+        result.data[11] = -1.0;
+        result.data[14] = ( 2.0 * far * near ) / ( near - far );
+
+        result
+    }
+
+    /// Creates a new *reversed-z perspective* **projection** `Matrix4x4`
+    /// 
+    /// Maps view space frustum into normalized device coordinates
+    /// with `x` and `y` between **-1.0** and **1.0**
+    /// and `z` between **1.0** ( *near* ) and **0.0** ( *far* )
+    /// 
+    /// `fov`: vertical field of view in **Radians**
+    /// 
+    /// `aspect`: width / height
+    pub fn new_perspective_reversed_z( fov:f32, aspect:f32, near:f32, far:f32 ) -> Self {
+        let focal_length = 1.0 / math::tan( fov / 2.0 );
+        let mut result = Self::new_zero();
+
+        result.data[0]  = focal_length / aspect;
+        result.data[5]  = focal_length;
+        result.data[10] = near / ( far - near );
+        result.data[11] = -1.0;
+        result.data[14] = ( far * near ) / ( far - near );
+
+        result
+    }
+
+    /// Converts `depth` produced by `new_perspective` into linear depth
+    /// 
+    /// `depth`: depth buffer value between **0.0** and **1.0**
+    /// 
+    /// Returns: distance from viewer along view direction
+    pub fn linearize_depth( depth:f32, near:f32, far:f32 ) -> f32 {
+        let ndc_z = ( depth * 2.0 ) - 1.0;
+        ( 2.0 * near * far ) / ( ( far + near ) - ( ndc_z * ( far - near ) ) )
+    }
 
+    /// Converts `depth` produced by `new_perspective_reversed_z` into linear depth
+    /// 
+    /// `depth`: depth buffer value between **1.0** ( *near* ) and **0.0** ( *far* )
+    /// 
+    /// Returns: distance from viewer along view direction
+    pub fn linearize_depth_reversed_z( depth:f32, near:f32, far:f32 ) -> f32 {
+        ( near * far ) / ( ( depth * ( far - near ) ) + near )
+    }
+
+    /// Converts view space `z` into depth buffer value of `new_perspective`
+    /// 
+    /// `view_z` is **negative** in front of the viewer
+    /// 
+    /// Returns: depth between **0.0** and **1.0**
+    pub fn view_z_to_depth( view_z:f32, near:f32, far:f32 ) -> f32 {
+        let ndc_z =
+            ( ( far + near ) / ( far - near ) ) +
+            ( ( 2.0 * far * near ) / ( ( far - near ) * view_z ) );
+        ( ndc_z * 0.5 ) + 0.5
+    }
+
+    /// Converts view space `z` into depth buffer value of `new_perspective_reversed_z`
+    /// 
+    /// `view_z` is **negative** in front of the viewer
+    /// 
+    /// Returns: depth between **1.0** ( *near* ) and **0.0** ( *far* )
+    pub fn view_z_to_depth_reversed_z( view_z:f32, near:f32, far:f32 ) -> f32 {
+        -( near / ( far - near ) ) - ( ( far * near ) / ( ( far - near ) * view_z ) )
+    }
+
+    /// Reconstructs view space position from depth buffer value
+    /// 
+    /// `depth`: depth buffer value between **0.0** and **1.0**
+    /// 
+    /// `uv`: screen coordinates between **0.0** and **1.0**
+    /// 
+    /// `inverse_projection`: inverse of `new_perspective` matrix
+    /// 
+    /// Returns: `Vector3` in view space
+    pub fn reconstruct_view_position(
+        depth:f32, uv:&Vector2, inverse_projection:&Matrix4x4
+    ) -> Vector3 {
+        let ndc = Vector4::new(
+            ( uv[0] * 2.0 ) - 1.0,
+            ( uv[1] * 2.0 ) - 1.0,
+            ( depth * 2.0 ) - 1.0,
+            1.0
+        );
+        let view = inverse_projection.mul_vector4( &ndc );
+        Vector3::new( view[0], view[1], view[2] ) / view[3]
+    }
+
+    /// Creates a new *view-projection* `Matrix4x4` for **projecting decals**
+    /// 
+    /// `position`: position of the projector
+    /// 
+    /// `direction`: direction the projector is facing
+    /// 
+    /// `up`: approximate up direction, must **not** be parallel to `direction`
+    /// 
+    /// `size`: width and height of the projected area
+    /// 
+    /// `near` and `far`: depth range of the projected volume
+    /// 
+    /// Multiply result with `new_texture_bias` to map into decal *uv* space
+    pub fn new_decal_projector(
+        position:&Vector3, direction:&Vector3, up:&Vector3,
+        size:&Vector2, near:f32, far:f32
+    ) -> Self {
+        let half_width  = size[0] / 2.0;
+        let half_height = size[1] / 2.0;
+
+        Self::new_orthographic(
+            -half_width, half_width,
+            -half_height, half_height,
+            near, far
+        ) * Self::new_view( position, direction, up )
+    }
+
+    /// Creates a new `Matrix4x4` that maps coordinates
+    /// from **-1.0**-**1.0** to **0.0**-**1.0**
+    pub fn new_texture_bias() -> Self {
+        Self::new_ndc_to_uv( false )
+    }
+
+    /// Creates a new `Matrix4x4` that maps *NDC* coordinates
+    /// from **-1.0**-**1.0** to texture coordinates **0.0**-**1.0**
+    /// 
+    /// Depth is mapped from **-1.0**-**1.0** to **0.0**-**1.0**
+    /// 
+    /// `flip_y`: **+y** in *NDC* maps to **v = 0.0** ( *top-left texture origin* )
+    pub fn new_ndc_to_uv( flip_y:bool ) -> Self {
+        let scale_y = if flip_y { -0.5 } else { 0.5 };
+        Self::new_translate( &[0.5, 0.5, 0.5] ) *
+        Self::new_scale( &[0.5, scale_y, 0.5] )
+    }
+
+    /// Creates a new `Matrix4x4` that flattens geometry onto `plane`
+    /// as seen from `light` ( *planar projected shadows* )
+    /// 
+    /// `light`: `w` = **0.0** for a directional light pointing *towards* the light,
+    /// `w` = **1.0** for a point light position
+    pub fn new_planar_shadow( light:&Vector4, plane:&Plane ) -> Self {
+        let p = [
+            plane.normal()[0], plane.normal()[1], plane.normal()[2], plane.distance()
+        ];
+        let dot = ( p[0] * light[0] ) + ( p[1] * light[1] ) +
+            ( p[2] * light[2] ) + ( p[3] * light[3] );
+
+        let mut result = Self::new_zero();
+        let mut i = 0;
+        while i < 16 {
+            let row    = i % 4;
+            let column = i / 4;
+            let diagonal = if row == column { dot } else { 0.0 };
+            result.data[i] = diagonal - ( light[row] * p[column] );
+            i += 1;
+        }
+        result
+    }
+
+    /// Creates a new `Matrix4x4` that mirrors coordinates across `plane`
+    /// 
+    /// `plane` normal should be normalized
+    pub fn new_reflection( plane:&Plane ) -> Self {
+        let n = plane.normal();
+        let d = plane.distance();
+
+        let mut result = Self::new_identity();
+        let mut i = 0;
+        while i < 12 {
+            let row    = i % 4;
+            let column = i / 4;
+            if row < 3 {
+                result.data[i] -= 2.0 * n[row] * n[column];
+            }
+            i += 1;
+        }
+
+        // data indeces( 12, 13, 14 ) are where translation values go
+        result.data[12] = -2.0 * d * n[0];
+        result.data[13] = -2.0 * d * n[1];
+        result.data[14] = -2.0 * d * n[2];
+
+        result
+    }
+
+    /// Creates a new color `Matrix4x4` that adds `brightness` to `R` `G` `B`
+    /// 
+    /// Colors are multiplied as ( `R`, `G`, `B`, **1.0** )
+    pub fn new_color_brightness( brightness:f32 ) -> Self {
+        Self::new_translate( &[brightness, brightness, brightness] )
+    }
+
+    /// Creates a new color `Matrix4x4` that scales `R` `G` `B` by `contrast` around **0.5**
+    /// 
+    /// Colors are multiplied as ( `R`, `G`, `B`, **1.0** )
+    pub fn new_color_contrast( contrast:f32 ) -> Self {
+        let offset = 0.5 * ( 1.0 - contrast );
+        let mut result = Self::new_scale( &[contrast, contrast, contrast] );
+        result.data[12] = offset;
+        result.data[13] = offset;
+        result.data[14] = offset;
+        result
+    }
+
+    /// Creates a new color `Matrix4x4` that blends `R` `G` `B` with *Rec. 709* luma
+    /// 
+    /// `saturation`: **0.0** is grayscale, **1.0** is unchanged
+    /// 
+    /// Colors are multiplied as ( `R`, `G`, `B`, **1.0** )
+    pub fn new_color_saturation( saturation:f32 ) -> Self {
+        let luma = [0.2126, 0.7152, 0.0722];
+        let mut result = Self::new_identity();
+        for ( col, weight ) in luma.iter().enumerate() {
+            for row in 0..3 {
+                let diagonal = if row == col { saturation } else { 0.0 };
+                result.data[row + ( col * 4 )] = ( ( 1.0 - saturation ) * weight ) + diagonal;
+            }
+        }
+        result
+    }
+
+    /// Creates a new color `Matrix4x4` that adds `brightness` then scales by `contrast` around **0.5**
+    /// 
+    /// Colors are multiplied as ( `R`, `G`, `B`, **1.0** )
+    pub fn new_color_brightness_contrast( brightness:f32, contrast:f32 ) -> Self {
+        Self::new_color_contrast( contrast ) * Self::new_color_brightness( brightness )
+    }
+
+    /// Creates a new color `Matrix4x4` that rotates hue while preserving luma
+    /// 
+    /// Matches *CSS*/*SVG* `hue-rotate` filter
+    /// 
+    /// Angle is in **Radians**
+    pub fn new_color_hue_rotate( theta_rad:f32 ) -> Self {
+        let ( sin, cos ) = math::sin_cos( theta_rad );
+        Self::from_array_row_major([
+            0.213 + ( cos * 0.787 ) - ( sin * 0.213 ),
+            0.715 - ( cos * 0.715 ) - ( sin * 0.715 ),
+            0.072 - ( cos * 0.072 ) + ( sin * 0.928 ),
+            0.0,
+
+            0.213 - ( cos * 0.213 ) + ( sin * 0.143 ),
+            0.715 + ( cos * 0.285 ) + ( sin * 0.140 ),
+            0.072 - ( cos * 0.072 ) - ( sin * 0.283 ),
+            0.0,
+
+            0.213 - ( cos * 0.213 ) - ( sin * 0.787 ),
+            0.715 - ( cos * 0.715 ) + ( sin * 0.715 ),
+            0.072 + ( cos * 0.928 ) + ( sin * 0.072 ),
+            0.0,
+
+            0.0, 0.0, 0.0, 1.0,
+        ])
+    }
+
+    /// Creates a new color `Matrix4x4` that applies a sepia tone
+    /// 
+    /// Matches *CSS*/*SVG* `sepia` filter at full strength
+    pub fn new_color_sepia() -> Self {
+        Self::from_array_row_major([
+            0.393, 0.769, 0.189, 0.0,
+            0.349, 0.686, 0.168, 0.0,
+            0.272, 0.534, 0.131, 0.0,
+            0.0,   0.0,   0.0,   1.0,
+        ])
+    }
 
     /// Creates `Matrix4x4` from `array` in *column-major* order
     pub fn from_array(array:[f32;16]) -> Self {
@@ -180,6 +617,35 @@ impl Matrix4x4 {
         }
     }
 
+    /// Creates `Matrix4x4` from **64** `bytes` ordered with given `layout` and `endianness`
+    /// 
+    /// Returns: `Matrix4x4` if `bytes` has correct length
+    /// 
+    /// Returns: `String` error if `bytes` has incorrect length
+    pub fn from_bytes(
+        bytes:&[u8], layout:MatrixLayout, endianness:Endianness
+    ) -> Result<Self, String> {
+        let array = f32_array_from_bytes::<16>( bytes, endianness )?;
+        match layout {
+            MatrixLayout::ColumnMajor => Ok( Self::from_array( array ) ),
+            MatrixLayout::RowMajor    => Ok( Self::from_array_row_major( array ) ),
+        }
+    }
+
+    /// Returns: **new** `Vec` of bytes ordered with given `layout` and `endianness`
+    pub fn to_bytes( &self, layout:MatrixLayout, endianness:Endianness ) -> Vec<u8> {
+        let array = match layout {
+            MatrixLayout::ColumnMajor => self.data,
+            MatrixLayout::RowMajor    => self.as_array_row_major(),
+        };
+
+        let mut result = Vec::with_capacity( 64 );
+        for value in array.iter() {
+            result.extend_from_slice( &f32_to_bytes( *value, endianness ) );
+        }
+        result
+    }
+
     /// Returns: `reference` to data `array` in *column-major* order
     pub fn as_array(&self) -> &[f32;16] {
         &self.data
@@ -202,6 +668,89 @@ impl Matrix4x4 {
         ]
     }
 
+    /// Returns: local **+x** axis in world space ( *first column* ), includes scale
+    pub fn right(&self) -> Vector3 {
+        Vector3::new( self.data[0], self.data[1], self.data[2] )
+    }
+
+    /// Returns: local **+y** axis in world space ( *second column* ), includes scale
+    pub fn up(&self) -> Vector3 {
+        Vector3::new( self.data[4], self.data[5], self.data[6] )
+    }
+
+    /// Returns: local **+z** axis ( `Vector3::new_forward` ) in world space ( *third column* ), includes scale
+    /// 
+    /// Cameras built with `new_view` look down the **-z** axis, use `-forward()` for their viewing direction
+    pub fn forward(&self) -> Vector3 {
+        Vector3::new( self.data[8], self.data[9], self.data[10] )
+    }
+
+    /// Returns: translation ( *fourth column* )
+    pub fn translation(&self) -> Vector3 {
+        Vector3::new( self.data[12], self.data[13], self.data[14] )
+    }
+
+    /// Set translation ( *fourth column* ), other cells are kept
+    pub fn set_translation( &mut self, translation:&Vector3 ) {
+        self.data[12] = translation[0];
+        self.data[13] = translation[1];
+        self.data[14] = translation[2];
+    }
+
+    /// Classify transformation with tolerance of **1e-5**
+    /// 
+    /// Returns: most specific `MatrixKind`
+    pub fn classify(&self) -> MatrixKind {
+        self.classify_with_tolerance( 1e-5 )
+    }
+
+    /// Classify transformation
+    /// 
+    /// `tolerance`: allowed error, relative to axis lengths for orthogonality and scale checks
+    /// 
+    /// Returns: most specific `MatrixKind`
+    pub fn classify_with_tolerance( &self, tolerance:f32 ) -> MatrixKind {
+        let near = | a:f32, b:f32, scale:f32 | ( a - b ).abs() <= tolerance * scale.max( 1.0 );
+
+        if !near( self.data[3], 0.0, 1.0 ) || !near( self.data[7], 0.0, 1.0 ) ||
+            !near( self.data[11], 0.0, 1.0 ) || !near( self.data[15], 1.0, 1.0 )
+        {
+            return MatrixKind::Projective;
+        }
+
+        let axes = [ self.right(), self.up(), self.forward() ];
+        let lengths = [ axes[0].magnitude(), axes[1].magnitude(), axes[2].magnitude() ];
+
+        let is_identity_basis = axes.iter().enumerate().all( | ( column, axis ) | {
+            ( 0..3 ).all( | row | near( axis[row], if row == column { 1.0 } else { 0.0 }, 1.0 ) )
+        } );
+        if is_identity_basis {
+            let has_translation = ( 12..15 ).any( | i | !near( self.data[i], 0.0, 1.0 ) );
+            return if has_translation { MatrixKind::Translation } else { MatrixKind::Identity };
+        }
+
+        let is_orthogonal =
+            near( Vector3::dot( &axes[0], &axes[1] ), 0.0, lengths[0] * lengths[1] ) &&
+            near( Vector3::dot( &axes[0], &axes[2] ), 0.0, lengths[0] * lengths[2] ) &&
+            near( Vector3::dot( &axes[1], &axes[2] ), 0.0, lengths[1] * lengths[2] );
+        let largest = lengths[0].max( lengths[1] ).max( lengths[2] );
+        let is_uniform = largest > 0.0 &&
+            near( lengths[0], lengths[1], largest ) &&
+            near( lengths[0], lengths[2], largest );
+
+        if !is_orthogonal || !is_uniform {
+            return MatrixKind::Affine;
+        }
+
+        let is_unit = near( lengths[0], 1.0, 1.0 );
+        let determinant = Vector3::dot( &axes[0], &Vector3::cross( &axes[1], &axes[2] ) );
+        if is_unit && determinant > 0.0 {
+            MatrixKind::RigidBody
+        } else {
+            MatrixKind::Similarity
+        }
+    }
+
     /// Index `Matrix4x4` with `row` and `column` index instead of 1D index
     /// 
     /// Returns: `reference` to value at given index
@@ -218,16 +767,44 @@ impl Matrix4x4 {
         &mut self[row + (column * 4)]
     }
 
+    /// Offset **projection** `Matrix4x4` by `offset` in *normalized device coordinates*
+    /// 
+    /// Works for both *perspective* and *orthographic* projections
+    /// 
+    /// Use with `functions::sampling::taa_jitter`
+    pub fn jitter( &mut self, offset:&Vector2 ) {
+        // equivalent to new_translate( offset.x, offset.y, 0.0 ) * self
+        let mut column = 0;
+        while column < 4 {
+            let first = column * 4;
+            let w = self.data[first + 3];
+            self.data[first]     += offset[0] * w;
+            self.data[first + 1] += offset[1] * w;
+            column += 1;
+        }
+    }
+
     /// Multiply `Vector4` by `Matrix4x4`
     /// 
     /// Returns: `Vector4`
     pub fn mul_vector4( &self, v:&Vector4 ) -> Vector4 {
-        Vector4::from_array([
-            ( self[0] * v[0] ) + ( self[4] * v[1] ) + ( self[8]  * v[2] ) + ( self[12] * v[3] ),
-            ( self[1] * v[0] ) + ( self[5] * v[1] ) + ( self[9]  * v[2] ) + ( self[13] * v[3] ),
-            ( self[2] * v[0] ) + ( self[6] * v[1] ) + ( self[10] * v[2] ) + ( self[14] * v[3] ),
-            ( self[3] * v[0] ) + ( self[7] * v[1] ) + ( self[11] * v[2] ) + ( self[15] * v[3] ),
-        ])
+        Vector4::from_array( simd::mul_vector4( self.as_array(), v.as_array() ) )
+    }
+
+    /// Transform `color` by color `Matrix4x4`, alpha is kept
+    /// 
+    /// Color is multiplied as ( `R`, `G`, `B`, **1.0** ) and
+    /// result is clamped between **0.0** and **1.0**
+    /// 
+    /// Returns: new `RGB`
+    pub fn transform_color( &self, color:RGB ) -> RGB {
+        let [ r, g, b ] = color.as_float_rgb_array();
+        let result = self.mul_vector4( &Vector4::new( r, g, b, 1.0 ) );
+        RGB::from_float_rgb(
+            result[0].clamp( 0.0, 1.0 ),
+            result[1].clamp( 0.0, 1.0 ),
+            result[2].clamp( 0.0, 1.0 ),
+        ).with_alpha( color.a() )
     }
 
     /// Multiply `Vector3` by `Matrix4x4`
@@ -244,10 +821,309 @@ impl Matrix4x4 {
         ])
     }
 
+    /// Returns: new *transposed* `Matrix4x4`
+    pub fn transpose(&self) -> Self {
+        Self::from_array( self.as_array_row_major() )
+    }
+
+    /// Returns: *determinant* of `Matrix4x4`
+    pub fn determinant(&self) -> f32 {
+        let ( s, c ) = self.sub_determinants();
+        ( s[0] * c[5] ) - ( s[1] * c[4] ) + ( s[2] * c[3] ) +
+        ( s[3] * c[2] ) - ( s[4] * c[1] ) + ( s[5] * c[0] )
+    }
+
+    /// Returns: new *inverted* `Matrix4x4`
+    /// 
+    /// Returns: `String` error if `Matrix4x4` is **not** invertible ( *determinant is 0.0* )
+    pub fn inverse(&self) -> Result<Self, String> {
+        let m = &self.data;
+        let ( s, c ) = self.sub_determinants();
+        let determinant =
+            ( s[0] * c[5] ) - ( s[1] * c[4] ) + ( s[2] * c[3] ) +
+            ( s[3] * c[2] ) - ( s[4] * c[1] ) + ( s[5] * c[0] );
+
+        if determinant == 0.0 {
+            return Err( String::from("MATRIX4X4 ERROR: Matrix with determinant of 0.0 cannot be inverted!") );
+        }
+        let inv_det = 1.0 / determinant;
+
+        // adjugate from shared 2x2 sub-determinants, inverse of the transpose
+        // read as a transposed array is the inverse itself
+        Ok( Self { data:[
+            (  m[5]  * c[5] - m[6]  * c[4] + m[7]  * c[3] ) * inv_det,
+            ( -m[1]  * c[5] + m[2]  * c[4] - m[3]  * c[3] ) * inv_det,
+            (  m[13] * s[5] - m[14] * s[4] + m[15] * s[3] ) * inv_det,
+            ( -m[9]  * s[5] + m[10] * s[4] - m[11] * s[3] ) * inv_det,
+
+            ( -m[4]  * c[5] + m[6]  * c[2] - m[7]  * c[1] ) * inv_det,
+            (  m[0]  * c[5] - m[2]  * c[2] + m[3]  * c[1] ) * inv_det,
+            ( -m[12] * s[5] + m[14] * s[2] - m[15] * s[1] ) * inv_det,
+            (  m[8]  * s[5] - m[10] * s[2] + m[11] * s[1] ) * inv_det,
+
+            (  m[4]  * c[4] - m[5]  * c[2] + m[7]  * c[0] ) * inv_det,
+            ( -m[0]  * c[4] + m[1]  * c[2] - m[3]  * c[0] ) * inv_det,
+            (  m[12] * s[4] - m[13] * s[2] + m[15] * s[0] ) * inv_det,
+            ( -m[8]  * s[4] + m[9]  * s[2] - m[11] * s[0] ) * inv_det,
+
+            ( -m[4]  * c[3] + m[5]  * c[1] - m[6]  * c[0] ) * inv_det,
+            (  m[0]  * c[3] - m[1]  * c[1] + m[2]  * c[0] ) * inv_det,
+            ( -m[12] * s[3] + m[13] * s[1] - m[14] * s[0] ) * inv_det,
+            (  m[8]  * s[3] - m[9]  * s[1] + m[10] * s[0] ) * inv_det,
+        ] } )
+    }
+
+    /// Returns: new *inverted* affine `Matrix4x4`
+    /// 
+    /// Much cheaper than `inverse`, only the upper-left 3x3 cells are inverted
+    /// and translation is rotated back, bottom row is assumed to be **0, 0, 0, 1**
+    /// 
+    /// Returns: `String` error if upper-left 3x3 cells are **not** invertible ( *determinant is 0.0* )
+    pub fn inverse_affine(&self) -> Result<Self, String> {
+        let columns = [ self.right(), self.up(), self.forward() ];
+
+        // rows of the inverse are cross products of the other two columns
+        let rows = [
+            Vector3::cross( &columns[1], &columns[2] ),
+            Vector3::cross( &columns[2], &columns[0] ),
+            Vector3::cross( &columns[0], &columns[1] ),
+        ];
+        let determinant = Vector3::dot( &columns[0], &rows[0] );
+
+        if determinant == 0.0 {
+            return Err( String::from("MATRIX4X4 ERROR: Affine matrix with determinant of 0.0 cannot be inverted!") );
+        }
+        let inv_det = 1.0 / determinant;
+        let rows = [ rows[0] * inv_det, rows[1] * inv_det, rows[2] * inv_det ];
+
+        let translation = self.translation();
+        Ok( Self { data:[
+            rows[0][0], rows[1][0], rows[2][0], 0.0,
+            rows[0][1], rows[1][1], rows[2][1], 0.0,
+            rows[0][2], rows[1][2], rows[2][2], 0.0,
+            -Vector3::dot( &rows[0], &translation ),
+            -Vector3::dot( &rows[1], &translation ),
+            -Vector3::dot( &rows[2], &translation ),
+            1.0,
+        ] } )
+    }
+
+    /// 2x2 sub-determinants of the first two and last two rows of the transpose
+    /// ( *Laplace expansion* )
+    fn sub_determinants(&self) -> ( [f32;6], [f32;6] ) {
+        let m = &self.data;
+        let s = [
+            ( m[0] * m[5] ) - ( m[4] * m[1] ),
+            ( m[0] * m[6] ) - ( m[4] * m[2] ),
+            ( m[0] * m[7] ) - ( m[4] * m[3] ),
+            ( m[1] * m[6] ) - ( m[5] * m[2] ),
+            ( m[1] * m[7] ) - ( m[5] * m[3] ),
+            ( m[2] * m[7] ) - ( m[6] * m[3] ),
+        ];
+        let c = [
+            ( m[8]  * m[13] ) - ( m[12] * m[9]  ),
+            ( m[8]  * m[14] ) - ( m[12] * m[10] ),
+            ( m[8]  * m[15] ) - ( m[12] * m[11] ),
+            ( m[9]  * m[14] ) - ( m[13] * m[10] ),
+            ( m[9]  * m[15] ) - ( m[13] * m[11] ),
+            ( m[10] * m[15] ) - ( m[14] * m[11] ),
+        ];
+        ( s, c )
+    }
+
+    /// Creates a new `Matrix4x4` for **rotating** coordinates from rotation vector
+    /// ( *exponential map* )
+    /// 
+    /// `omega`: rotation axis scaled by rotation angle in **Radians**
+    pub fn new_rotate_exp( omega:&Vector3 ) -> Self {
+        let angle = omega.magnitude();
+        if angle < f32::EPSILON {
+            return Self::new_identity();
+        }
+
+        let axis = *omega / angle;
+        let ( sin, cos ) = math::sin_cos( angle );
+        let one_minus_cos = 1.0 - cos;
+        let ( x, y, z ) = ( axis[0], axis[1], axis[2] );
+
+        // Rodrigues' rotation formula
+        let mut result = Self::new_identity();
+        result.data[0]  = cos + ( x * x * one_minus_cos );
+        result.data[1]  = ( y * x * one_minus_cos ) + ( z * sin );
+        result.data[2]  = ( z * x * one_minus_cos ) - ( y * sin );
+
+        result.data[4]  = ( x * y * one_minus_cos ) - ( z * sin );
+        result.data[5]  = cos + ( y * y * one_minus_cos );
+        result.data[6]  = ( z * y * one_minus_cos ) + ( x * sin );
+
+        result.data[8]  = ( x * z * one_minus_cos ) + ( y * sin );
+        result.data[9]  = ( y * z * one_minus_cos ) - ( x * sin );
+        result.data[10] = cos + ( z * z * one_minus_cos );
+
+        result
+    }
+
+    /// Rotation vector of **rotation** `Matrix4x4` ( *logarithmic map* )
+    /// 
+    /// Only the upper-left 3x3 cells are used,
+    /// they should form a pure rotation ( *no scale or shear* )
+    /// 
+    /// Returns: rotation axis scaled by rotation angle in **Radians**
+    pub fn rotation_log(&self) -> Vector3 {
+        let trace = self[0] + self[5] + self[10];
+
+        // skew-symmetric part: ( R - Rt ) / 2 = sin( angle ) * axis
+        let skew = Vector3::new(
+            self[6] - self[9],
+            self[8] - self[2],
+            self[1] - self[4],
+        ) / 2.0;
+
+        // atan2 keeps full precision close to 0 and 180 degrees where acos does not
+        let sin   = skew.magnitude();
+        let angle = math::atan2( sin, ( trace - 1.0 ) / 2.0 );
+
+        if angle < 1e-4 {
+            // sin( angle ) ~= angle
+            return skew;
+        }
+
+        if ( crate::PI - angle ) < 1e-3 {
+            // skew part vanishes near 180 degrees, recover axis from diagonal
+            let mut axis = Vector3::new(
+                math::sqrt( ( ( self[0]  + 1.0 ) / 2.0 ).max( 0.0 ) ),
+                math::sqrt( ( ( self[5]  + 1.0 ) / 2.0 ).max( 0.0 ) ),
+                math::sqrt( ( ( self[10] + 1.0 ) / 2.0 ).max( 0.0 ) ),
+            );
+            // pick signs relative to largest component
+            if axis[0] >= axis[1] && axis[0] >= axis[2] {
+                *axis.y_mut() = axis[1].copysign( self[1] + self[4] );
+                *axis.z_mut() = axis[2].copysign( self[2] + self[8] );
+            } else if axis[1] >= axis[2] {
+                *axis.x_mut() = axis[0].copysign( self[1] + self[4] );
+                *axis.z_mut() = axis[2].copysign( self[6] + self[9] );
+            } else {
+                *axis.x_mut() = axis[0].copysign( self[2] + self[8] );
+                *axis.y_mut() = axis[1].copysign( self[6] + self[9] );
+            }
+            // diagonal only fixes relative signs, skew part still points along the axis below 180 degrees
+            if Vector3::dot( &axis, &skew ) < 0.0 {
+                axis = -axis;
+            }
+            return axis.normal() * angle;
+        }
+
+        skew * ( angle / sin )
+    }
+
+    /// Splits affine `Matrix4x4` into translation, rotation and scale
+    /// 
+    /// Negative determinant is represented by negative `x` scale
+    pub(crate) fn decompose_trs(&self) -> ( Vector3, Self, Vector3 ) {
+        let translation = self.translation();
+
+        let columns = [ self.right(), self.up(), self.forward() ];
+
+        let mut scale = Vector3::new(
+            columns[0].magnitude(),
+            columns[1].magnitude(),
+            columns[2].magnitude(),
+        );
+
+        let determinant = Vector3::dot(
+            &columns[0],
+            &Vector3::cross( &columns[1], &columns[2] )
+        );
+        if determinant < 0.0 {
+            *scale.x_mut() = -scale[0];
+        }
+
+        let mut rotation = Self::new_identity();
+        let mut column = 0;
+        while column < 3 {
+            if scale[column] != 0.0 {
+                let axis = columns[column] / scale[column];
+                let first = column * 4;
+                rotation.data[first]     = axis[0];
+                rotation.data[first + 1] = axis[1];
+                rotation.data[first + 2] = axis[2];
+            }
+            column += 1;
+        }
+
+        ( translation, rotation, scale )
+    }
+
+    /// Splits affine *TRS* `Matrix4x4` into translation, rotation and scale,
+    /// inverse of `new_translate` * rotation * `new_scale`
+    /// 
+    /// Negative determinant is represented by negative `x` scale
+    /// 
+    /// Returns: translation, rotation and scale,
+    /// `None` if `Matrix4x4` is projective, has a zero scale axis or contains shear
+    pub fn decompose(&self) -> Option<( Vector3, Quaternion, Vector3 )> {
+        Transform::from_matrix( self ).ok().map( | transform | (
+            *transform.translation(),
+            *transform.rotation(),
+            *transform.scale(),
+        ) )
+    }
+
+    /// Rotation part of affine `Matrix4x4` with scale removed
+    /// 
+    /// Only meaningful if `Matrix4x4` contains no shear, use `decompose` to check
+    /// 
+    /// Returns: `Quaternion`
+    pub fn extract_rotation(&self) -> Quaternion {
+        let ( _, rotation, _ ) = self.decompose_trs();
+        Quaternion::from_matrix4x4( &rotation )
+    }
+
+    /// Scale part of affine `Matrix4x4` ( *length of each basis column* )
+    /// 
+    /// Negative determinant is represented by negative `x` scale
+    /// 
+    /// Returns: `Vector3`
+    pub fn extract_scale(&self) -> Vector3 {
+        let ( _, _, scale ) = self.decompose_trs();
+        scale
+    }
+
+    /// Translation part of affine `Matrix4x4`, same as `translation`
+    /// 
+    /// Returns: `Vector3`
+    pub fn extract_translation(&self) -> Vector3 {
+        self.translation()
+    }
+
+    /// Interpolate from `a` to `b` without skewing geometry
+    /// 
+    /// Both matrices should be affine *TRS* matrices.
+    /// Translation and scale are interpolated *linearly*,
+    /// rotation is interpolated *spherically*
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// Returns: new `Matrix4x4`
+    pub fn interpolate( a:&Self, b:&Self, t:f32 ) -> Self {
+        let ( translation_a, rotation_a, scale_a ) = a.decompose_trs();
+        let ( translation_b, rotation_b, scale_b ) = b.decompose_trs();
+
+        let translation = Vector3::lerp_unclamped( &translation_a, &translation_b, t );
+        let scale       = Vector3::lerp_unclamped( &scale_a, &scale_b, t );
+
+        let delta    = ( rotation_a.transpose() * rotation_b ).rotation_log();
+        let rotation = rotation_a * Self::new_rotate_exp( &( delta * t ) );
+
+        Self::new_translate( translation.as_array() ) *
+        rotation *
+        Self::new_scale( scale.as_array() )
+    }
+
 }
 
 impl Display for Matrix4x4 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Matrix4x4:\n   {:7.2} {:7.2} {:7.2} {:7.2}\n   {:7.2} {:7.2} {:7.2} {:7.2}\n   {:7.2} {:7.2} {:7.2} {:7.2}\n   {:7.2} {:7.2} {:7.2} {:7.2}",
@@ -273,9 +1149,72 @@ impl IndexMut<usize> for Matrix4x4 {
     }
 }
 
+impl AsRef<[f32]> for Matrix4x4 {
+    fn as_ref( &self ) -> &[f32] {
+        &self.data
+    }
+}
+
+impl AsMut<[f32]> for Matrix4x4 {
+    fn as_mut( &mut self ) -> &mut [f32] {
+        &mut self.data
+    }
+}
+
+/// Iterates cells in *column-major* order
+impl IntoIterator for Matrix4x4 {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 16>;
+
+    fn into_iter( self ) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+/// Iterates cells in *column-major* order
+impl<'a> IntoIterator for &'a Matrix4x4 {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    fn into_iter( self ) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Iterates cells in *column-major* order
+impl<'a> IntoIterator for &'a mut Matrix4x4 {
+    type Item = &'a mut f32;
+    type IntoIter = core::slice::IterMut<'a, f32>;
+
+    fn into_iter( self ) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+/// Create new `Matrix4x4` from `array` in *column-major* order
+impl From<[f32;16]> for Matrix4x4 {
+    fn from( data:[f32;16] ) -> Self {
+        Self::from_array( data )
+    }
+}
+
+/// Returns: `array` in *column-major* order
+impl From<Matrix4x4> for [f32;16] {
+    fn from( m:Matrix4x4 ) -> Self {
+        m.data
+    }
+}
+
+impl From<&Matrix4x4> for [f32;16] {
+    fn from( m:&Matrix4x4 ) -> Self {
+        m.data
+    }
+}
+
 impl Add for Matrix4x4 {
     type Output = Self;
 
+    #[allow(clippy::needless_return)]
     fn add(self, rhs: Self) -> Self::Output {
         let mut result = Self::new_zero();
         add_components(self.as_array(), rhs.as_array(), result.as_mut_array() );
@@ -286,6 +1225,7 @@ impl Add for Matrix4x4 {
 impl Sub for Matrix4x4 {
     type Output = Self;
 
+    #[allow(clippy::needless_return)]
     fn sub(self, rhs: Self) -> Self::Output {
         let mut result = Self::new_zero();
         sub_components(self.as_array(), rhs.as_array(), result.as_mut_array() );
@@ -297,6 +1237,7 @@ impl Mul<f32> for Matrix4x4 {
 
     type Output = Self;
 
+    #[allow(clippy::needless_return)]
     fn mul( self, scalar:f32 ) -> Self::Output {
         let mut result = Self::new_zero();
         scale_components(self.as_array(), scalar, result.as_mut_array());
@@ -309,6 +1250,7 @@ impl Div<f32> for Matrix4x4 {
     
     type Output = Self;
 
+    #[allow(clippy::needless_return)]
     fn div( self, scalar:f32 ) -> Self::Output {
         let mut result = Self::new_zero();
         scale_components(self.as_array(), 1.0 / scalar, result.as_mut_array());
@@ -322,31 +1264,133 @@ impl Mul<Self> for Matrix4x4 {
     type Output = Self;
 
     fn mul( self, _rhs:Self ) -> Self::Output {
-        Self {
-            data:[
-                // column - 0
-                ( self[0] * _rhs[0] ) + ( self[4] * _rhs[1] ) + ( self[8]  * _rhs[2] ) + ( self[12] * _rhs[3] ),
-                ( self[1] * _rhs[0] ) + ( self[5] * _rhs[1] ) + ( self[9]  * _rhs[2] ) + ( self[13] * _rhs[3] ),
-                ( self[2] * _rhs[0] ) + ( self[6] * _rhs[1] ) + ( self[10] * _rhs[2] ) + ( self[14] * _rhs[3] ),
-                ( self[3] * _rhs[0] ) + ( self[7] * _rhs[1] ) + ( self[11] * _rhs[2] ) + ( self[15] * _rhs[3] ),
-                // column - 1
-                ( self[0] * _rhs[4] ) + ( self[4] * _rhs[5] ) + ( self[8]  * _rhs[6] ) + ( self[12] * _rhs[7] ),
-                ( self[1] * _rhs[4] ) + ( self[5] * _rhs[5] ) + ( self[9]  * _rhs[6] ) + ( self[13] * _rhs[7] ),
-                ( self[2] * _rhs[4] ) + ( self[6] * _rhs[5] ) + ( self[10] * _rhs[6] ) + ( self[14] * _rhs[7] ),
-                ( self[3] * _rhs[4] ) + ( self[7] * _rhs[5] ) + ( self[11] * _rhs[6] ) + ( self[15] * _rhs[7] ),
-                // column - 2
-                ( self[0] * _rhs[8] ) + ( self[4] * _rhs[9] ) + ( self[8]  * _rhs[10] ) + ( self[12] * _rhs[11] ),
-                ( self[1] * _rhs[8] ) + ( self[5] * _rhs[9] ) + ( self[9]  * _rhs[10] ) + ( self[13] * _rhs[11] ),
-                ( self[2] * _rhs[8] ) + ( self[6] * _rhs[9] ) + ( self[10] * _rhs[10] ) + ( self[14] * _rhs[11] ),
-                ( self[3] * _rhs[8] ) + ( self[7] * _rhs[9] ) + ( self[11] * _rhs[10] ) + ( self[15] * _rhs[11] ),
-                // column - 3
-                ( self[0] * _rhs[12] ) + ( self[4] * _rhs[13] ) + ( self[8]  * _rhs[14] ) + ( self[12] * _rhs[15] ),
-                ( self[1] * _rhs[12] ) + ( self[5] * _rhs[13] ) + ( self[9]  * _rhs[14] ) + ( self[13] * _rhs[15] ),
-                ( self[2] * _rhs[12] ) + ( self[6] * _rhs[13] ) + ( self[10] * _rhs[14] ) + ( self[14] * _rhs[15] ),
-                ( self[3] * _rhs[12] ) + ( self[7] * _rhs[13] ) + ( self[11] * _rhs[14] ) + ( self[15] * _rhs[15] ),
-            ]
+        Self::from_array( simd::mul_matrix4x4( self.as_array(), _rhs.as_array() ) )
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matrix_close( a:&Matrix4x4, b:&Matrix4x4 ) {
+        for i in 0..16 {
+            assert!( ( a[i] - b[i] ).abs() < 1e-5, "{} != {}", a, b );
         }
+    }
+
+    fn assert_vector_close( a:&Vector3, b:&Vector3 ) {
+        assert!( ( *a - *b ).magnitude() < 1e-5, "{} != {}", a, b );
+    }
+
+    fn recompose( translation:&Vector3, rotation:&Quaternion, scale:&Vector3 ) -> Matrix4x4 {
+        Matrix4x4::new_translate( translation.as_array() ) *
+        rotation.as_matrix4x4() *
+        Matrix4x4::new_scale( scale.as_array() )
+    }
+
+    #[test]
+    fn rotation_log_near_half_turn() {
+        let axes = [
+            Vector3::new( -1.0, 0.0, 0.0 ),
+            Vector3::new( 0.0, -1.0, 0.0 ),
+            Vector3::new( 0.0, 0.0, -1.0 ),
+            Vector3::new( 1.0, -2.0, 0.5 ).normal(),
+            Vector3::new( -0.3, 0.2, -3.0 ).normal(),
+        ];
+        for axis in axes.iter() {
+            for angle in [ crate::PI - 0.0005, crate::PI - 0.002, crate::PI - 0.2 ] {
+                let rotation = Matrix4x4::new_rotate_exp( &( *axis * angle ) );
+                let log = rotation.rotation_log();
+                // acos loses precision close to 180 degrees, a flipped axis would be off by ~2 * angle
+                assert!( ( log - ( *axis * angle ) ).magnitude() < 1e-2, "{} != {}", log, *axis * angle );
+                let round_trip = Matrix4x4::new_rotate_exp( &log );
+                for i in 0..16 {
+                    assert!( ( round_trip[i] - rotation[i] ).abs() < 1e-3, "{} != {}", round_trip, rotation );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn determinant_triangular() {
+        // column-major, zeroes below the diagonal
+        let m = Matrix4x4::from_array([
+            2.0, 0.0, 0.0, 0.0,
+            7.0, 3.0, 0.0, 0.0,
+            -1.0, 5.0, 4.0, 0.0,
+            6.0, 2.0, -3.0, 5.0,
+        ]);
+        assert_eq!( m.determinant(), 120.0 );
+        assert_eq!( m.transpose().determinant(), 120.0 );
+    }
+
+    #[test]
+    fn inverse_general() {
+        let m = Matrix4x4::from_array([
+            2.0, 1.0, 0.0, 0.5,
+            -1.0, 3.0, 2.0, 0.0,
+            0.0, 1.0, 4.0, -1.0,
+            1.0, 0.0, 2.0, 3.0,
+        ]);
+        let inverse = m.inverse().unwrap();
+        let identity = Matrix4x4::new_identity();
+        assert_matrix_close( &( m * inverse ), &identity );
+        assert_matrix_close( &( inverse * m ), &identity );
+        assert!( ( inverse.determinant() * m.determinant() - 1.0 ).abs() < 1e-5 );
+    }
+
+    #[test]
+    fn inverse_singular() {
+        let mut m = Matrix4x4::new_identity();
+        m[10] = 0.0;
+        assert!( m.inverse().is_err() );
+        assert!( m.inverse_affine().is_err() );
+        assert!( Matrix4x4::new_zero().inverse().is_err() );
+    }
+
+    #[test]
+    fn inverse_affine_matches_inverse() {
+        let m = Matrix4x4::new_trs( &[ 3.0, -1.0, 0.5 ], &[ 0.7, 0.2, -1.9 ], &[ 1.5, -2.0, 0.25 ] );
+        let affine = m.inverse_affine().unwrap();
+        assert_matrix_close( &affine, &m.inverse().unwrap() );
+        assert_matrix_close( &( m * affine ), &Matrix4x4::new_identity() );
+        let point = Vector3::new( 1.0, 2.0, -3.0 );
+        assert_vector_close( &affine.mul_vector3( &m.mul_vector3( &point ) ), &point );
+    }
+
+    #[test]
+    fn decompose_trs() {
+        let m = Matrix4x4::new_trs( &[ 1.0, -2.0, 3.0 ], &[ 0.3, -1.2, 2.5 ], &[ 2.0, 0.5, 3.0 ] );
+        let ( translation, rotation, scale ) = m.decompose().unwrap();
+        assert_vector_close( &translation, &Vector3::new( 1.0, -2.0, 3.0 ) );
+        assert_vector_close( &scale, &Vector3::new( 2.0, 0.5, 3.0 ) );
+        assert_matrix_close( &recompose( &translation, &rotation, &scale ), &m );
+
+        assert_vector_close( &m.extract_translation(), &translation );
+        assert_vector_close( &m.extract_scale(), &scale );
+        assert!( ( Quaternion::dot( &m.extract_rotation(), &rotation ).abs() - 1.0 ).abs() < 1e-5 );
+    }
+
+    #[test]
+    fn decompose_mirrored() {
+        let m = Matrix4x4::new_trs( &[ 0.0, 4.0, 0.0 ], &[ 1.0, 0.0, -0.5 ], &[ 2.0, -3.0, 4.0 ] );
+        let ( translation, rotation, scale ) = m.decompose().unwrap();
+        assert_vector_close( &scale, &Vector3::new( -2.0, 3.0, 4.0 ) );
+        assert_matrix_close( &recompose( &translation, &rotation, &scale ), &m );
+    }
+
+    #[test]
+    fn decompose_rejects_shear_projection_and_zero_scale() {
+        let mut shear = Matrix4x4::new_identity();
+        shear[4] = 0.5;
+        assert!( shear.decompose().is_none() );
+
+        let mut projective = Matrix4x4::new_identity();
+        projective[3] = 0.1;
+        assert!( projective.decompose().is_none() );
 
+        assert!( Matrix4x4::new_scale( &[ 1.0, 0.0, 1.0 ] ).decompose().is_none() );
     }
 
 }
@@ -1,6 +1,11 @@
-#[allow(dead_code)]
+#[allow(dead_code, clippy::empty_line_after_outer_attr)]
 
 mod matrix4x4;
 pub use matrix4x4::{
-    Matrix4x4,
+    Matrix4x4, MatrixKind,
+};
+
+mod matrix3x3;
+pub use matrix3x3::{
+    Matrix3x3,
 };
\ No newline at end of file
@@ -0,0 +1,137 @@
+use core::fmt::Display;
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::String,
+    vec::Vec,
+};
+
+/// Edge key with the smaller vertex index first
+fn edge_key( a:u32, b:u32 ) -> ( u32, u32 ) {
+    if a < b { ( a, b ) } else { ( b, a ) }
+}
+
+/// Triangle adjacency built from a triangle list index buffer
+/// 
+/// Every edge knows which triangles share it,
+/// which is enough to find neighbors and mesh borders
+/// 
+/// Edges are stored in sorted order so iteration is deterministic
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct MeshAdjacency {
+    indices:Vec<u32>,
+    edges:BTreeMap<( u32, u32 ), Vec<u32>>,
+}
+
+impl MeshAdjacency {
+
+    /// Build adjacency from triangle list `indices`
+    /// 
+    /// Returns: `MeshAdjacency` if `indices` length is a multiple of **3**
+    /// 
+    /// Returns: `String` error if `indices` length is **not** a multiple of **3**
+    pub fn new( indices:&[u32] ) -> Result<Self, String> {
+        if !indices.len().is_multiple_of( 3 ) {
+            return Err(
+                format!(
+                    "MESH ADJACENCY ERROR: Index count must be a multiple of 3, got {}!",
+                    indices.len()
+                )
+            );
+        }
+
+        let mut edges:BTreeMap<( u32, u32 ), Vec<u32>> = BTreeMap::new();
+        for ( triangle, corners ) in indices.chunks_exact( 3 ).enumerate() {
+            for i in 0..3 {
+                let key = edge_key( corners[i], corners[( i + 1 ) % 3] );
+                edges.entry( key ).or_default().push( triangle as u32 );
+            }
+        }
+
+        Ok( Self { indices:indices.to_vec(), edges } )
+    }
+
+    /// Returns: number of triangles
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Returns: number of unique edges
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns: vertex indices of `triangle`
+    pub fn triangle( &self, triangle:u32 ) -> [u32;3] {
+        let first = triangle as usize * 3;
+        [ self.indices[first], self.indices[first + 1], self.indices[first + 2] ]
+    }
+
+    /// Returns: `slice` of triangles that use edge between vertices `a` and `b`,
+    /// empty if edge does not exist
+    pub fn edge_triangles( &self, a:u32, b:u32 ) -> &[u32] {
+        match self.edges.get( &edge_key( a, b ) ) {
+            Some( triangles ) => triangles,
+            None => &[],
+        }
+    }
+
+    /// Neighbors of `triangle` across its edges
+    /// ( *v0 -> v1*, *v1 -> v2*, *v2 -> v0* )
+    /// 
+    /// Non-manifold edges report the first other triangle
+    /// 
+    /// Returns: `None` for border edges
+    pub fn triangle_neighbors( &self, triangle:u32 ) -> [Option<u32>;3] {
+        let corners = self.triangle( triangle );
+        let mut result = [None;3];
+        for ( i, neighbor ) in result.iter_mut().enumerate() {
+            *neighbor = self.edge_triangles( corners[i], corners[( i + 1 ) % 3] )
+                .iter()
+                .copied()
+                .find( |other| *other != triangle );
+        }
+        result
+    }
+
+    /// Returns: `true` if edge between `a` and `b` is used by exactly one triangle
+    pub fn is_border_edge( &self, a:u32, b:u32 ) -> bool {
+        self.edge_triangles( a, b ).len() == 1
+    }
+
+    /// Returns: every border edge, in the direction its triangle winds
+    pub fn border_edges(&self) -> Vec<( u32, u32 )> {
+        let mut result = Vec::new();
+        for ( key, triangles ) in self.edges.iter() {
+            if triangles.len() != 1 {
+                continue;
+            }
+            let corners = self.triangle( triangles[0] );
+            let forward = ( 0..3 ).any( |i| corners[i] == key.0 && corners[( i + 1 ) % 3] == key.1 );
+            result.push( if forward { *key } else { ( key.1, key.0 ) } );
+        }
+        result
+    }
+
+    /// Returns: `true` if every edge is shared by at most two triangles
+    pub fn is_manifold(&self) -> bool {
+        self.edges.values().all( |triangles| triangles.len() <= 2 )
+    }
+
+    /// Returns: `true` if mesh has no border edges
+    pub fn is_closed(&self) -> bool {
+        self.edges.values().all( |triangles| triangles.len() >= 2 )
+    }
+
+}
+
+impl Display for MeshAdjacency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "MeshAdjacency: {} triangles, {} edges",
+            self.triangle_count(), self.edge_count()
+        )
+    }
+}
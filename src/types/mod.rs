@@ -1,13 +1,116 @@
 mod vector;
 pub use vector::{
-    Vector2, Vector3, Vector4
+    Vector2, Vector3, Vector4, VectorN, Vector3Large,
+    Vector2Bool, Vector3Bool, Vector4Bool,
+    RoundingMode,
 };
 
 mod matrix;
-pub use matrix::Matrix4x4;
+pub use matrix::{ Matrix4x4, Matrix3x3, MatrixKind };
 
 mod quaternion;
 pub use quaternion::Quaternion;
 
+mod unit_quaternion;
+pub use unit_quaternion::UnitQuaternion;
+
+mod transform;
+pub use transform::Transform;
+
+mod transform_hierarchy;
+pub use transform_hierarchy::TransformHierarchy;
+
+mod transform_track;
+pub use transform_track::{
+    TransformTrack, TrackMode,
+};
+
 /// `RGB` and `HSV` data structures
+/// 
+/// *sRGB* transfer functions and *RGBA8* byte buffer conversions
 pub mod color;
+
+mod unit_scale;
+pub use unit_scale::UnitScale;
+
+mod random;
+pub use random::{
+    Pcg32, AliasTable,
+};
+
+mod layout;
+pub use layout::{
+    Endianness, MatrixLayout,
+};
+
+mod two_float;
+pub use two_float::{
+    TwoF32, TwoF64,
+};
+
+mod kahan_accumulator;
+pub use kahan_accumulator::KahanAccumulator;
+
+mod stream;
+pub use stream::{
+    MathWriter, MathReader,
+};
+
+mod interval;
+pub use interval::Interval;
+
+mod aabb;
+pub use aabb::Aabb;
+
+mod aabb2d;
+pub use aabb2d::Aabb2d;
+
+mod summed_area_table;
+pub use summed_area_table::SummedAreaTable;
+
+mod flow_field;
+pub use flow_field::FlowField;
+
+mod plane;
+pub use plane::Plane;
+
+mod ray;
+pub use ray::Ray;
+
+mod ray2d;
+pub use ray2d::Ray2d;
+
+mod frustum;
+pub use frustum::Frustum;
+
+mod frame;
+pub use frame::Frame;
+
+mod fixed_timestep;
+pub use fixed_timestep::FixedTimestep;
+
+mod bezier_patch;
+pub use bezier_patch::BezierPatch;
+
+mod orbit_camera;
+pub use orbit_camera::OrbitCamera;
+
+mod fly_camera;
+pub use fly_camera::FlyCamera;
+
+mod atlas;
+pub use atlas::{
+    UvChart, AtlasLayout,
+};
+
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+
+mod mesh_adjacency;
+pub use mesh_adjacency::MeshAdjacency;
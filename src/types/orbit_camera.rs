@@ -0,0 +1,171 @@
+use core::fmt::Display;
+
+use crate::{
+    math,
+    FRAC_PI_2,
+    types::{
+        Vector2, Vector3, Matrix4x4,
+    },
+};
+
+/// Pitch is kept slightly away from straight up/down so view stays well defined
+pub(crate) const PITCH_LIMIT:f32 = FRAC_PI_2 - 0.001;
+/// Closest the camera can get to its target
+const MIN_DISTANCE:f32 = 0.001;
+
+/// Direction looking down **-z** at `yaw` and `pitch` of **0.0**,
+/// `yaw` rotates around **+y**
+pub(crate) fn yaw_pitch_to_forward( yaw:f32, pitch:f32 ) -> Vector3 {
+    let ( sin_yaw, cos_yaw )     = math::sin_cos( yaw );
+    let ( sin_pitch, cos_pitch ) = math::sin_cos( pitch );
+    -Vector3::new( cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw )
+}
+
+/// Orbit ( *arc-ball* ) camera that rotates around a `target`
+/// 
+/// `yaw` rotates around the **+y** axis, `pitch` tilts up and down,
+/// angles are in **Radians**
+/// 
+/// Input moves *goal* values, `update` smoothly moves the camera towards them
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OrbitCamera {
+    target:Vector3,
+    yaw:f32,
+    pitch:f32,
+    distance:f32,
+
+    goal_target:Vector3,
+    goal_yaw:f32,
+    goal_pitch:f32,
+    goal_distance:f32,
+
+    damping:f32,
+}
+
+impl OrbitCamera {
+
+    /// Create new `OrbitCamera`
+    /// 
+    /// `pitch` is clamped just short of straight up and down
+    /// 
+    /// Damping is **0.0** ( *no smoothing* )
+    pub fn new( target:Vector3, yaw:f32, pitch:f32, distance:f32 ) -> Self {
+        let pitch    = pitch.clamp( -PITCH_LIMIT, PITCH_LIMIT );
+        let distance = distance.max( MIN_DISTANCE );
+        Self {
+            target, yaw, pitch, distance,
+            goal_target:target,
+            goal_yaw:yaw,
+            goal_pitch:pitch,
+            goal_distance:distance,
+            damping:0.0,
+        }
+    }
+
+    /// Returns: point camera orbits around
+    pub fn target(&self) -> &Vector3 {
+        &self.target
+    }
+
+    /// Returns: rotation around **+y** axis in **Radians**
+    pub fn yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    /// Returns: tilt in **Radians**
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    /// Returns: distance from target
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Returns: damping speed
+    pub fn damping(&self) -> f32 {
+        self.damping
+    }
+
+    /// Set damping speed
+    /// 
+    /// **0.0** snaps to goal immediately, larger values reach goal faster
+    pub fn set_damping( &mut self, damping:f32 ) {
+        self.damping = damping.max( 0.0 );
+    }
+
+    /// Set goal target
+    pub fn set_target( &mut self, target:Vector3 ) {
+        self.goal_target = target;
+    }
+
+    /// Rotate goal by `delta` ( *yaw, pitch* ) in **Radians**
+    /// 
+    /// Pitch is clamped just short of straight up and down
+    pub fn rotate( &mut self, delta:&Vector2 ) {
+        self.goal_yaw  += delta[0];
+        self.goal_pitch = ( self.goal_pitch + delta[1] ).clamp( -PITCH_LIMIT, PITCH_LIMIT );
+    }
+
+    /// Move goal towards or away from target
+    /// 
+    /// Positive `delta` moves closer, scaled by current distance
+    /// so zooming feels the same at any distance
+    pub fn zoom( &mut self, delta:f32 ) {
+        self.goal_distance = ( self.goal_distance * ( 1.0 - delta ) ).max( MIN_DISTANCE );
+    }
+
+    /// Move goal target along camera *right* and *up* axes
+    /// 
+    /// `delta` is scaled by current distance
+    pub fn pan( &mut self, delta:&Vector2 ) {
+        let forward = self.forward();
+        let right   = Vector3::cross( &forward, &Vector3::new_up() ).normal();
+        let up      = Vector3::cross( &right, &forward );
+        self.goal_target = self.goal_target +
+            ( ( ( right * delta[0] ) + ( up * delta[1] ) ) * self.goal_distance );
+    }
+
+    /// Move camera towards goal values
+    /// 
+    /// `delta_time` in **seconds**
+    pub fn update( &mut self, delta_time:f32 ) {
+        let t = if self.damping == 0.0 {
+            1.0
+        } else {
+            1.0 - math::exp( -self.damping * delta_time )
+        };
+
+        self.target   = Vector3::lerp( &self.target, &self.goal_target, t );
+        self.yaw      = self.yaw + ( ( self.goal_yaw - self.yaw ) * t );
+        self.pitch    = self.pitch + ( ( self.goal_pitch - self.pitch ) * t );
+        self.distance = self.distance + ( ( self.goal_distance - self.distance ) * t );
+    }
+
+    /// Returns: normalized direction camera is looking at
+    pub fn forward(&self) -> Vector3 {
+        yaw_pitch_to_forward( self.yaw, self.pitch )
+    }
+
+    /// Returns: camera position in world space
+    pub fn position(&self) -> Vector3 {
+        self.target - ( self.forward() * self.distance )
+    }
+
+    /// Returns: new **view** `Matrix4x4`
+    pub fn view_matrix(&self) -> Matrix4x4 {
+        Matrix4x4::new_view( &self.position(), &self.forward(), &Vector3::new_up() )
+    }
+
+}
+
+impl Display for OrbitCamera {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "OrbitCamera: target: {}, yaw: {}, pitch: {}, distance: {}",
+            self.target, self.yaw, self.pitch, self.distance
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use core::fmt::Display;
+
+use alloc::string::String;
+
+use super::{
+    Vector3,
+    Vector4,
+    Matrix4x4,
+};
+
+/// Infinite plane described by `normal` and `distance`
+/// 
+/// Points on the plane satisfy `dot( normal, point ) + distance = 0.0`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Plane {
+    normal:Vector3,
+    distance:f32,
+}
+
+impl Plane {
+
+    /// Create new `Plane` from `normal` and `distance`
+    /// 
+    /// `normal` should be normalized
+    pub fn new( normal:Vector3, distance:f32 ) -> Self {
+        Self { normal, distance }
+    }
+
+    /// Create new `Plane` that contains `point` and faces `normal`
+    /// 
+    /// `normal` is normalized
+    pub fn from_point_normal( point:&Vector3, normal:&Vector3 ) -> Self {
+        let normal = normal.normal();
+        Self { normal, distance:-Vector3::dot( &normal, point ) }
+    }
+
+    /// Create new `Plane` that contains `a`, `b` and `c`
+    /// 
+    /// Normal faces towards the side where `a`, `b`, `c` are *counter-clockwise*
+    pub fn from_points( a:&Vector3, b:&Vector3, c:&Vector3 ) -> Self {
+        let normal = Vector3::cross( &( *b - *a ), &( *c - *a ) );
+        Self::from_point_normal( a, &normal )
+    }
+
+    /// Create new `Plane` from `Vector4` ( *nx, ny, nz, distance* )
+    pub fn from_vector4( v:&Vector4 ) -> Self {
+        Self::new( Vector3::new( v[0], v[1], v[2] ), v[3] )
+    }
+
+    /// Returns: new `Vector4` ( *nx, ny, nz, distance* )
+    /// 
+    /// `dot( plane, point )` with `point` `w` = **1.0** is the signed distance
+    pub fn as_vector4(&self) -> Vector4 {
+        Vector4::new( self.normal[0], self.normal[1], self.normal[2], self.distance )
+    }
+
+    /// Returns: `reference` to normal
+    pub fn normal(&self) -> &Vector3 {
+        &self.normal
+    }
+
+    /// Returns: signed distance from origin along `-normal`
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Returns: signed distance from plane to `point`,
+    /// positive on the side `normal` faces
+    pub fn signed_distance( &self, point:&Vector3 ) -> f32 {
+        Vector3::dot( &self.normal, point ) + self.distance
+    }
+
+    /// Returns: `point` projected onto plane
+    pub fn project_point( &self, point:&Vector3 ) -> Vector3 {
+        *point - ( self.normal * self.signed_distance( point ) )
+    }
+
+    /// Transform plane by `matrix`
+    /// 
+    /// Planes transform by the *inverse-transpose* of `matrix`, **not** like points
+    /// 
+    /// Returns: new `Plane` with normalized normal
+    /// 
+    /// Returns: `String` error if `matrix` is **not** invertible
+    pub fn transformed( &self, matrix:&Matrix4x4 ) -> Result<Self, String> {
+        Ok( self.transformed_inverse_transpose( &matrix.inverse()?.transpose() ) )
+    }
+
+    /// Transform plane by precomputed *inverse-transpose* of a matrix
+    /// 
+    /// Useful when transforming many planes by the same matrix
+    /// 
+    /// Returns: new `Plane` with normalized normal
+    pub fn transformed_inverse_transpose( &self, inverse_transpose:&Matrix4x4 ) -> Self {
+        let v = inverse_transpose.mul_vector4( &self.as_vector4() );
+        let length = Vector3::new( v[0], v[1], v[2] ).magnitude();
+        Self::from_vector4( &( v / length ) )
+    }
+
+}
+
+impl Display for Plane {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Plane: normal: {}, distance: {}", self.normal, self.distance )
+    }
+}
+
+impl From<Vector4> for Plane {
+    fn from( v:Vector4 ) -> Self {
+        Self::from_vector4( &v )
+    }
+}
+
+impl From<Plane> for Vector4 {
+    fn from( plane:Plane ) -> Self {
+        plane.as_vector4()
+    }
+}
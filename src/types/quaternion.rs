@@ -4,9 +4,18 @@ use core::ops::{
     Add, Sub, Mul, Div, Index, IndexMut
 };
 
+use alloc::{
+    format,
+    string::String,
+};
+
+use core::f32::consts::FRAC_1_SQRT_2;
+
 use crate::{
     FRAC_PI_2,
+    math,
     types::{
+        Matrix3x3, Matrix4x4,
         vector::{
             Vector3,
             magnitude_components,
@@ -21,20 +30,44 @@ use crate::{
     }
 };
 
-// TODO: Implement Slerp, Inverse, From/As Angle Axis
-
 /// 4-component structure
 /// 
 /// Compact way to represent rotations without gimbal lock.
 /// 
 /// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
 pub struct Quaternion {
     components:[f32;4]
 }
 
 impl Quaternion {
 
+    /// `Quaternion` with no rotation
+    pub const IDENTITY:Self = Self { components:[1.0, 0.0, 0.0, 0.0] };
+
+    /// Rotation of **90** degrees around *x axis*
+    pub const ROT_X_90:Self = Self { components:[FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0] };
+    /// Rotation of **180** degrees around *x axis*
+    pub const ROT_X_180:Self = Self { components:[0.0, 1.0, 0.0, 0.0] };
+    /// Rotation of **270** degrees around *x axis*
+    pub const ROT_X_270:Self = Self { components:[FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0, 0.0] };
+
+    /// Rotation of **90** degrees around *y axis*
+    pub const ROT_Y_90:Self = Self { components:[FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2, 0.0] };
+    /// Rotation of **180** degrees around *y axis*
+    pub const ROT_Y_180:Self = Self { components:[0.0, 0.0, 1.0, 0.0] };
+    /// Rotation of **270** degrees around *y axis*
+    pub const ROT_Y_270:Self = Self { components:[FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2, 0.0] };
+
+    /// Rotation of **90** degrees around *z axis*
+    pub const ROT_Z_90:Self = Self { components:[FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2] };
+    /// Rotation of **180** degrees around *z axis*
+    pub const ROT_Z_180:Self = Self { components:[0.0, 0.0, 0.0, 1.0] };
+    /// Rotation of **270** degrees around *z axis*
+    pub const ROT_Z_270:Self = Self { components:[FRAC_1_SQRT_2, 0.0, 0.0, -FRAC_1_SQRT_2] };
+
+
     /// Create new `Quaternion` from given `scalar` and `x` `y` `z` values
     pub fn new( scalar:f32, x:f32, y:f32, z:f32 ) -> Self {
         Self { components:[scalar,x,y,z] }
@@ -57,23 +90,190 @@ impl Quaternion {
     /// Create new `Quaternion` from given euler angles (`Vector3`)
     /// 
     /// Angles in **Radians**
+    /// 
+    /// Same rotation as `Matrix4x4::new_rotate`, x * y * z
     pub fn from_euler_angles( euler:Vector3 ) -> Self {
 
-        let ( x_sin, x_cos ) = ( euler[0] / 2.0 ).sin_cos();
-        let ( y_sin, y_cos ) = ( euler[1] / 2.0 ).sin_cos();
-        let ( z_sin, z_cos ) = ( euler[2] / 2.0 ).sin_cos();
+        let ( x_sin, x_cos ) = math::sin_cos( euler[0] / 2.0 );
+        let ( y_sin, y_cos ) = math::sin_cos( euler[1] / 2.0 );
+        let ( z_sin, z_cos ) = math::sin_cos( euler[2] / 2.0 );
 
         Self {
             components:[
-                ( x_cos * y_cos * z_cos ) + ( x_sin * y_sin * z_sin ), // scalar
+                ( x_cos * y_cos * z_cos ) - ( x_sin * y_sin * z_sin ), // scalar
 
                 ( x_sin * y_cos * z_cos ) + ( x_cos * y_sin * z_sin ), // vector x
-                ( x_cos * y_sin * z_cos ) + ( x_sin * y_cos * z_sin ), // vector y
+                ( x_cos * y_sin * z_cos ) - ( x_sin * y_cos * z_sin ), // vector y
                 ( x_cos * y_cos * z_sin ) + ( x_sin * y_sin * z_cos ), // vector z
             ]
         }
     }
 
+    /// Create new `Quaternion` from given euler angles (`Vector3`)
+    /// 
+    /// Angles in **Degrees**
+    pub fn from_euler_angles_deg( euler:Vector3 ) -> Self {
+        Self::from_euler_angles( euler.map( degrees_to_radians ) )
+    }
+
+    /// Create new `Quaternion` rotating forward ( **+z** ) to `forward`
+    /// and up ( **+y** ) as close to `up` as possible
+    /// 
+    /// Neither vector needs to be normalized, if they are parallel
+    /// an arbitrary but stable up is used
+    /// 
+    /// Returns: `IDENTITY` if `forward` is zero
+    pub fn look_rotation( forward:&Vector3, up:&Vector3 ) -> Self {
+        if forward.sqr_magnitude() <= f32::EPSILON * f32::EPSILON {
+            return Self::IDENTITY;
+        }
+        let forward = forward.normal();
+
+        let mut right = Vector3::cross( up, &forward );
+        if right.sqr_magnitude() <= f32::EPSILON {
+            right = forward.any_perpendicular();
+        }
+        let right = right.normal();
+        let up = Vector3::cross( &forward, &right );
+
+        let mut basis = Matrix4x4::new_identity();
+        for row in 0..3 {
+            basis[row]     = right[row];
+            basis[row + 4] = up[row];
+            basis[row + 8] = forward[row];
+        }
+        Self::from_matrix4x4( &basis )
+    }
+
+    /// Create new `Quaternion` with shortest rotation from direction `from` to direction `to`
+    /// 
+    /// Neither vector needs to be normalized,
+    /// opposite directions rotate **180** degrees around an arbitrary perpendicular axis
+    /// 
+    /// Returns: `IDENTITY` if either vector is zero
+    pub fn from_to_rotation( from:&Vector3, to:&Vector3 ) -> Self {
+        let tiny = f32::EPSILON * f32::EPSILON;
+        if from.sqr_magnitude() <= tiny || to.sqr_magnitude() <= tiny {
+            return Self::IDENTITY;
+        }
+        let ( from, to ) = ( from.normal(), to.normal() );
+
+        let cos_theta = Vector3::dot( &from, &to );
+        if cos_theta <= -1.0 + 1e-6 {
+            return Self::from_scalar_vector( 0.0, from.any_perpendicular() );
+        }
+
+        // half-way quaternion: ( 1 + cos, from x to ) normalized
+        let s = math::sqrt( 2.0 * ( 1.0 + cos_theta ) );
+        Self::from_scalar_vector( s / 2.0, Vector3::cross( &from, &to ) / s )
+    }
+
+    /// Create new `Quaternion` rotating only around *y axis* so that
+    /// forward ( **+z** ) points along `direction` flattened onto the *xz plane*
+    /// 
+    /// Keeps objects upright, `direction` does not need to be normalized
+    /// 
+    /// Returns: `IDENTITY` if `direction` is vertical or zero
+    pub fn yaw_towards( direction:&Vector3 ) -> Self {
+        let ( x, z ) = ( direction[0], direction[2] );
+        if ( x * x ) + ( z * z ) <= f32::EPSILON * f32::EPSILON {
+            return Self::IDENTITY;
+        }
+
+        let ( sin, cos ) = math::sin_cos( math::atan2( x, z ) / 2.0 );
+        Self::new( cos, 0.0, sin, 0.0 )
+    }
+
+    /// Create new `Quaternion` rotating only around *y axis* so that
+    /// an object at `from` faces `to`, height difference is ignored
+    /// 
+    /// Returns: `IDENTITY` if `to` is directly above or below `from`
+    pub fn face_position_flat( from:&Vector3, to:&Vector3 ) -> Self {
+        Self::yaw_towards( &( *to - *from ) )
+    }
+
+    /// Create new `Quaternion` from **rotation** `Matrix4x4`
+    /// 
+    /// Only the upper-left 3x3 cells are used, see `from_matrix3x3`
+    pub fn from_matrix4x4( m:&Matrix4x4 ) -> Self {
+        Self::from_matrix3x3( &Matrix3x3::from_matrix4x4( m ) )
+    }
+
+    /// Create new `Quaternion` from **rotation** `Matrix3x3`
+    /// 
+    /// Cells should form a pure rotation ( *no scale or shear* )
+    /// 
+    /// Uses *Shepperd's* method which stays accurate for every rotation,
+    /// including rotations of 180 degrees
+    pub fn from_matrix3x3( m:&Matrix3x3 ) -> Self {
+        let ( m00, m11, m22 ) = ( m[0], m[4], m[8] );
+        let trace = m00 + m11 + m22;
+
+        // pick largest of 4 candidates to avoid dividing by a small number
+        if trace >= m00 && trace >= m11 && trace >= m22 {
+            let s = math::sqrt( 1.0 + trace ) * 2.0;
+            Self::new(
+                0.25 * s,
+                ( m[5] - m[7] ) / s,
+                ( m[6] - m[2] ) / s,
+                ( m[1] - m[3] ) / s,
+            )
+        } else if m00 >= m11 && m00 >= m22 {
+            let s = math::sqrt( 1.0 + m00 - m11 - m22 ) * 2.0;
+            Self::new(
+                ( m[5] - m[7] ) / s,
+                0.25 * s,
+                ( m[3] + m[1] ) / s,
+                ( m[6] + m[2] ) / s,
+            )
+        } else if m11 >= m22 {
+            let s = math::sqrt( 1.0 + m11 - m00 - m22 ) * 2.0;
+            Self::new(
+                ( m[6] - m[2] ) / s,
+                ( m[3] + m[1] ) / s,
+                0.25 * s,
+                ( m[7] + m[5] ) / s,
+            )
+        } else {
+            let s = math::sqrt( 1.0 + m22 - m00 - m11 ) * 2.0;
+            Self::new(
+                ( m[1] - m[3] ) / s,
+                ( m[6] + m[2] ) / s,
+                ( m[7] + m[5] ) / s,
+                0.25 * s,
+            )
+        }
+    }
+
+    /// Create new `Quaternion` rotating by `angle` around `axis`
+    /// 
+    /// `axis` does not need to be normalized, zero `axis` gives `IDENTITY`
+    /// 
+    /// `angle` is in **Radians**
+    pub fn from_angle_axis( angle:f32, axis:&Vector3 ) -> Self {
+        let magnitude = axis.magnitude();
+        if magnitude == 0.0 {
+            return Self::IDENTITY;
+        }
+        let ( sin, cos ) = math::sin_cos( angle / 2.0 );
+        Self::from_scalar_vector( cos, *axis * ( sin / magnitude ) )
+    }
+
+    /// Get `Quaternion` rotation as angle and axis
+    /// 
+    /// `Quaternion` should be normalized
+    /// 
+    /// Returns: ( angle in **Radians** between **0.0** and **2π**, normalized axis ),
+    /// axis is `x` if there is no rotation
+    pub fn as_angle_axis(&self) -> ( f32, Vector3 ) {
+        let vector = Vector3::new( self[1], self[2], self[3] );
+        let sin = vector.magnitude();
+        if sin == 0.0 {
+            return ( 0.0, Vector3::new( 1.0, 0.0, 0.0 ) );
+        }
+        ( 2.0 * math::atan2( sin, self[0] ), vector / sin )
+    }
+
     /// Get `Quaternion` rotation as *euler angles*
     /// 
     /// Angles in **Radians**
@@ -82,16 +282,25 @@ impl Quaternion {
     /// this euler angles representation might not have the same values as the original*
     pub fn as_euler_angles(&self) -> Vector3 {
         Vector3::from_array([
-            ( 2.0 * ( self[0] * self[1] + self[2] * self[3] ) )
-                .atan2( 1.0 - 2.0 * ( self[1] * self[1] + self[2] * self[2] ) ),
+            math::atan2(
+                2.0 * ( self[0] * self[1] - self[2] * self[3] ),
+                1.0 - 2.0 * ( self[1] * self[1] + self[2] * self[2] )
+            ),
 
-            Self::no_nan_asin( 2.0 * ( (self[0] * self[2]) - (self[3] * self[1]) ) ),
+            Self::no_nan_asin( 2.0 * ( (self[0] * self[2]) + (self[3] * self[1]) ) ),
 
-            ( 2.0 * (self[0] * self[3] + self[1] * self[2]) )
-                .atan2( 1.0 - 2.0 * (self[2] * self[2] + self[3] * self[3]) )
+            math::atan2(
+                2.0 * (self[0] * self[3] - self[1] * self[2]),
+                1.0 - 2.0 * (self[2] * self[2] + self[3] * self[3])
+            )
         ])
     }
 
+    /// Returns: new `Vector3` with euler angles in **Degrees**
+    pub fn as_euler_angles_deg(&self) -> Vector3 {
+        self.as_euler_angles().map( radians_to_degrees )
+    }
+
     /// Get `Quaternion` values as `f32` array
     /// 
     /// `0` = scalar
@@ -120,7 +329,7 @@ impl Quaternion {
 
     /// Returns: `Quaternion` length
     pub fn magnitude(&self) -> f32 {
-        self.sqr_magnitude().sqrt()
+        math::sqrt( self.sqr_magnitude() )
     }
 
     /// Returns: normalization result
@@ -128,6 +337,7 @@ impl Quaternion {
     /// `Ok`: if `Quaternion`'s norm is **not** zero.
     /// 
     /// `Err`: if `Quaternion`'s norm **is** zero.
+    #[allow(clippy::useless_format)]
     pub fn normalize(&self) -> Result<Self, String> {
         let norm = self.norm();
         if norm == 0.0 {
@@ -137,7 +347,7 @@ impl Quaternion {
         }
 
         // TODO: Implement faster inverse square root
-        let n = 1.0 / norm.sqrt();
+        let n = 1.0 / math::sqrt( norm );
 
         Ok( Self {
             components:[
@@ -150,6 +360,7 @@ impl Quaternion {
     }
 
     /// Returns: new `Quaternion` with the same magtinude but the sign of the imaginary components flipped
+    #[allow(clippy::clone_on_copy)]
     pub fn conjugate(&self) -> Self {
         Self {
             components:[
@@ -161,6 +372,204 @@ impl Quaternion {
         }
     }
 
+    /// Returns: inversion result, conjugate divided by norm
+    /// 
+    /// `Ok`: if `Quaternion`'s norm is **not** zero.
+    /// 
+    /// `Err`: if `Quaternion`'s norm **is** zero.
+    pub fn inverse(&self) -> Result<Self, String> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return Err( String::from("Quaternion cannot be inverted if its square magnitude is 0.0!") );
+        }
+        Ok( self.conjugate() * ( 1.0 / norm ) )
+    }
+
+    /// Get `Quaternion` rotation as **rotation** `Matrix4x4`
+    /// 
+    /// `Quaternion` should be normalized
+    pub fn as_matrix4x4(&self) -> Matrix4x4 {
+        let ( w, x, y, z ) = ( self[0], self[1], self[2], self[3] );
+
+        let mut result = Matrix4x4::new_identity();
+        // column - 0
+        result[0]  = 1.0 - ( 2.0 * ( ( y * y ) + ( z * z ) ) );
+        result[1]  = 2.0 * ( ( x * y ) + ( w * z ) );
+        result[2]  = 2.0 * ( ( x * z ) - ( w * y ) );
+        // column - 1
+        result[4]  = 2.0 * ( ( x * y ) - ( w * z ) );
+        result[5]  = 1.0 - ( 2.0 * ( ( x * x ) + ( z * z ) ) );
+        result[6]  = 2.0 * ( ( y * z ) + ( w * x ) );
+        // column - 2
+        result[8]  = 2.0 * ( ( x * z ) + ( w * y ) );
+        result[9]  = 2.0 * ( ( y * z ) - ( w * x ) );
+        result[10] = 1.0 - ( 2.0 * ( ( x * x ) + ( y * y ) ) );
+
+        result
+    }
+
+    /// Returns: **dot** product of `a` and `b`
+    pub fn dot( a:&Self, b:&Self ) -> f32 {
+        dot_components( a.as_array(), b.as_array() )
+    }
+
+    /// Spherically interpolate from `a` to `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new `Quaternion` with rotation between `a` and `b`
+    pub fn slerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::slerp_unclamped( a, b, t.clamp( 0.0, 1.0 ) )
+    }
+
+    /// Spherically interpolate from `a` to `b`
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// `a` and `b` should be normalized
+    /// 
+    /// Always takes the shortest path, same as `slerp_precise`
+    /// 
+    /// Returns: new `Quaternion` with rotation between `a` and `b`
+    pub fn slerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::slerp_precise( a, b, t )
+    }
+
+    /// Spherically interpolate from `a` to `b` with constant angular speed
+    /// 
+    /// Angle between `a` and `b` is measured with *atan2* so it stays accurate
+    /// for nearly parallel and nearly opposite quaternions,
+    /// below **1e-4** radians interpolation falls back to normalized lerp
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// `a` and `b` should be normalized
+    /// 
+    /// Always takes the shortest path
+    /// 
+    /// Returns: new `Quaternion` with rotation between `a` and `b`
+    pub fn slerp_precise( a:&Self, b:&Self, t:f32 ) -> Self {
+        const EPSILON:f32 = 1e-4;
+
+        // q and -q represent the same rotation, pick the closer one
+        let b = if Self::dot( a, b ) < 0.0 { *b * -1.0 } else { *b };
+
+        // angle between a and b ( *half the rotation angle* ), accurate even when a and b are almost equal
+        let theta = 2.0 * math::atan2( ( *a - b ).magnitude(), ( *a + b ).magnitude() );
+        if theta < EPSILON {
+            return Self::nlerp_unclamped( a, &b, t );
+        }
+
+        let sin_theta = math::sin( theta );
+        let weight_a = math::sin( ( 1.0 - t ) * theta ) / sin_theta;
+        let weight_b = math::sin( t * theta ) / sin_theta;
+
+        ( *a * weight_a ) + ( b * weight_b )
+    }
+
+    /// Approximately spherically interpolate from `a` to `b`
+    /// 
+    /// Normalized lerp with `t` corrected by a polynomial fit of `slerp_precise`,
+    /// no trigonometric functions, rotation error stays below **0.01** radians
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// `a` and `b` should be normalized
+    /// 
+    /// Always takes the shortest path
+    /// 
+    /// Returns: new normalized `Quaternion` with rotation between `a` and `b`
+    pub fn slerp_fast( a:&Self, b:&Self, t:f32 ) -> Self {
+        // polynomial from "Approximating slerp", Arseny Kapoulkine
+        let d = Self::dot( a, b ).abs();
+        let k = 0.931872 + ( d * ( -1.25654 + ( d * 0.331442 ) ) );
+        let corrected = t + ( t * ( t - 0.5 ) * ( t - 1.0 ) * k );
+        Self::nlerp_unclamped( a, b, corrected )
+    }
+
+    /// Normalized linear interpolation from `a` to `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new normalized `Quaternion` with rotation between `a` and `b`
+    pub fn nlerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::nlerp_unclamped( a, b, t.clamp( 0.0, 1.0 ) )
+    }
+
+    /// Normalized linear interpolation from `a` to `b`
+    /// 
+    /// Much cheaper than `slerp_unclamped`, angular speed is not constant
+    /// but the path is the same
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// Always takes the shortest path
+    /// 
+    /// Returns: new normalized `Quaternion` with rotation between `a` and `b`,
+    /// `a` if the result has no length
+    pub fn nlerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        let b = if Self::dot( a, b ) < 0.0 { *b * -1.0 } else { *b };
+        let result = ( *a * ( 1.0 - t ) ) + ( b * t );
+        result.normalize().unwrap_or( *a )
+    }
+
+    /// Inner control point of `current` for `squad`,
+    /// smooths the curve through `previous`, `current` and `next` keys
+    /// 
+    /// All keys should be normalized
+    /// 
+    /// Returns: new normalized `Quaternion`
+    pub fn squad_inner_quadrangle( previous:&Self, current:&Self, next:&Self ) -> Self {
+        let inverse = current.conjugate();
+        let closest = | q:&Self | if Self::dot( current, q ) < 0.0 { *q * -1.0 } else { *q };
+
+        let log_next     = ( inverse * closest( next ) ).unit_log();
+        let log_previous = ( inverse * closest( previous ) ).unit_log();
+        let tangent = ( log_next + log_previous ) * -0.25;
+
+        *current * Self::unit_exp( &tangent )
+    }
+
+    /// Spherical cubic interpolation from `a` to `b`
+    /// 
+    /// `inner_a` `inner_b`: control points from `squad_inner_quadrangle`
+    /// of `a` and `b` with their neighboring keys
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new `Quaternion` with rotation between `a` and `b`,
+    /// rotation speed is continuous across keys
+    pub fn squad( a:&Self, inner_a:&Self, inner_b:&Self, b:&Self, t:f32 ) -> Self {
+        let t = t.clamp( 0.0, 1.0 );
+        Self::slerp_unclamped(
+            &Self::slerp_unclamped( a, b, t ),
+            &Self::slerp_unclamped( inner_a, inner_b, t ),
+            2.0 * t * ( 1.0 - t )
+        )
+    }
+
+    /// Logarithm of normalized `Quaternion`
+    /// 
+    /// Returns: rotation axis scaled by half the rotation angle
+    fn unit_log(&self) -> Vector3 {
+        let vector = Vector3::new( self[1], self[2], self[3] );
+        let sin = vector.magnitude();
+        if sin < f32::EPSILON {
+            return vector;
+        }
+        vector * ( math::atan2( sin, self[0] ) / sin )
+    }
+
+    /// Exponential of pure `Quaternion` `v`, inverse of `unit_log`
+    fn unit_exp( v:&Vector3 ) -> Self {
+        let angle = v.magnitude();
+        if angle < f32::EPSILON {
+            return Self::from_scalar_vector( 1.0, *v ).normalize().unwrap_or( Self::IDENTITY );
+        }
+        let ( sin, cos ) = math::sin_cos( angle );
+        Self::from_scalar_vector( cos, *v * ( sin / angle ) )
+    }
+
     fn no_nan_asin(n:f32) -> f32 {
         if n.abs() >= 1.0 {
             if n.is_sign_positive() {
@@ -169,14 +578,14 @@ impl Quaternion {
                 -FRAC_PI_2
             }
         } else {
-            n.asin()
+            math::asin( n )
         }
     }
 
 }
 
 impl Display for Quaternion {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!( f, "Quaternion:\n    scalar:{}, vector: {}, {}, {}", self[0], self[1], self[2], self[3] )
     }
 }
@@ -279,4 +688,271 @@ impl IndexMut<usize> for Quaternion {
     fn index_mut(&mut self, index:usize) -> &mut Self::Output {
         &mut self.components[index]
     }
-}
\ No newline at end of file
+}
+
+impl AsRef<[f32]> for Quaternion {
+    fn as_ref(&self) -> &[f32] {
+        &self.components
+    }
+}
+
+impl AsMut<[f32]> for Quaternion {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.components
+    }
+}
+
+/// Iterates scalar first, then vector components
+impl IntoIterator for Quaternion {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+/// Iterates scalar first, then vector components
+impl<'a> IntoIterator for &'a Quaternion {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+/// Create new `Quaternion` from `f32;4`
+/// 
+/// `0` = scalar
+/// 
+/// `1` `2` `3` = vector
+impl From<[f32;4]> for Quaternion {
+    fn from(components:[f32;4]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+/// `0` = scalar
+/// 
+/// `1` `2` `3` = vector
+impl From<Quaternion> for [f32;4] {
+    fn from(q:Quaternion) -> Self {
+        q.components
+    }
+}
+
+impl From<&Quaternion> for [f32;4] {
+    fn from(q:&Quaternion) -> Self {
+        q.components
+    }
+}
+
+/// `0` = scalar
+/// 
+/// `1` `2` `3` = vector
+impl From<Quaternion> for ( f32, f32, f32, f32 ) {
+    fn from(q:Quaternion) -> Self {
+        ( q[0], q[1], q[2], q[3] )
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ PI, FRAC_PI_2 };
+
+    fn assert_same_rotation( a:&Quaternion, b:&Quaternion ) {
+        // q and -q are the same rotation
+        let dot = Quaternion::dot( a, b ).abs();
+        assert!( ( dot - 1.0 ).abs() < 1e-5, "{} != {}", a, b );
+    }
+
+    fn axis_angle( axis:Vector3, angle:f32 ) -> Quaternion {
+        let ( sin, cos ) = math::sin_cos( angle / 2.0 );
+        Quaternion::from_scalar_vector( cos, axis.normal() * sin )
+    }
+
+    /// Angle of the rotation from `a` to `b`
+    fn rotation_angle( a:&Quaternion, b:&Quaternion ) -> f32 {
+        let difference = a.conjugate() * *b;
+        let vector = Vector3::new( difference[1], difference[2], difference[3] );
+        2.0 * math::atan2( vector.magnitude(), difference[0].abs() )
+    }
+
+    #[test]
+    fn angle_axis_round_trip() {
+        let axis = Vector3::new( 1.0, -2.0, 0.5 );
+        let q = Quaternion::from_angle_axis( 2.0, &axis );
+        assert_same_rotation( &q, &axis_angle( axis, 2.0 ) );
+        let ( angle, result_axis ) = q.as_angle_axis();
+        assert!( ( angle - 2.0 ).abs() < 1e-5 );
+        assert!( ( result_axis - axis.normal() ).magnitude() < 1e-5 );
+
+        assert_eq!( Quaternion::from_angle_axis( 1.0, &Vector3::new_zero() ), Quaternion::IDENTITY );
+        assert_eq!( Quaternion::IDENTITY.as_angle_axis(), ( 0.0, Vector3::new( 1.0, 0.0, 0.0 ) ) );
+    }
+
+    #[test]
+    fn inverse() {
+        let q = Quaternion::new( 1.0, 2.0, -3.0, 0.5 );
+        let product = q * q.inverse().unwrap();
+        for ( component, expected ) in product.as_array().iter().zip( Quaternion::IDENTITY.as_array() ) {
+            assert!( ( component - expected ).abs() < 1e-6 );
+        }
+        assert!( Quaternion::new( 0.0, 0.0, 0.0, 0.0 ).inverse().is_err() );
+    }
+
+    #[test]
+    fn slerp_precise_endpoints_and_midpoint() {
+        let a = axis_angle( Vector3::new( 0.0, 0.0, 1.0 ), 0.0 );
+        let b = axis_angle( Vector3::new( 0.0, 0.0, 1.0 ), FRAC_PI_2 );
+        assert_same_rotation( &Quaternion::slerp_precise( &a, &b, 0.0 ), &a );
+        assert_same_rotation( &Quaternion::slerp_precise( &a, &b, 1.0 ), &b );
+        assert_same_rotation(
+            &Quaternion::slerp_precise( &a, &b, 0.5 ),
+            &axis_angle( Vector3::new( 0.0, 0.0, 1.0 ), FRAC_PI_2 / 2.0 )
+        );
+    }
+
+    #[test]
+    fn slerp_precise_constant_velocity() {
+        let a = axis_angle( Vector3::new( 1.0, 2.0, -1.0 ), 0.4 );
+        let b = axis_angle( Vector3::new( -3.0, 0.5, 2.0 ), 2.1 );
+        let total = rotation_angle( &a, &b );
+        for step in 0..=10 {
+            let t = step as f32 / 10.0;
+            let q = Quaternion::slerp_precise( &a, &b, t );
+            assert!( ( q.magnitude() - 1.0 ).abs() < 1e-5 );
+            assert!( ( rotation_angle( &a, &q ) - ( total * t ) ).abs() < 1e-4 );
+        }
+    }
+
+    #[test]
+    fn slerp_precise_shortest_path_and_extremes() {
+        let a = axis_angle( Vector3::new( 1.0, 0.0, 0.0 ), 0.3 );
+        let b = axis_angle( Vector3::new( 1.0, 0.0, 0.0 ), 1.3 );
+        let expected = axis_angle( Vector3::new( 1.0, 0.0, 0.0 ), 0.8 );
+        assert_same_rotation( &Quaternion::slerp_precise( &a, &( b * -1.0 ), 0.5 ), &expected );
+
+        // nearly parallel falls back to normalized lerp
+        let near = axis_angle( Vector3::new( 1.0, 0.0, 0.0 ), 0.3 + 1e-5 );
+        let q = Quaternion::slerp_precise( &a, &near, 0.5 );
+        assert!( q.as_array().iter().all( | c | c.is_finite() ) );
+        assert_same_rotation( &q, &a );
+
+        // almost half a turn apart
+        let identity = Quaternion::IDENTITY;
+        let far = axis_angle( Vector3::new( 0.0, 1.0, 0.0 ), PI - 0.01 );
+        assert_same_rotation(
+            &Quaternion::slerp_precise( &identity, &far, 0.5 ),
+            &axis_angle( Vector3::new( 0.0, 1.0, 0.0 ), ( PI - 0.01 ) / 2.0 )
+        );
+    }
+
+    #[test]
+    fn slerp_clamps_t() {
+        let a = axis_angle( Vector3::new( 0.0, 1.0, 0.0 ), 0.2 );
+        let b = axis_angle( Vector3::new( 0.0, 1.0, 0.0 ), 1.2 );
+        assert_same_rotation( &Quaternion::slerp( &a, &b, -1.0 ), &a );
+        assert_same_rotation( &Quaternion::slerp( &a, &b, 2.0 ), &b );
+        assert_same_rotation(
+            &Quaternion::slerp_unclamped( &a, &b, 2.0 ),
+            &axis_angle( Vector3::new( 0.0, 1.0, 0.0 ), 2.2 )
+        );
+    }
+
+    #[test]
+    fn slerp_fast_matches_slerp_precise() {
+        let a = axis_angle( Vector3::new( 0.2, 1.0, -0.4 ), -0.9 );
+        for angle in [ 0.01, 0.5, 1.5, 2.5, 3.1 ] {
+            let b = a * axis_angle( Vector3::new( 1.0, -1.0, 0.5 ), angle );
+            for step in 0..=20 {
+                let t = step as f32 / 20.0;
+                let fast    = Quaternion::slerp_fast( &a, &b, t );
+                let precise = Quaternion::slerp_precise( &a, &b, t );
+                assert!( ( fast.magnitude() - 1.0 ).abs() < 1e-5 );
+                assert!( rotation_angle( &fast, &precise ) < 0.01 );
+            }
+        }
+    }
+
+    #[test]
+    fn from_euler_angles_matches_new_rotate() {
+        let eulers = [
+            Vector3::new( 0.3, -1.1, 2.0 ),
+            Vector3::new( -0.7, 0.4, 1.3 ),
+            Vector3::new( 1.0, 0.5, -0.25 ),
+        ];
+        for euler in eulers {
+            let q = Quaternion::from_euler_angles( euler );
+            assert!( ( q.magnitude() - 1.0 ).abs() < 1e-6 );
+
+            let expected = Matrix4x4::new_rotate( euler.as_array() );
+            for ( a, b ) in q.as_matrix4x4().as_array().iter().zip( expected.as_array() ) {
+                assert!( ( a - b ).abs() < 1e-5, "{} != {}", a, b );
+            }
+
+            let x = axis_angle( Vector3::new( 1.0, 0.0, 0.0 ), euler[0] );
+            let y = axis_angle( Vector3::new( 0.0, 1.0, 0.0 ), euler[1] );
+            let z = axis_angle( Vector3::new( 0.0, 0.0, 1.0 ), euler[2] );
+            assert_same_rotation( &q, &( x * y * z ) );
+
+            let round_trip = q.as_euler_angles();
+            assert!( ( round_trip - euler ).magnitude() < 1e-5, "{}", round_trip );
+        }
+    }
+
+    #[test]
+    fn from_matrix3x3_half_turn_x() {
+        let m = Matrix3x3::from_array([
+            1.0,  0.0,  0.0,
+            0.0, -1.0,  0.0,
+            0.0,  0.0, -1.0,
+        ]);
+        assert_same_rotation( &Quaternion::from_matrix3x3( &m ), &Quaternion::new( 0.0, 1.0, 0.0, 0.0 ) );
+    }
+
+    #[test]
+    fn from_matrix3x3_half_turn_y() {
+        let m = Matrix3x3::from_array([
+            -1.0, 0.0,  0.0,
+             0.0, 1.0,  0.0,
+             0.0, 0.0, -1.0,
+        ]);
+        assert_same_rotation( &Quaternion::from_matrix3x3( &m ), &Quaternion::new( 0.0, 0.0, 1.0, 0.0 ) );
+    }
+
+    #[test]
+    fn from_matrix3x3_half_turn_z() {
+        let m = Matrix3x3::from_array([
+            -1.0,  0.0, 0.0,
+             0.0, -1.0, 0.0,
+             0.0,  0.0, 1.0,
+        ]);
+        assert_same_rotation( &Quaternion::from_matrix3x3( &m ), &Quaternion::new( 0.0, 0.0, 0.0, 1.0 ) );
+    }
+
+    #[test]
+    fn from_matrix3x3_near_identity() {
+        let q = axis_angle( Vector3::new( 1.0, 2.0, 3.0 ), 1e-3 );
+        let m = Matrix3x3::from_matrix4x4( &q.as_matrix4x4() );
+        assert_same_rotation( &Quaternion::from_matrix3x3( &m ), &q );
+    }
+
+    #[test]
+    fn from_matrix4x4_round_trip() {
+        let axes = [
+            Vector3::new( 1.0, 0.0, 0.0 ),
+            Vector3::new( 0.0, 1.0, 0.0 ),
+            Vector3::new( 0.0, 0.0, 1.0 ),
+            Vector3::new( 1.0, -2.0, 0.5 ),
+        ];
+        for axis in axes {
+            for angle in [ 0.3, 1.5, 3.0, core::f32::consts::PI ] {
+                let q = axis_angle( axis, angle );
+                assert_same_rotation( &Quaternion::from_matrix4x4( &q.as_matrix4x4() ), &q );
+            }
+        }
+    }
+
+}
@@ -0,0 +1,269 @@
+use core::ops::Range;
+use core::fmt::Display;
+
+use alloc::{
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    math,
+    PI,
+    types::{
+        Vector2, Vector3,
+        color::RGB,
+    },
+};
+
+const PCG32_MULTIPLIER:u64 = 6364136223846793005;
+
+/// Seedable *PCG32* ( XSH-RR ) random number generator
+/// 
+/// Produces the same sequence for the same `seed` and `stream` on every platform
+/// 
+/// **Not** suitable for cryptography!
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Pcg32 {
+    state:u64,
+    increment:u64,
+}
+
+impl Pcg32 {
+
+    /// Create new `Pcg32` from `seed` and `stream`
+    /// 
+    /// Generators with different `stream` values produce independent sequences
+    pub fn new( seed:u64, stream:u64 ) -> Self {
+        let mut result = Self {
+            state:0,
+            increment:( stream << 1 ) | 1,
+        };
+        result.step();
+        result.state = result.state.wrapping_add( seed );
+        result.step();
+        result
+    }
+
+    /// Create new `Pcg32` from `seed` using default stream
+    pub fn from_seed( seed:u64 ) -> Self {
+        Self::new( seed, 0xda3e39cb94b95bdb )
+    }
+
+    fn step(&mut self) {
+        self.state = self.state
+            .wrapping_mul( PCG32_MULTIPLIER )
+            .wrapping_add( self.increment );
+    }
+
+    /// Returns: next random `u32`
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+
+        let xorshifted = ( ( ( old >> 18 ) ^ old ) >> 27 ) as u32;
+        let rotation   = ( old >> 59 ) as u32;
+        xorshifted.rotate_right( rotation )
+    }
+
+    /// Returns: next random `f32` between **0.0** ( *inclusive* ) and **1.0** ( *exclusive* )
+    pub fn next_f32(&mut self) -> f32 {
+        // 24 bits of mantissa precision
+        ( self.next_u32() >> 8 ) as f32 * ( 1.0 / 16_777_216.0 )
+    }
+
+    /// Returns: next random `bool`
+    pub fn next_bool(&mut self) -> bool {
+        ( self.next_u32() >> 31 ) == 1
+    }
+
+    /// Returns: next random `f32` within `range`
+    pub fn range( &mut self, range:Range<f32> ) -> f32 {
+        range.start + ( ( range.end - range.start ) * self.next_f32() )
+    }
+
+    /// Returns: next random `u32` within `range` without modulo bias
+    /// 
+    /// Returns: `range.start` if `range` is empty
+    pub fn range_u32( &mut self, range:Range<u32> ) -> u32 {
+        if range.end <= range.start {
+            return range.start;
+        }
+        let bound = range.end - range.start;
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let r = self.next_u32();
+            if r >= threshold {
+                return range.start + ( r % bound );
+            }
+        }
+    }
+
+    /// Returns: next random `Vector2` with `magnitude` of **1.0**
+    pub fn next_unit_vector2(&mut self) -> Vector2 {
+        let ( sin, cos ) = math::sin_cos( self.next_f32() * 2.0 * PI );
+        Vector2::new( cos, sin )
+    }
+
+    /// Returns: next random `Vector3` with `magnitude` of **1.0**
+    /// 
+    /// Directions are uniformly distributed over the sphere
+    pub fn next_unit_vector3(&mut self) -> Vector3 {
+        let z = self.range( -1.0..1.0 );
+        let ( sin, cos ) = math::sin_cos( self.next_f32() * 2.0 * PI );
+        let r = math::sqrt( ( 1.0 - ( z * z ) ).max( 0.0 ) );
+        Vector3::new( r * cos, r * sin, z )
+    }
+
+    /// Returns: next random `Vector3` inside sphere with radius of **1.0**
+    pub fn next_vector3_in_unit_sphere(&mut self) -> Vector3 {
+        let radius = math::cbrt( self.next_f32() );
+        self.next_unit_vector3() * radius
+    }
+
+    /// Returns: next random opaque `RGB`
+    pub fn next_rgb(&mut self) -> RGB {
+        let bits = self.next_u32();
+        RGB::new_rgb( bits as u8, ( bits >> 8 ) as u8, ( bits >> 16 ) as u8 )
+    }
+
+}
+
+/// Table for repeatedly sampling indices with given weights in constant time
+/// 
+/// Uses *Vose's* alias method
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct AliasTable {
+    probability:Vec<f32>,
+    alias:Vec<usize>,
+}
+
+impl AliasTable {
+
+    /// Create new `AliasTable` from `weights`
+    /// 
+    /// Returns: `AliasTable` if `weights` is not empty and its sum is finite and larger than **0.0**
+    /// 
+    /// Returns: `String` error if `weights` is empty, contains negative or non-finite values,
+    /// sums to **0.0** or the sum overflows
+    pub fn new( weights:&[f32] ) -> Result<Self, String> {
+        if weights.is_empty() {
+            return Err( String::from("ALIAS TABLE ERROR: Weights are empty!") );
+        }
+
+        let mut total = 0.0;
+        for weight in weights.iter() {
+            if *weight < 0.0 || !weight.is_finite() {
+                return Err( String::from("ALIAS TABLE ERROR: Weights must be finite and not negative!") );
+            }
+            total += weight;
+        }
+        if !total.is_finite() {
+            return Err( String::from("ALIAS TABLE ERROR: Weights sum overflows!") );
+        }
+        if total <= 0.0 {
+            return Err( String::from("ALIAS TABLE ERROR: Weights must sum to more than 0.0!") );
+        }
+
+        let count = weights.len();
+        let mut scaled:Vec<f32> = weights.iter()
+            .map( |weight| ( weight * count as f32 ) / total )
+            .collect();
+
+        let mut probability = vec![ 0.0; count ];
+        let mut alias = vec![ 0; count ];
+
+        let mut small:Vec<usize> = Vec::with_capacity( count );
+        let mut large:Vec<usize> = Vec::with_capacity( count );
+        for ( i, p ) in scaled.iter().enumerate() {
+            if *p < 1.0 {
+                small.push( i );
+            } else {
+                large.push( i );
+            }
+        }
+
+        while !small.is_empty() && !large.is_empty() {
+            let less = small.pop().unwrap();
+            let more = large.pop().unwrap();
+
+            probability[less] = scaled[less];
+            alias[less] = more;
+
+            scaled[more] = ( scaled[more] + scaled[less] ) - 1.0;
+            if scaled[more] < 1.0 {
+                small.push( more );
+            } else {
+                large.push( more );
+            }
+        }
+
+        // remaining entries are 1.0 within rounding error
+        for i in large.into_iter().chain( small ) {
+            probability[i] = 1.0;
+            alias[i] = i;
+        }
+
+        Ok( Self { probability, alias } )
+    }
+
+    /// Returns: number of entries in table
+    pub fn len(&self) -> usize {
+        self.probability.len()
+    }
+
+    /// Returns: `true` if table has no entries
+    pub fn is_empty(&self) -> bool {
+        self.probability.is_empty()
+    }
+
+    /// Returns: random index with probability proportional to its weight
+    pub fn sample( &self, rng:&mut Pcg32 ) -> usize {
+        let column = rng.range_u32( 0..self.len() as u32 ) as usize;
+        if rng.next_f32() < self.probability[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+
+}
+
+impl Display for AliasTable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "AliasTable: {} entries", self.len() )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alias_table_rejects_invalid_weights() {
+        assert!( AliasTable::new( &[] ).is_err() );
+        assert!( AliasTable::new( &[ 0.0, 0.0 ] ).is_err() );
+        for weight in [ -1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY ] {
+            assert!( AliasTable::new( &[ 1.0, weight ] ).is_err(), "{}", weight );
+        }
+        assert!( AliasTable::new( &[ f32::MAX, f32::MAX ] ).is_err() );
+    }
+
+    #[test]
+    fn alias_table_sample() {
+        let table = AliasTable::new( &[ 0.0, 1.0, 3.0 ] ).unwrap();
+        let mut rng = Pcg32::new( 7, 1 );
+        let mut counts = [ 0_u32; 3 ];
+        for _ in 0..4000 {
+            counts[table.sample( &mut rng )] += 1;
+        }
+        assert_eq!( counts[0], 0 );
+        assert!( counts[1] > 800 && counts[1] < 1200, "{:?}", counts );
+        assert!( counts[2] > 2800 && counts[2] < 3200, "{:?}", counts );
+    }
+
+}
@@ -0,0 +1,207 @@
+use core::fmt::Display;
+
+use crate::math;
+
+use super::{
+    Vector2, Vector3, Vector4,
+    Matrix4x4, Plane, Aabb,
+};
+
+/// Half-line starting at `origin` going along `direction`
+/// 
+/// Intersection distances are measured in multiples of `direction`,
+/// they are world distances when `direction` is normalized
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ray {
+    origin:Vector3,
+    direction:Vector3,
+}
+
+impl Ray {
+
+    /// Create new `Ray` from `origin` and `direction`
+    pub fn new( origin:Vector3, direction:Vector3 ) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Create new `Ray` going from `from` through `to`
+    /// 
+    /// `direction` is normalized
+    pub fn from_points( from:&Vector3, to:&Vector3 ) -> Self {
+        Self::new( *from, ( *to - *from ).normal() )
+    }
+
+    /// Create new `Ray` through a point on screen, used for *mouse picking*
+    /// 
+    /// `ndc`: point in *normalized device coordinates* ( **-1.0**-**1.0** )
+    /// 
+    /// `inverse_view_projection`: inverse of projection * view matrix
+    /// 
+    /// Expects *OpenGL* clip space ( *z* between **-1.0** and **1.0** ),
+    /// use `from_ndc_depth_range` for *Direct3D*, *Vulkan* or *reversed-z* projections
+    /// 
+    /// Ray starts on the near plane, `direction` is normalized
+    pub fn from_ndc( ndc:&Vector2, inverse_view_projection:&Matrix4x4 ) -> Self {
+        Self::from_ndc_depth_range( ndc, inverse_view_projection, -1.0, 1.0 )
+    }
+
+    /// Create new `Ray` through a point on screen, used for *mouse picking*
+    /// 
+    /// `ndc`: point in *normalized device coordinates* ( **-1.0**-**1.0** )
+    /// 
+    /// `inverse_view_projection`: inverse of projection * view matrix
+    /// 
+    /// `near_depth`, `far_depth`: normalized device *z* of near and far planes,
+    /// **-1.0**, **1.0** for `new_perspective`,
+    /// **0.0**, **1.0** for *Direct3D* and *Vulkan*,
+    /// **1.0**, **0.0** for `new_perspective_reversed_z`
+    /// 
+    /// Ray starts on the near plane, `direction` is normalized
+    pub fn from_ndc_depth_range(
+        ndc:&Vector2, inverse_view_projection:&Matrix4x4,
+        near_depth:f32, far_depth:f32
+    ) -> Self {
+        let unproject = | depth:f32 | {
+            let p = inverse_view_projection.mul_vector4( &Vector4::new( ndc[0], ndc[1], depth, 1.0 ) );
+            Vector3::new( p[0] / p[3], p[1] / p[3], p[2] / p[3] )
+        };
+        Self::from_points( &unproject( near_depth ), &unproject( far_depth ) )
+    }
+
+    /// Returns: `reference` to origin
+    pub fn origin(&self) -> &Vector3 {
+        &self.origin
+    }
+
+    /// Returns: `reference` to direction
+    pub fn direction(&self) -> &Vector3 {
+        &self.direction
+    }
+
+    /// Returns: point at distance `t` along ray
+    pub fn point_at( &self, t:f32 ) -> Vector3 {
+        self.origin + ( self.direction * t )
+    }
+
+    /// Transform ray by `matrix`, `direction` is **not** normalized afterwards
+    /// 
+    /// Returns: new `Ray`
+    pub fn transformed( &self, matrix:&Matrix4x4 ) -> Self {
+        let direction = matrix.mul_vector4( &Vector4::new( self.direction[0], self.direction[1], self.direction[2], 0.0 ) );
+        Self::new(
+            matrix.mul_vector3( &self.origin ),
+            Vector3::new( direction[0], direction[1], direction[2] ),
+        )
+    }
+
+    /// Returns: distance to `plane` if ray hits it from either side
+    /// 
+    /// Returns: `None` if ray is parallel to `plane` or points away from it
+    pub fn intersect_plane( &self, plane:&Plane ) -> Option<f32> {
+        let denominator = Vector3::dot( plane.normal(), &self.direction );
+        if denominator.abs() <= f32::EPSILON {
+            return None;
+        }
+        let t = -plane.signed_distance( &self.origin ) / denominator;
+        if t >= 0.0 { Some( t ) } else { None }
+    }
+
+    /// Returns: distance to first hit with sphere at `center` with `radius`,
+    /// **0.0** if `origin` is inside sphere
+    /// 
+    /// Returns: `None` if ray misses sphere
+    pub fn intersect_sphere( &self, center:&Vector3, radius:f32 ) -> Option<f32> {
+        let offset = self.origin - *center;
+        let a = self.direction.sqr_magnitude();
+        let b = Vector3::dot( &offset, &self.direction );
+        let c = offset.sqr_magnitude() - ( radius * radius );
+
+        if c <= 0.0 {
+            return Some( 0.0 );
+        }
+        nearest_quadratic_root( a, b, c )
+    }
+
+    /// Returns: distance to first hit with `aabb`, **0.0** if `origin` is inside box
+    /// 
+    /// Returns: `None` if ray misses `aabb`
+    pub fn intersect_aabb( &self, aabb:&Aabb ) -> Option<f32> {
+        slab_intersection( self.origin.as_array(), self.direction.as_array(), aabb.min().as_array(), aabb.max().as_array() )
+    }
+
+}
+
+impl Display for Ray {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Ray: origin: {}, direction: {}", self.origin, self.direction )
+    }
+}
+
+/// Smallest non-negative `t` solving `a`t² + 2`b`t + `c` = **0.0**,
+/// `c` is positive so both roots have the same sign
+pub(crate) fn nearest_quadratic_root( a:f32, b:f32, c:f32 ) -> Option<f32> {
+    // moving away from the shape
+    if b > 0.0 || a == 0.0 {
+        return None;
+    }
+    let discriminant = ( b * b ) - ( a * c );
+    if discriminant < 0.0 {
+        return None;
+    }
+    Some( ( -b - math::sqrt( discriminant ) ) / a )
+}
+
+/// Slab test between ray and box, shared by every dimension
+pub(crate) fn slab_intersection( origin:&[f32], direction:&[f32], min:&[f32], max:&[f32] ) -> Option<f32> {
+    let mut near = 0.0_f32;
+    let mut far  = f32::INFINITY;
+    for ( ( ( o, d ), min ), max ) in origin.iter().zip( direction ).zip( min ).zip( max ) {
+        if *d == 0.0 {
+            // parallel to slab, must already be between its planes
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+        let ( t1, t2 ) = ( ( min - o ) / d, ( max - o ) / d );
+        near = near.max( t1.min( t2 ) );
+        far  = far.min( t1.max( t2 ) );
+        if near > far {
+            return None;
+        }
+    }
+    Some( near )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close( a:&Vector3, b:&Vector3 ) {
+        assert!( ( *a - *b ).magnitude() < 1e-4, "{} != {}", a, b );
+    }
+
+    #[test]
+    fn from_ndc() {
+        let inverse = Matrix4x4::new_perspective( 1.2, 1.0, 0.5, 50.0 ).inverse().unwrap();
+        let ray = Ray::from_ndc( &Vector2::new( 0.0, 0.0 ), &inverse );
+        assert_close( ray.origin(), &Vector3::new( 0.0, 0.0, -0.5 ) );
+        assert_close( ray.direction(), &Vector3::new( 0.0, 0.0, -1.0 ) );
+    }
+
+    #[test]
+    fn from_ndc_depth_range_reversed_z() {
+        let projection = Matrix4x4::new_perspective( 1.2, 1.0, 0.5, 50.0 );
+        let reversed_z = Matrix4x4::new_perspective_reversed_z( 1.2, 1.0, 0.5, 50.0 );
+        let ndc = Vector2::new( 0.5, -0.25 );
+
+        let expected = Ray::from_ndc( &ndc, &projection.inverse().unwrap() );
+        let ray = Ray::from_ndc_depth_range( &ndc, &reversed_z.inverse().unwrap(), 1.0, 0.0 );
+        assert_close( ray.origin(), expected.origin() );
+        assert_close( ray.direction(), expected.direction() );
+        assert!( ray.origin()[2] < 0.0 && ray.direction()[2] < 0.0 );
+    }
+
+}
@@ -0,0 +1,93 @@
+use core::fmt::Display;
+
+use super::{
+    Vector2, Aabb2d,
+    ray::{
+        nearest_quadratic_root,
+        slab_intersection,
+    },
+};
+
+/// 2D half-line starting at `origin` going along `direction`
+/// 
+/// Intersection distances are measured in multiples of `direction`,
+/// they are world distances when `direction` is normalized
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ray2d {
+    origin:Vector2,
+    direction:Vector2,
+}
+
+impl Ray2d {
+
+    /// Create new `Ray2d` from `origin` and `direction`
+    pub fn new( origin:Vector2, direction:Vector2 ) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Create new `Ray2d` going from `from` through `to`
+    /// 
+    /// `direction` is normalized
+    pub fn from_points( from:&Vector2, to:&Vector2 ) -> Self {
+        Self::new( *from, ( *to - *from ).normal() )
+    }
+
+    /// Returns: `reference` to origin
+    pub fn origin(&self) -> &Vector2 {
+        &self.origin
+    }
+
+    /// Returns: `reference` to direction
+    pub fn direction(&self) -> &Vector2 {
+        &self.direction
+    }
+
+    /// Returns: point at distance `t` along ray
+    pub fn point_at( &self, t:f32 ) -> Vector2 {
+        self.origin + ( self.direction * t )
+    }
+
+    /// Returns: distance to line through `point` with `normal` if ray hits it from either side
+    /// 
+    /// Returns: `None` if ray is parallel to line or points away from it
+    pub fn intersect_line( &self, point:&Vector2, normal:&Vector2 ) -> Option<f32> {
+        let denominator = Vector2::dot( normal, &self.direction );
+        if denominator.abs() <= f32::EPSILON {
+            return None;
+        }
+        let t = Vector2::dot( normal, &( *point - self.origin ) ) / denominator;
+        if t >= 0.0 { Some( t ) } else { None }
+    }
+
+    /// Returns: distance to first hit with circle at `center` with `radius`,
+    /// **0.0** if `origin` is inside circle
+    /// 
+    /// Returns: `None` if ray misses circle
+    pub fn intersect_circle( &self, center:&Vector2, radius:f32 ) -> Option<f32> {
+        let offset = self.origin - *center;
+        let a = self.direction.sqr_magnitude();
+        let b = Vector2::dot( &offset, &self.direction );
+        let c = offset.sqr_magnitude() - ( radius * radius );
+
+        if c <= 0.0 {
+            return Some( 0.0 );
+        }
+        nearest_quadratic_root( a, b, c )
+    }
+
+    /// Returns: distance to first hit with `aabb`, **0.0** if `origin` is inside rectangle
+    /// 
+    /// Returns: `None` if ray misses `aabb`
+    pub fn intersect_aabb2d( &self, aabb:&Aabb2d ) -> Option<f32> {
+        slab_intersection( self.origin.as_array(), self.direction.as_array(), aabb.min().as_array(), aabb.max().as_array() )
+    }
+
+}
+
+impl Display for Ray2d {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "Ray2d: origin: {}, direction: {}", self.origin, self.direction )
+    }
+}
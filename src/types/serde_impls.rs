@@ -0,0 +1,246 @@
+//! *serde* `Serialize` and `Deserialize` implementations
+//! 
+//! Vectors, matrices and quaternions are plain arrays ( *matrices column-major* ),
+//! `RGB` is an array of **4** bytes ( *R G B A* ), `HSV` is an array of **3** floats,
+//! `Transform` is a struct with `translation`, `rotation` and `scale`
+
+use serde::{
+    Serialize, Serializer,
+    Deserialize, Deserializer,
+    de::Error,
+};
+
+use super::{
+    Vector2, Vector3, Vector4,
+    Vector2Bool, Vector3Bool, Vector4Bool,
+    Matrix3x3, Matrix4x4,
+    Quaternion, UnitQuaternion,
+    Transform,
+    color::{
+        RGB, HSV,
+    },
+};
+
+impl Serialize for Vector2 {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector2 {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[f32;2]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Vector3 {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector3 {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[f32;3]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Vector4 {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector4 {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[f32;4]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Vector2Bool {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector2Bool {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[bool;2]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Vector3Bool {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector3Bool {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[bool;3]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Vector4Bool {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector4Bool {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[bool;4]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Matrix3x3 {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Matrix3x3 {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[f32;9]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Matrix4x4 {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Matrix4x4 {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[f32;16]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for Quaternion {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Quaternion {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[f32;4]>::deserialize( deserializer ).map( Self::from_array )
+    }
+}
+
+impl Serialize for UnitQuaternion {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        Quaternion::from( *self ).serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for UnitQuaternion {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        let quaternion = Quaternion::deserialize( deserializer )?;
+        Self::new( &quaternion ).map_err( D::Error::custom )
+    }
+}
+
+impl Serialize for RGB {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        self.as_rgba_array().serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for RGB {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        <[u8;4]>::deserialize( deserializer ).map( Self::from_array_rgba )
+    }
+}
+
+impl Serialize for HSV {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        [ *self.hue(), *self.saturation(), *self.value() ].serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for HSV {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        let [ hue, saturation, value ] = <[f32;3]>::deserialize( deserializer )?;
+        Ok( Self::new( hue, saturation, value ) )
+    }
+}
+
+/// Field layout of a serialized `Transform`
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Transform")]
+struct TransformFields {
+    translation:Vector3,
+    rotation:Quaternion,
+    scale:Vector3,
+}
+
+impl Serialize for Transform {
+    fn serialize<S>( &self, serializer:S ) -> Result<S::Ok, S::Error>
+    where S:Serializer
+    {
+        TransformFields {
+            translation:*self.translation(),
+            rotation:*self.rotation(),
+            scale:*self.scale(),
+        }.serialize( serializer )
+    }
+}
+
+impl<'de> Deserialize<'de> for Transform {
+    fn deserialize<D>( deserializer:D ) -> Result<Self, D::Error>
+    where D:Deserializer<'de>
+    {
+        let fields = TransformFields::deserialize( deserializer )?;
+        Ok( Self::new( fields.translation, fields.rotation, fields.scale ) )
+    }
+}
@@ -0,0 +1,299 @@
+use alloc::{
+    format,
+    string::String,
+    vec::Vec,
+};
+
+use super::{
+    Vector2, Vector3, Vector4,
+    Quaternion, Matrix3x3, Matrix4x4,
+    Transform, Plane, Aabb, Interval,
+    color::RGB,
+    layout::{
+        Endianness,
+        MatrixLayout,
+        f32_to_bytes,
+        f32_from_bytes,
+        f32_array_from_bytes,
+    },
+};
+
+/// Appends fmath types to a byte buffer with fixed `Endianness` and `MatrixLayout`
+/// 
+/// `f32`s take **4** bytes, `RGB` takes **4** bytes in *R, G, B, A* order
+pub struct MathWriter<'a> {
+    buffer:&'a mut Vec<u8>,
+    endianness:Endianness,
+    layout:MatrixLayout,
+}
+
+impl<'a> MathWriter<'a> {
+
+    /// Create new `MathWriter` appending to `buffer`
+    pub fn new( buffer:&'a mut Vec<u8>, endianness:Endianness, layout:MatrixLayout ) -> Self {
+        Self { buffer, endianness, layout }
+    }
+
+    /// Returns: byte order of written values
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Returns: cell order of written matrices
+    pub fn layout(&self) -> MatrixLayout {
+        self.layout
+    }
+
+    /// Returns: number of bytes in buffer
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns: `true` if buffer has no bytes
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Append `u8`
+    pub fn push_u8( &mut self, value:u8 ) {
+        self.buffer.push( value );
+    }
+
+    /// Append `u32`
+    pub fn push_u32( &mut self, value:u32 ) {
+        let bytes = match self.endianness {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big    => value.to_be_bytes(),
+        };
+        self.buffer.extend_from_slice( &bytes );
+    }
+
+    /// Append `f32`
+    pub fn push_f32( &mut self, value:f32 ) {
+        self.buffer.extend_from_slice( &f32_to_bytes( value, self.endianness ) );
+    }
+
+    /// Append every `f32` in `values`
+    pub fn push_f32_slice( &mut self, values:&[f32] ) {
+        for value in values.iter() {
+            self.push_f32( *value );
+        }
+    }
+
+    /// Append `Vector2`
+    pub fn push_vector2( &mut self, v:&Vector2 ) {
+        self.push_f32_slice( v.as_array() );
+    }
+
+    /// Append `Vector3`
+    pub fn push_vector3( &mut self, v:&Vector3 ) {
+        self.push_f32_slice( v.as_array() );
+    }
+
+    /// Append `Vector4`
+    pub fn push_vector4( &mut self, v:&Vector4 ) {
+        self.push_f32_slice( v.as_array() );
+    }
+
+    /// Append `Quaternion` in *scalar, x, y, z* order
+    pub fn push_quaternion( &mut self, q:&Quaternion ) {
+        self.push_f32_slice( q.as_array() );
+    }
+
+    /// Append `Matrix3x3`
+    /// 
+    /// `padded`: every column ( *or row* ) is followed by **4** bytes of zeroes ( *std140* )
+    pub fn push_matrix3x3( &mut self, m:&Matrix3x3, padded:bool ) {
+        self.buffer.extend_from_slice( &m.to_bytes( self.layout, self.endianness, padded ) );
+    }
+
+    /// Append `Matrix4x4`
+    pub fn push_matrix4x4( &mut self, m:&Matrix4x4 ) {
+        self.buffer.extend_from_slice( &m.to_bytes( self.layout, self.endianness ) );
+    }
+
+    /// Append `RGB` as *R, G, B, A* bytes
+    pub fn push_rgb( &mut self, color:&RGB ) {
+        self.buffer.extend_from_slice( &[ color.r(), color.g(), color.b(), color.a() ] );
+    }
+
+    /// Append `Transform` as translation, rotation, scale
+    pub fn push_transform( &mut self, transform:&Transform ) {
+        self.push_vector3( transform.translation() );
+        self.push_quaternion( transform.rotation() );
+        self.push_vector3( transform.scale() );
+    }
+
+    /// Append `Plane` as normal, distance
+    pub fn push_plane( &mut self, plane:&Plane ) {
+        self.push_vector3( plane.normal() );
+        self.push_f32( plane.distance() );
+    }
+
+    /// Append `Aabb` as min, max
+    pub fn push_aabb( &mut self, aabb:&Aabb ) {
+        self.push_vector3( aabb.min() );
+        self.push_vector3( aabb.max() );
+    }
+
+    /// Append `Interval` as min, max
+    pub fn push_interval( &mut self, interval:&Interval ) {
+        self.push_f32( interval.min() );
+        self.push_f32( interval.max() );
+    }
+
+}
+
+/// Reads fmath types from a byte slice with fixed `Endianness` and `MatrixLayout`
+/// 
+/// Reads values in the format written by `MathWriter`
+pub struct MathReader<'a> {
+    bytes:&'a [u8],
+    position:usize,
+    endianness:Endianness,
+    layout:MatrixLayout,
+}
+
+impl<'a> MathReader<'a> {
+
+    /// Create new `MathReader` reading from start of `bytes`
+    pub fn new( bytes:&'a [u8], endianness:Endianness, layout:MatrixLayout ) -> Self {
+        Self { bytes, position:0, endianness, layout }
+    }
+
+    /// Returns: byte order of read values
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Returns: cell order of read matrices
+    pub fn layout(&self) -> MatrixLayout {
+        self.layout
+    }
+
+    /// Returns: offset of next byte to read
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns: number of bytes left to read
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Returns: `true` if every byte has been read
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Read next `count` bytes
+    /// 
+    /// Returns: `String` error if fewer than `count` bytes remain
+    pub fn read_bytes( &mut self, count:usize ) -> Result<&'a [u8], String> {
+        if count > self.remaining() {
+            return Err(
+                format!(
+                    "MATH READER ERROR: Expected {} bytes at offset {}, only {} remaining!",
+                    count, self.position, self.remaining()
+                )
+            );
+        }
+        let result = &self.bytes[self.position..self.position + count];
+        self.position += count;
+        Ok( result )
+    }
+
+    /// Read `u8`
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        Ok( self.read_bytes( 1 )?[0] )
+    }
+
+    /// Read `u32`
+    pub fn read_u32(&mut self) -> Result<u32, String> {
+        let bytes = self.read_bytes( 4 )?;
+        let bytes = [ bytes[0], bytes[1], bytes[2], bytes[3] ];
+        match self.endianness {
+            Endianness::Little => Ok( u32::from_le_bytes( bytes ) ),
+            Endianness::Big    => Ok( u32::from_be_bytes( bytes ) ),
+        }
+    }
+
+    /// Read `f32`
+    pub fn read_f32(&mut self) -> Result<f32, String> {
+        let bytes = self.read_bytes( 4 )?;
+        Ok( f32_from_bytes( [ bytes[0], bytes[1], bytes[2], bytes[3] ], self.endianness ) )
+    }
+
+    /// Read `N` `f32`s
+    pub fn read_f32_array<const N:usize>(&mut self) -> Result<[f32;N], String> {
+        let bytes = self.read_bytes( N * 4 )?;
+        f32_array_from_bytes::<N>( bytes, self.endianness )
+    }
+
+    /// Read `Vector2`
+    pub fn read_vector2(&mut self) -> Result<Vector2, String> {
+        Ok( Vector2::from_array( self.read_f32_array()? ) )
+    }
+
+    /// Read `Vector3`
+    pub fn read_vector3(&mut self) -> Result<Vector3, String> {
+        Ok( Vector3::from_array( self.read_f32_array()? ) )
+    }
+
+    /// Read `Vector4`
+    pub fn read_vector4(&mut self) -> Result<Vector4, String> {
+        Ok( Vector4::from_array( self.read_f32_array()? ) )
+    }
+
+    /// Read `Quaternion` in *scalar, x, y, z* order
+    pub fn read_quaternion(&mut self) -> Result<Quaternion, String> {
+        Ok( Quaternion::from_array( self.read_f32_array()? ) )
+    }
+
+    /// Read `Matrix3x3`
+    /// 
+    /// `padded`: every column ( *or row* ) is followed by **4** bytes of padding ( *std140* )
+    pub fn read_matrix3x3( &mut self, padded:bool ) -> Result<Matrix3x3, String> {
+        let bytes = self.read_bytes( if padded { 48 } else { 36 } )?;
+        Matrix3x3::from_bytes( bytes, self.layout, self.endianness, padded )
+    }
+
+    /// Read `Matrix4x4`
+    pub fn read_matrix4x4(&mut self) -> Result<Matrix4x4, String> {
+        let bytes = self.read_bytes( 64 )?;
+        Matrix4x4::from_bytes( bytes, self.layout, self.endianness )
+    }
+
+    /// Read `RGB` from *R, G, B, A* bytes
+    pub fn read_rgb(&mut self) -> Result<RGB, String> {
+        let bytes = self.read_bytes( 4 )?;
+        Ok( RGB::new_rgba( bytes[0], bytes[1], bytes[2], bytes[3] ) )
+    }
+
+    /// Read `Transform` as translation, rotation, scale
+    pub fn read_transform(&mut self) -> Result<Transform, String> {
+        let translation = self.read_vector3()?;
+        let rotation    = self.read_quaternion()?;
+        let scale       = self.read_vector3()?;
+        Ok( Transform::new( translation, rotation, scale ) )
+    }
+
+    /// Read `Plane` as normal, distance
+    pub fn read_plane(&mut self) -> Result<Plane, String> {
+        let normal = self.read_vector3()?;
+        Ok( Plane::new( normal, self.read_f32()? ) )
+    }
+
+    /// Read `Aabb` as min, max
+    pub fn read_aabb(&mut self) -> Result<Aabb, String> {
+        let min = self.read_vector3()?;
+        Ok( Aabb::new( min, self.read_vector3()? ) )
+    }
+
+    /// Read `Interval` as min, max
+    pub fn read_interval(&mut self) -> Result<Interval, String> {
+        let min = self.read_f32()?;
+        Ok( Interval::new( min, self.read_f32()? ) )
+    }
+
+}
@@ -0,0 +1,150 @@
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::types::{
+    Vector4,
+    color::RGB,
+};
+
+/// Summed-area table ( *integral image* ) of a `width` x `height` grid
+/// 
+/// Sum or average of any rectangle is found with **4** lookups,
+/// sums are accumulated in `f64` to stay exact on large grids
+/// 
+/// Tables built from `f32` grids store values in `x`,
+/// tables built from `RGB` grids store **0.0**-**1.0** `R` `G` `B` `A` in `x` `y` `z` `w`
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct SummedAreaTable {
+    width:u32,
+    height:u32,
+    // ( width + 1 ) x ( height + 1 ), first row and column are zero
+    sums:Vec<[f64;4]>,
+}
+
+impl SummedAreaTable {
+
+    /// Build `SummedAreaTable` from `grid` stored row by row
+    /// 
+    /// Returns: `SummedAreaTable` if `grid` has `width` * `height` values
+    /// 
+    /// Returns: `String` error otherwise
+    pub fn from_f32( grid:&[f32], width:u32, height:u32 ) -> Result<Self, String> {
+        Self::build( grid, width, height, | value | [ *value as f64, 0.0, 0.0, 0.0 ] )
+    }
+
+    /// Build `SummedAreaTable` from color `grid` stored row by row
+    /// 
+    /// Returns: `SummedAreaTable` if `grid` has `width` * `height` colors
+    /// 
+    /// Returns: `String` error otherwise
+    pub fn from_rgb( grid:&[RGB], width:u32, height:u32 ) -> Result<Self, String> {
+        Self::build( grid, width, height, | color | color.as_float_rgba_array().map( | c | c as f64 ) )
+    }
+
+    fn build<T, F>( grid:&[T], width:u32, height:u32, channels:F ) -> Result<Self, String>
+    where F:Fn( &T ) -> [f64;4]
+    {
+        let ( w, h ) = ( width as usize, height as usize );
+        if grid.len() != w * h {
+            return Err(
+                format!(
+                    "SUMMED AREA TABLE ERROR: Expected {} values, got {}!",
+                    w * h, grid.len()
+                )
+            );
+        }
+
+        let stride = w + 1;
+        let mut sums = vec![ [0.0;4]; stride * ( h + 1 ) ];
+        for ( y, row ) in grid.chunks_exact( w.max( 1 ) ).take( h ).enumerate() {
+            let mut row_sum = [0.0;4];
+            for ( x, value ) in row.iter().enumerate() {
+                let value = channels( value );
+                let above = sums[ ( y * stride ) + x + 1 ];
+                let cell  = &mut sums[ ( ( y + 1 ) * stride ) + x + 1 ];
+                for ( i, channel ) in cell.iter_mut().enumerate() {
+                    row_sum[i] += value[i];
+                    *channel = row_sum[i] + above[i];
+                }
+            }
+        }
+
+        Ok( Self { width, height, sums } )
+    }
+
+    /// Returns: width of source grid
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns: height of source grid
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Sum of every value inside rectangle from `min` ( *inclusive* ) to `max` ( *exclusive* )
+    /// 
+    /// Rectangle is clamped to the grid
+    /// 
+    /// Returns: `Vector4` sum, **0.0** if rectangle is empty
+    pub fn query_rect( &self, min:[u32;2], max:[u32;2] ) -> Vector4 {
+        let sum = self.sum_rect( min, max );
+        Vector4::new( sum[0] as f32, sum[1] as f32, sum[2] as f32, sum[3] as f32 )
+    }
+
+    /// Average of every value inside rectangle from `min` ( *inclusive* ) to `max` ( *exclusive* )
+    /// 
+    /// Rectangle is clamped to the grid
+    /// 
+    /// Returns: `Vector4` average, **0.0** if rectangle is empty
+    pub fn average_rect( &self, min:[u32;2], max:[u32;2] ) -> Vector4 {
+        let ( min, max ) = self.clamp_rect( min, max );
+        let area = ( max[0] - min[0] ) as f64 * ( max[1] - min[1] ) as f64;
+        if area == 0.0 {
+            return Vector4::new_zero();
+        }
+        let sum = self.sum_rect( min, max );
+        Vector4::new(
+            ( sum[0] / area ) as f32,
+            ( sum[1] / area ) as f32,
+            ( sum[2] / area ) as f32,
+            ( sum[3] / area ) as f32,
+        )
+    }
+
+    /// Average color inside rectangle from `min` ( *inclusive* ) to `max` ( *exclusive* )
+    /// 
+    /// Only meaningful for tables built with `from_rgb`
+    /// 
+    /// Returns: `RGB` average
+    pub fn average_rect_rgb( &self, min:[u32;2], max:[u32;2] ) -> RGB {
+        self.average_rect( min, max ).as_color_rgba()
+    }
+
+    fn clamp_rect( &self, min:[u32;2], max:[u32;2] ) -> ( [u32;2], [u32;2] ) {
+        let max = [ max[0].min( self.width ), max[1].min( self.height ) ];
+        let min = [ min[0].min( max[0] ), min[1].min( max[1] ) ];
+        ( min, max )
+    }
+
+    fn sum_rect( &self, min:[u32;2], max:[u32;2] ) -> [f64;4] {
+        let ( min, max ) = self.clamp_rect( min, max );
+        let stride = self.width as usize + 1;
+        let at = | x:u32, y:u32 | self.sums[ ( y as usize * stride ) + x as usize ];
+
+        let ( a, b ) = ( at( max[0], max[1] ), at( min[0], max[1] ) );
+        let ( c, d ) = ( at( max[0], min[1] ), at( min[0], min[1] ) );
+        let mut result = [0.0;4];
+        for ( i, channel ) in result.iter_mut().enumerate() {
+            *channel = ( a[i] - b[i] ) - ( c[i] - d[i] );
+        }
+        result
+    }
+
+}
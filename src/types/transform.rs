@@ -0,0 +1,303 @@
+use core::fmt::Display;
+use core::ops::Mul;
+
+use alloc::{
+    format,
+    string::String,
+};
+
+use super::{
+    Vector3,
+    Quaternion,
+    UnitQuaternion,
+    Matrix4x4,
+};
+
+/// Translation, rotation and scale
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform {
+    translation:Vector3,
+    rotation:Quaternion,
+    scale:Vector3,
+}
+
+impl Transform {
+
+    /// Create new `Transform`
+    /// 
+    /// `rotation` should be normalized
+    pub fn new( translation:Vector3, rotation:Quaternion, scale:Vector3 ) -> Self {
+        Self { translation, rotation, scale }
+    }
+
+    /// Create new `Transform` from a rotation that is guaranteed to be normalized
+    pub fn new_unit_rotation( translation:Vector3, rotation:UnitQuaternion, scale:Vector3 ) -> Self {
+        Self::new( translation, rotation.into(), scale )
+    }
+
+    /// Create new `Transform` with no translation, no rotation and scale of **1.0**
+    pub fn new_identity() -> Self {
+        Self::new( Vector3::new_zero(), Quaternion::IDENTITY, Vector3::new_one() )
+    }
+
+    /// Create new `Transform` by decomposing affine *TRS* `matrix`
+    /// 
+    /// Negative determinant is stored as negative *x* scale
+    /// 
+    /// Returns: `String` error if `matrix` is projective, has a zero scale axis or contains shear
+    pub fn from_matrix( matrix:&Matrix4x4 ) -> Result<Self, String> {
+        const TOLERANCE:f32 = 1e-4;
+
+        if matrix[3].abs() > TOLERANCE || matrix[7].abs() > TOLERANCE ||
+            matrix[11].abs() > TOLERANCE || ( matrix[15] - 1.0 ).abs() > TOLERANCE
+        {
+            return Err( String::from("TRANSFORM ERROR: Matrix is projective, bottom row must be 0, 0, 0, 1!") );
+        }
+
+        let ( translation, rotation, scale ) = matrix.decompose_trs();
+        if scale[0].abs() <= TOLERANCE || scale[1].abs() <= TOLERANCE || scale[2].abs() <= TOLERANCE {
+            return Err( format!("TRANSFORM ERROR: Matrix has degenerate scale {}!", scale) );
+        }
+
+        let columns = [
+            Vector3::new( rotation[0], rotation[1], rotation[2]  ),
+            Vector3::new( rotation[4], rotation[5], rotation[6]  ),
+            Vector3::new( rotation[8], rotation[9], rotation[10] ),
+        ];
+        if Vector3::dot( &columns[0], &columns[1] ).abs() > TOLERANCE ||
+            Vector3::dot( &columns[0], &columns[2] ).abs() > TOLERANCE ||
+            Vector3::dot( &columns[1], &columns[2] ).abs() > TOLERANCE
+        {
+            return Err( String::from("TRANSFORM ERROR: Matrix contains shear!") );
+        }
+
+        Ok( Self::new( translation, Quaternion::from_matrix4x4( &rotation ), scale ) )
+    }
+
+    /// Returns: `reference` to translation
+    pub fn translation(&self) -> &Vector3 {
+        &self.translation
+    }
+
+    /// Returns: `reference` to rotation
+    pub fn rotation(&self) -> &Quaternion {
+        &self.rotation
+    }
+
+    /// Returns: `reference` to scale
+    pub fn scale(&self) -> &Vector3 {
+        &self.scale
+    }
+
+    /// Returns: `mutable reference` to translation
+    pub fn translation_mut(&mut self) -> &mut Vector3 {
+        &mut self.translation
+    }
+
+    /// Returns: `mutable reference` to rotation
+    pub fn rotation_mut(&mut self) -> &mut Quaternion {
+        &mut self.rotation
+    }
+
+    /// Returns: `mutable reference` to scale
+    pub fn scale_mut(&mut self) -> &mut Vector3 {
+        &mut self.scale
+    }
+
+    /// Set translation
+    pub fn set_translation( &mut self, translation:Vector3 ) {
+        self.translation = translation;
+    }
+
+    /// Set rotation
+    /// 
+    /// `rotation` should be normalized
+    pub fn set_rotation( &mut self, rotation:Quaternion ) {
+        self.rotation = rotation;
+    }
+
+    /// Set rotation from a rotation that is guaranteed to be normalized
+    pub fn set_unit_rotation( &mut self, rotation:UnitQuaternion ) {
+        self.rotation = rotation.into();
+    }
+
+    /// Set rotation from euler angles in **Radians**
+    pub fn set_rotation_euler( &mut self, euler:Vector3 ) {
+        self.rotation = Quaternion::from_euler_angles( euler );
+    }
+
+    /// Set rotation from euler angles in **Degrees**
+    pub fn set_rotation_euler_deg( &mut self, euler:Vector3 ) {
+        self.rotation = Quaternion::from_euler_angles_deg( euler );
+    }
+
+    /// Set scale
+    pub fn set_scale( &mut self, scale:Vector3 ) {
+        self.scale = scale;
+    }
+
+    /// Returns: new `Matrix4x4` that applies scale, then rotation, then translation
+    pub fn as_matrix4x4(&self) -> Matrix4x4 {
+        Matrix4x4::new_translate( self.translation.as_array() ) *
+        self.rotation.as_matrix4x4() *
+        Matrix4x4::new_scale( self.scale.as_array() )
+    }
+
+    /// Transform `point` from local space into parent space,
+    /// applies scale, then rotation, then translation
+    /// 
+    /// Returns: new `Vector3`
+    pub fn transform_point( &self, point:&Vector3 ) -> Vector3 {
+        ( self.rotation * Vector3::scale( point, &self.scale ) ) + self.translation
+    }
+
+    /// Transform `point` from parent space into local space, inverse of `transform_point`
+    /// 
+    /// Returns: new `Vector3`, components with a scale of **0.0** are **0.0**
+    pub fn inverse_transform_point( &self, point:&Vector3 ) -> Vector3 {
+        let local = self.rotation.conjugate() * ( *point - self.translation );
+        Vector3::scale( &local, &reciprocal( &self.scale ) )
+    }
+
+    /// Rotate `direction` from local space into parent space, ignores translation and scale
+    /// 
+    /// Returns: new `Vector3`
+    pub fn transform_direction( &self, direction:&Vector3 ) -> Vector3 {
+        self.rotation * *direction
+    }
+
+    /// Rotate `direction` from parent space into local space, ignores translation and scale
+    /// 
+    /// Returns: new `Vector3`
+    pub fn inverse_transform_direction( &self, direction:&Vector3 ) -> Vector3 {
+        self.rotation.conjugate() * *direction
+    }
+
+    /// Returns: new inverted `Transform`,
+    /// exact only for uniform scale since *TRS* cannot represent the inverse of non-uniform scale
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        let scale = reciprocal( &self.scale );
+        let translation = Vector3::scale( &( rotation * -self.translation ), &scale );
+        Self { translation, rotation, scale }
+    }
+
+    // =============================== STATIC =====================================
+    /// Interpolate from `a` to `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new `Transform` between `a` and `b`
+    pub fn lerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::lerp_unclamped( a, b, t.clamp( 0.0, 1.0 ) )
+    }
+
+    /// Interpolate from `a` to `b`
+    /// 
+    /// Translation and scale are interpolated *linearly*,
+    /// rotation is interpolated *spherically*
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// Returns: new `Transform` between `a` and `b`
+    pub fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self {
+            translation:Vector3::lerp_unclamped( &a.translation, &b.translation, t ),
+            rotation:Quaternion::slerp_unclamped( &a.rotation, &b.rotation, t ),
+            scale:Vector3::lerp_unclamped( &a.scale, &b.scale, t ),
+        }
+    }
+
+}
+
+/// Component-wise reciprocal, **0.0** stays **0.0**
+fn reciprocal( v:&Vector3 ) -> Vector3 {
+    v.map( | c | if c == 0.0 { 0.0 } else { 1.0 / c } )
+}
+
+impl Mul<Transform> for Transform {
+    type Output = Self;
+
+    /// Combine parent `self` with local `rhs` into a `Transform` in parent space
+    /// 
+    /// Scales are multiplied component-wise, exact unless a rotated child
+    /// has non-uniform parent scale ( *skew cannot be represented* )
+    fn mul( self, rhs:Self ) -> Self {
+        Self {
+            translation:self.transform_point( &rhs.translation ),
+            rotation:self.rotation * rhs.rotation,
+            scale:Vector3::scale( &self.scale, &rhs.scale ),
+        }
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::new_identity()
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Transform:\n    translation: {}\n    rotation: {}, {}, {}, {}\n    scale: {}",
+            self.translation,
+            self.rotation[0], self.rotation[1], self.rotation[2], self.rotation[3],
+            self.scale
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close( a:&Vector3, b:&Vector3 ) {
+        assert!( ( *a - *b ).magnitude() < 1e-4, "{} != {}", a, b );
+    }
+
+    fn sample_transform( scale:Vector3 ) -> Transform {
+        Transform::new(
+            Vector3::new( 3.0, -2.0, 5.0 ),
+            Quaternion::from_angle_axis( 1.2, &Vector3::new( 1.0, 2.0, -0.5 ) ),
+            scale
+        )
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let transform = sample_transform( Vector3::new( 2.5, 2.5, 2.5 ) );
+        let inverse = transform.inverse();
+        let point = Vector3::new( -1.0, 4.0, 0.5 );
+
+        assert_close( &inverse.transform_point( &transform.transform_point( &point ) ), &point );
+        assert_close( &transform.transform_point( &inverse.transform_point( &point ) ), &point );
+
+        let identity = transform * inverse;
+        assert_close( identity.translation(), &Vector3::new_zero() );
+        assert_close( identity.scale(), &Vector3::new_one() );
+        assert!( ( identity.rotation()[0].abs() - 1.0 ).abs() < 1e-5 );
+    }
+
+    #[test]
+    fn inverse_transform_point_non_uniform() {
+        let transform = sample_transform( Vector3::new( 1.0, 2.0, 0.5 ) );
+        let point = Vector3::new( 0.25, -3.0, 7.0 );
+        assert_close( &transform.inverse_transform_point( &transform.transform_point( &point ) ), &point );
+        assert_close(
+            &transform.inverse_transform_direction( &transform.transform_direction( &point ) ),
+            &point
+        );
+    }
+
+    #[test]
+    fn matches_matrix() {
+        let transform = sample_transform( Vector3::new( 1.0, 2.0, 0.5 ) );
+        let point = Vector3::new( 0.25, -3.0, 7.0 );
+        assert_close( &transform.as_matrix4x4().mul_vector3( &point ), &transform.transform_point( &point ) );
+    }
+
+}
@@ -0,0 +1,278 @@
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use super::{
+    Vector3,
+    Transform,
+    Matrix4x4,
+};
+
+/// Local `Transform`s with parent/child relationships
+/// 
+/// Nodes are addressed by the index returned from `add`,
+/// world transforms are composed from the root down
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`, `Default`
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TransformHierarchy {
+    locals:Vec<Transform>,
+    parents:Vec<Option<usize>>,
+}
+
+impl TransformHierarchy {
+
+    /// Create new empty `TransformHierarchy`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add node with `local` transform relative to `parent`, `None` for a root node
+    /// 
+    /// Returns: index of new node
+    /// 
+    /// Returns: `String` error if `parent` does not exist
+    pub fn add( &mut self, local:Transform, parent:Option<usize> ) -> Result<usize, String> {
+        self.check_parent( parent )?;
+        self.locals.push( local );
+        self.parents.push( parent );
+        Ok( self.locals.len() - 1 )
+    }
+
+    /// Returns: number of nodes
+    pub fn len(&self) -> usize {
+        self.locals.len()
+    }
+
+    /// Returns: `true` if hierarchy has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.locals.is_empty()
+    }
+
+    /// Returns: `reference` to local transform of `node`
+    /// 
+    /// Panics if `node` does not exist
+    pub fn local( &self, node:usize ) -> &Transform {
+        &self.locals[node]
+    }
+
+    /// Returns: `mutable reference` to local transform of `node`
+    /// 
+    /// Panics if `node` does not exist
+    pub fn local_mut( &mut self, node:usize ) -> &mut Transform {
+        &mut self.locals[node]
+    }
+
+    /// Returns: parent of `node`, `None` for root nodes
+    /// 
+    /// Panics if `node` does not exist
+    pub fn parent( &self, node:usize ) -> Option<usize> {
+        self.parents[node]
+    }
+
+    /// Attach `node` to `parent`, `None` detaches it into a root node
+    /// 
+    /// Local transform is kept, world transform changes with the new parent
+    /// 
+    /// Returns: `String` error if a node does not exist or `node` would become its own ancestor
+    pub fn set_parent( &mut self, node:usize, parent:Option<usize> ) -> Result<(), String> {
+        if node >= self.len() {
+            return Err( format!("TRANSFORM HIERARCHY ERROR: Node {} does not exist!", node) );
+        }
+        self.check_parent( parent )?;
+
+        let mut ancestor = parent;
+        while let Some( current ) = ancestor {
+            if current == node {
+                return Err(
+                    format!("TRANSFORM HIERARCHY ERROR: Node {} cannot be a descendant of itself!", node)
+                );
+            }
+            ancestor = self.parents[current];
+        }
+
+        self.parents[node] = parent;
+        Ok(())
+    }
+
+    /// Compose local transforms of `node` and all of its ancestors
+    /// 
+    /// Scales are multiplied component-wise, see `Transform` `Mul`
+    /// 
+    /// Returns: world `Transform` of `node`
+    /// 
+    /// Panics if `node` does not exist
+    pub fn world( &self, node:usize ) -> Transform {
+        let mut world = self.locals[node];
+        let mut ancestor = self.parents[node];
+        while let Some( current ) = ancestor {
+            world = self.locals[current] * world;
+            ancestor = self.parents[current];
+        }
+        world
+    }
+
+    /// Compose local matrices of `node` and all of its ancestors,
+    /// exact even with non-uniform scale
+    /// 
+    /// Returns: world `Matrix4x4` of `node`
+    /// 
+    /// Panics if `node` does not exist
+    pub fn world_matrix( &self, node:usize ) -> Matrix4x4 {
+        let mut world = self.locals[node].as_matrix4x4();
+        let mut ancestor = self.parents[node];
+        while let Some( current ) = ancestor {
+            world = self.locals[current].as_matrix4x4() * world;
+            ancestor = self.parents[current];
+        }
+        world
+    }
+
+    /// Compute world transforms of every node, each parent is composed once
+    /// 
+    /// Returns: `Vec` of world `Transform`s in node order
+    pub fn world_transforms(&self) -> Vec<Transform> {
+        let mut worlds:Vec<Option<Transform>> = vec![ None; self.len() ];
+        let mut chain = Vec::new();
+        for node in 0..self.len() {
+            // walk up to the closest ancestor with a known world transform
+            let mut current = Some( node );
+            while let Some( index ) = current {
+                if worlds[index].is_some() {
+                    break;
+                }
+                chain.push( index );
+                current = self.parents[index];
+            }
+            let mut world = current.and_then( | index | worlds[index] );
+            while let Some( index ) = chain.pop() {
+                let local = self.locals[index];
+                let composed = world.map_or( local, | parent | parent * local );
+                worlds[index] = Some( composed );
+                world = Some( composed );
+            }
+        }
+        worlds.into_iter().flatten().collect()
+    }
+
+    /// Transform `point` from local space of `node` into world space
+    /// 
+    /// Returns: new `Vector3`
+    /// 
+    /// Panics if `node` does not exist
+    pub fn transform_point( &self, node:usize, point:&Vector3 ) -> Vector3 {
+        self.world_matrix( node ).mul_vector3( point )
+    }
+
+    /// Transform world space `point` into local space of `node`
+    /// 
+    /// Returns: new `Vector3`
+    /// 
+    /// Panics if `node` does not exist
+    pub fn inverse_transform_point( &self, node:usize, point:&Vector3 ) -> Vector3 {
+        let mut chain = Vec::new();
+        let mut current = Some( node );
+        while let Some( index ) = current {
+            chain.push( index );
+            current = self.parents[index];
+        }
+        chain.iter().rev().fold( *point, | point, index | {
+            self.locals[*index].inverse_transform_point( &point )
+        } )
+    }
+
+    fn check_parent( &self, parent:Option<usize> ) -> Result<(), String> {
+        match parent {
+            Some( parent ) if parent >= self.len() => Err(
+                format!("TRANSFORM HIERARCHY ERROR: Parent {} does not exist!", parent)
+            ),
+            _ => Ok(()),
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Quaternion;
+
+    fn assert_close( a:&Vector3, b:&Vector3 ) {
+        assert!( ( *a - *b ).magnitude() < 1e-4, "{} != {}", a, b );
+    }
+
+    fn sample_hierarchy() -> ( TransformHierarchy, usize, usize, usize ) {
+        let mut hierarchy = TransformHierarchy::new();
+        let root = hierarchy.add(
+            Transform::new(
+                Vector3::new( 10.0, 0.0, 0.0 ),
+                Quaternion::from_angle_axis( crate::FRAC_PI_2, &Vector3::new( 0.0, 0.0, 1.0 ) ),
+                Vector3::new( 2.0, 2.0, 2.0 )
+            ),
+            None
+        ).unwrap();
+        let child = hierarchy.add(
+            Transform::new( Vector3::new( 1.0, 0.0, 0.0 ), Quaternion::IDENTITY, Vector3::new_one() ),
+            Some( root )
+        ).unwrap();
+        let grandchild = hierarchy.add(
+            Transform::new(
+                Vector3::new( 0.0, 3.0, 0.0 ),
+                Quaternion::from_angle_axis( 0.7, &Vector3::new( 1.0, 0.0, 0.0 ) ),
+                Vector3::new_one()
+            ),
+            Some( child )
+        ).unwrap();
+        ( hierarchy, root, child, grandchild )
+    }
+
+    #[test]
+    fn world_matrix_composes_parent_before_child() {
+        let ( hierarchy, root, child, _ ) = sample_hierarchy();
+        // child origin: scaled by 2, rotated 90 degrees around z, moved by 10 on x
+        let origin = hierarchy.transform_point( child, &Vector3::new_zero() );
+        assert_close( &origin, &Vector3::new( 10.0, 2.0, 0.0 ) );
+
+        let expected = hierarchy.local( root ).as_matrix4x4() * hierarchy.local( child ).as_matrix4x4();
+        let world = hierarchy.world_matrix( child );
+        for i in 0..16 {
+            assert!( ( world[i] - expected[i] ).abs() < 1e-5 );
+        }
+    }
+
+    #[test]
+    fn world_transforms_match_world() {
+        let ( hierarchy, .. ) = sample_hierarchy();
+        let worlds = hierarchy.world_transforms();
+        let point = Vector3::new( 0.5, -1.0, 2.0 );
+        for ( node, world ) in worlds.iter().enumerate() {
+            assert_close( &world.transform_point( &point ), &hierarchy.world( node ).transform_point( &point ) );
+            assert_close( &world.transform_point( &point ), &hierarchy.transform_point( node, &point ) );
+        }
+    }
+
+    #[test]
+    fn inverse_transform_point_round_trip() {
+        let ( hierarchy, _, _, grandchild ) = sample_hierarchy();
+        let point = Vector3::new( 0.5, -1.0, 2.0 );
+        let world = hierarchy.transform_point( grandchild, &point );
+        assert_close( &hierarchy.inverse_transform_point( grandchild, &world ), &point );
+    }
+
+    #[test]
+    fn invalid_parents() {
+        let ( mut hierarchy, root, child, grandchild ) = sample_hierarchy();
+        assert!( hierarchy.add( Transform::new_identity(), Some( 3 ) ).is_err() );
+        assert!( hierarchy.set_parent( root, Some( grandchild ) ).is_err() );
+        assert!( hierarchy.set_parent( child, Some( child ) ).is_err() );
+        assert!( hierarchy.set_parent( 7, None ).is_err() );
+
+        hierarchy.set_parent( grandchild, None ).unwrap();
+        assert_eq!( hierarchy.parent( grandchild ), None );
+        assert_eq!( hierarchy.world( grandchild ), *hierarchy.local( grandchild ) );
+    }
+
+}
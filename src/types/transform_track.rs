@@ -0,0 +1,185 @@
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+use crate::math;
+
+use super::Transform;
+
+/// What a `TransformTrack` does when sampled outside of its keys
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrackMode {
+    /// Hold first or last key
+    Clamp,
+    /// Wrap time around track duration
+    Loop,
+}
+
+/// Keyed `Transform`s sampled over time ( *animation track* )
+/// 
+/// Implements: `Clone`, `PartialEq`, `Debug`
+#[derive(Clone, PartialEq, Debug)]
+pub struct TransformTrack {
+    times:Vec<f32>,
+    keys:Vec<Transform>,
+    mode:TrackMode,
+}
+
+impl TransformTrack {
+
+    /// Create new empty `TransformTrack`
+    pub fn new( mode:TrackMode ) -> Self {
+        Self {
+            times:Vec::new(),
+            keys:Vec::new(),
+            mode,
+        }
+    }
+
+    /// Insert `key` at `time`, keeping keys ordered by time
+    /// 
+    /// Replaces key if one already exists at `time`
+    pub fn insert( &mut self, time:f32, key:Transform ) {
+        let index = self.times.partition_point( |t| *t < time );
+        if index < self.times.len() && self.times[index] == time {
+            self.keys[index] = key;
+        } else {
+            self.times.insert( index, time );
+            self.keys.insert( index, key );
+        }
+    }
+
+    /// Returns: number of keys
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns: `true` if track has no keys
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns: track mode
+    pub fn mode(&self) -> TrackMode {
+        self.mode
+    }
+
+    /// Set track mode
+    pub fn set_mode( &mut self, mode:TrackMode ) {
+        self.mode = mode;
+    }
+
+    /// Returns: time of first key
+    pub fn start_time(&self) -> f32 {
+        self.times.first().copied().unwrap_or( 0.0 )
+    }
+
+    /// Returns: time between first and last key
+    pub fn duration(&self) -> f32 {
+        match ( self.times.first(), self.times.last() ) {
+            ( Some( first ), Some( last ) ) => last - first,
+            _ => 0.0,
+        }
+    }
+
+    /// Sample track at `time`
+    /// 
+    /// Returns: `None` if track has no keys
+    pub fn sample( &self, time:f32 ) -> Option<Transform> {
+        let mut result = Transform::new_identity();
+        if self.sample_into( time, &mut result ) {
+            Some( result )
+        } else {
+            None
+        }
+    }
+
+    /// Sample track at `time` into `out`
+    /// 
+    /// Returns: `false` if track has no keys, `out` is left untouched
+    pub fn sample_into( &self, time:f32, out:&mut Transform ) -> bool {
+        if self.keys.is_empty() {
+            return false;
+        }
+
+        let duration = self.duration();
+        let time = match self.mode {
+            TrackMode::Loop if duration > 0.0 =>
+                self.start_time() + math::rem_euclid( time - self.start_time(), duration ),
+            _ => time,
+        };
+
+        // index of first key after time
+        let next = self.times.partition_point( |t| *t <= time );
+        if next == 0 {
+            *out = self.keys[0];
+        } else if next == self.keys.len() {
+            *out = self.keys[next - 1];
+        } else {
+            let previous = next - 1;
+            let t = ( time - self.times[previous] ) /
+                ( self.times[next] - self.times[previous] );
+            *out = Transform::lerp_unclamped( &self.keys[previous], &self.keys[next], t );
+        }
+
+        true
+    }
+
+}
+
+impl Display for TransformTrack {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "TransformTrack: {} keys, duration: {}, mode: {:?}",
+            self.len(), self.duration(), self.mode
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ Vector3, Quaternion };
+
+    fn key( x:f32 ) -> Transform {
+        Transform::new( Vector3::new( x, 0.0, 0.0 ), Quaternion::IDENTITY, Vector3::new_one() )
+    }
+
+    fn sample_x( track:&TransformTrack, time:f32 ) -> f32 {
+        track.sample( time ).unwrap().translation()[0]
+    }
+
+    #[test]
+    fn clamp_and_interpolate() {
+        let mut track = TransformTrack::new( TrackMode::Clamp );
+        assert!( track.sample( 0.0 ).is_none() );
+        track.insert( 2.0, key( 4.0 ) );
+        track.insert( 0.0, key( 0.0 ) );
+        track.insert( 1.0, key( 1.0 ) );
+        track.insert( 1.0, key( 2.0 ) );
+
+        assert_eq!( track.len(), 3 );
+        assert_eq!( track.duration(), 2.0 );
+        assert_eq!( sample_x( &track, -1.0 ), 0.0 );
+        assert_eq!( sample_x( &track, 0.5 ), 1.0 );
+        assert_eq!( sample_x( &track, 1.5 ), 3.0 );
+        assert_eq!( sample_x( &track, 5.0 ), 4.0 );
+    }
+
+    #[test]
+    fn loop_wraps_time() {
+        let mut track = TransformTrack::new( TrackMode::Loop );
+        track.insert( 1.0, key( 0.0 ) );
+        track.insert( 3.0, key( 2.0 ) );
+        assert_eq!( sample_x( &track, 2.0 ), 1.0 );
+        assert_eq!( sample_x( &track, 4.0 ), 1.0 );
+        assert_eq!( sample_x( &track, -2.0 ), 1.0 );
+        assert_eq!( sample_x( &track, -1.0 ), 0.0 );
+
+        let mut out = key( 9.0 );
+        assert!( track.sample_into( 6.5, &mut out ) );
+        assert_eq!( out.translation()[0], 1.5 );
+    }
+
+}
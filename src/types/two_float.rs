@@ -0,0 +1,297 @@
+use core::fmt::Display;
+use core::ops::{
+    Add, Sub, Mul, Neg, AddAssign, SubAssign,
+};
+
+/// Extended precision `f32` stored as unevaluated sum of `hi` and `lo` ( *double-f32* )
+/// 
+/// Keeps roughly twice the significant bits of `f32`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `PartialOrd`, `Debug`, `Default`
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+pub struct TwoF32 {
+    hi:f32,
+    lo:f32,
+}
+
+impl TwoF32 {
+
+    /// Create new `TwoF32` from `hi` and `lo` parts
+    /// 
+    /// Parts are renormalized so that `lo` is smaller than half an ulp of `hi`
+    pub fn new( hi:f32, lo:f32 ) -> Self {
+        let ( hi, lo ) = two_sum_f32( hi, lo );
+        Self { hi, lo }
+    }
+
+    /// Create new `TwoF32` from `f32`
+    pub fn from_f32( value:f32 ) -> Self {
+        Self { hi:value, lo:0.0 }
+    }
+
+    /// Create new `TwoF32` from `f64`, keeping about **48** significant bits
+    pub fn from_f64( value:f64 ) -> Self {
+        let hi = value as f32;
+        Self { hi, lo:( value - hi as f64 ) as f32 }
+    }
+
+    /// Returns: high part
+    pub fn hi(&self) -> f32 {
+        self.hi
+    }
+
+    /// Returns: low part
+    pub fn lo(&self) -> f32 {
+        self.lo
+    }
+
+    /// Returns: value rounded to `f32`
+    pub fn as_f32(&self) -> f32 {
+        self.hi + self.lo
+    }
+
+    /// Returns: value as `f64`
+    pub fn as_f64(&self) -> f64 {
+        self.hi as f64 + self.lo as f64
+    }
+
+    /// Returns: absolute value
+    pub fn abs(&self) -> Self {
+        if self.hi < 0.0 { -*self } else { *self }
+    }
+
+}
+
+impl Add for TwoF32 {
+    type Output = Self;
+
+    fn add( self, rhs:Self ) -> Self {
+        let ( hi, error )    = two_sum_f32( self.hi, rhs.hi );
+        let ( lo, lo_error ) = two_sum_f32( self.lo, rhs.lo );
+        let ( hi, error ) = quick_two_sum_f32( hi, error + lo );
+        let ( hi, lo )    = quick_two_sum_f32( hi, error + lo_error );
+        Self { hi, lo }
+    }
+}
+
+impl Sub for TwoF32 {
+    type Output = Self;
+
+    fn sub( self, rhs:Self ) -> Self {
+        self + ( -rhs )
+    }
+}
+
+impl Mul for TwoF32 {
+    type Output = Self;
+
+    fn mul( self, rhs:Self ) -> Self {
+        let ( product, error ) = two_product_f32( self.hi, rhs.hi );
+        let error = error + ( ( self.hi * rhs.lo ) + ( self.lo * rhs.hi ) );
+        let ( hi, lo ) = quick_two_sum_f32( product, error );
+        Self { hi, lo }
+    }
+}
+
+impl Neg for TwoF32 {
+    type Output = Self;
+
+    fn neg( self ) -> Self {
+        Self { hi:-self.hi, lo:-self.lo }
+    }
+}
+
+impl AddAssign for TwoF32 {
+    fn add_assign( &mut self, rhs:Self ) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for TwoF32 {
+    fn sub_assign( &mut self, rhs:Self ) {
+        *self = *self - rhs;
+    }
+}
+
+impl From<f32> for TwoF32 {
+    fn from( value:f32 ) -> Self {
+        Self::from_f32( value )
+    }
+}
+
+impl Display for TwoF32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "{} + {}", self.hi, self.lo )
+    }
+}
+
+/// Returns: `a + b` and its exact rounding error
+fn two_sum_f32( a:f32, b:f32 ) -> ( f32, f32 ) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let error = ( a - ( sum - b_virtual ) ) + ( b - b_virtual );
+    ( sum, error )
+}
+
+/// Returns: `a + b` and its exact rounding error, `a` must be larger in magnitude
+fn quick_two_sum_f32( a:f32, b:f32 ) -> ( f32, f32 ) {
+    let sum = a + b;
+    ( sum, b - ( sum - a ) )
+}
+
+/// Split `a` into two halves that multiply exactly ( *Dekker* )
+fn split_f32( a:f32 ) -> ( f32, f32 ) {
+    let t = 4097.0 * a;
+    let hi = t - ( t - a );
+    ( hi, a - hi )
+}
+
+/// Returns: `a * b` and its exact rounding error
+fn two_product_f32( a:f32, b:f32 ) -> ( f32, f32 ) {
+    let product = a * b;
+    let ( a_hi, a_lo ) = split_f32( a );
+    let ( b_hi, b_lo ) = split_f32( b );
+    let error = ( ( ( a_hi * b_hi ) - product ) + ( a_hi * b_lo ) + ( a_lo * b_hi ) ) + ( a_lo * b_lo );
+    ( product, error )
+}
+
+/// Extended precision `f64` stored as unevaluated sum of `hi` and `lo` ( *double-f64* )
+/// 
+/// Keeps roughly twice the significant bits of `f64`
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `PartialOrd`, `Debug`, `Default`
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+pub struct TwoF64 {
+    hi:f64,
+    lo:f64,
+}
+
+impl TwoF64 {
+
+    /// Create new `TwoF64` from `hi` and `lo` parts
+    /// 
+    /// Parts are renormalized so that `lo` is smaller than half an ulp of `hi`
+    pub fn new( hi:f64, lo:f64 ) -> Self {
+        let ( hi, lo ) = two_sum_f64( hi, lo );
+        Self { hi, lo }
+    }
+
+    /// Create new `TwoF64` from `f64`
+    pub fn from_f64( value:f64 ) -> Self {
+        Self { hi:value, lo:0.0 }
+    }
+
+    /// Returns: high part
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns: low part
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns: value rounded to `f64`
+    pub fn as_f64(&self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// Returns: absolute value
+    pub fn abs(&self) -> Self {
+        if self.hi < 0.0 { -*self } else { *self }
+    }
+
+}
+
+impl Add for TwoF64 {
+    type Output = Self;
+
+    fn add( self, rhs:Self ) -> Self {
+        let ( hi, error )    = two_sum_f64( self.hi, rhs.hi );
+        let ( lo, lo_error ) = two_sum_f64( self.lo, rhs.lo );
+        let ( hi, error ) = quick_two_sum_f64( hi, error + lo );
+        let ( hi, lo )    = quick_two_sum_f64( hi, error + lo_error );
+        Self { hi, lo }
+    }
+}
+
+impl Sub for TwoF64 {
+    type Output = Self;
+
+    fn sub( self, rhs:Self ) -> Self {
+        self + ( -rhs )
+    }
+}
+
+impl Mul for TwoF64 {
+    type Output = Self;
+
+    fn mul( self, rhs:Self ) -> Self {
+        let ( product, error ) = two_product_f64( self.hi, rhs.hi );
+        let error = error + ( ( self.hi * rhs.lo ) + ( self.lo * rhs.hi ) );
+        let ( hi, lo ) = quick_two_sum_f64( product, error );
+        Self { hi, lo }
+    }
+}
+
+impl Neg for TwoF64 {
+    type Output = Self;
+
+    fn neg( self ) -> Self {
+        Self { hi:-self.hi, lo:-self.lo }
+    }
+}
+
+impl AddAssign for TwoF64 {
+    fn add_assign( &mut self, rhs:Self ) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for TwoF64 {
+    fn sub_assign( &mut self, rhs:Self ) {
+        *self = *self - rhs;
+    }
+}
+
+impl From<f64> for TwoF64 {
+    fn from( value:f64 ) -> Self {
+        Self::from_f64( value )
+    }
+}
+
+impl Display for TwoF64 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "{} + {}", self.hi, self.lo )
+    }
+}
+
+/// Returns: `a + b` and its exact rounding error
+fn two_sum_f64( a:f64, b:f64 ) -> ( f64, f64 ) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let error = ( a - ( sum - b_virtual ) ) + ( b - b_virtual );
+    ( sum, error )
+}
+
+/// Returns: `a + b` and its exact rounding error, `a` must be larger in magnitude
+fn quick_two_sum_f64( a:f64, b:f64 ) -> ( f64, f64 ) {
+    let sum = a + b;
+    ( sum, b - ( sum - a ) )
+}
+
+/// Split `a` into two halves that multiply exactly ( *Dekker* )
+fn split_f64( a:f64 ) -> ( f64, f64 ) {
+    let t = 134_217_729.0 * a;
+    let hi = t - ( t - a );
+    ( hi, a - hi )
+}
+
+/// Returns: `a * b` and its exact rounding error
+fn two_product_f64( a:f64, b:f64 ) -> ( f64, f64 ) {
+    let product = a * b;
+    let ( a_hi, a_lo ) = split_f64( a );
+    let ( b_hi, b_lo ) = split_f64( b );
+    let error = ( ( ( a_hi * b_hi ) - product ) + ( a_hi * b_lo ) + ( a_lo * b_hi ) ) + ( a_lo * b_lo );
+    ( product, error )
+}
@@ -0,0 +1,177 @@
+use core::fmt::Display;
+use core::ops::Mul;
+
+use alloc::string::String;
+
+use super::{
+    Vector3,
+    Quaternion,
+    Matrix4x4,
+};
+
+/// `Quaternion` that is guaranteed to be normalized
+/// 
+/// Use for anything that rotates,
+/// plain `Quaternion` remains available for intermediate arithmetic
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct UnitQuaternion {
+    quaternion:Quaternion
+}
+
+impl UnitQuaternion {
+
+    /// No rotation
+    pub const IDENTITY:Self = Self { quaternion:Quaternion::IDENTITY };
+
+    /// Create new `UnitQuaternion` by normalizing `quaternion`
+    /// 
+    /// Returns: `UnitQuaternion` if `quaternion`'s norm is **not** zero
+    /// 
+    /// Returns: `String` error if `quaternion`'s norm **is** zero
+    pub fn new( quaternion:&Quaternion ) -> Result<Self, String> {
+        Ok( Self { quaternion:quaternion.normalize()? } )
+    }
+
+    /// Create new `UnitQuaternion` without normalizing
+    /// 
+    /// `quaternion` **must** already be normalized,
+    /// only checked in debug builds
+    pub fn new_unchecked( quaternion:Quaternion ) -> Self {
+        debug_assert!( ( quaternion.norm() - 1.0 ).abs() < 0.001 );
+        Self { quaternion }
+    }
+
+    /// Create new `UnitQuaternion` from given euler angles (`Vector3`)
+    /// 
+    /// Angles in **Radians**
+    pub fn from_euler_angles( euler:Vector3 ) -> Self {
+        Self { quaternion:Quaternion::from_euler_angles( euler ) }
+    }
+
+    /// Create new `UnitQuaternion` from given euler angles (`Vector3`)
+    /// 
+    /// Angles in **Degrees**
+    pub fn from_euler_angles_deg( euler:Vector3 ) -> Self {
+        Self { quaternion:Quaternion::from_euler_angles_deg( euler ) }
+    }
+
+    /// Create new `UnitQuaternion` from **rotation** `Matrix4x4`
+    /// 
+    /// Only the upper-left 3x3 cells are used,
+    /// they should form a pure rotation ( *no scale or shear* )
+    /// 
+    /// Returns: `String` error if upper-left 3x3 cells are all zero
+    pub fn from_matrix4x4( m:&Matrix4x4 ) -> Result<Self, String> {
+        Self::new( &Quaternion::from_matrix4x4( m ) )
+    }
+
+    /// Returns: `reference` to underlying `Quaternion`
+    pub fn as_quaternion(&self) -> &Quaternion {
+        &self.quaternion
+    }
+
+    /// Returns: `reference` to `array` of components
+    /// 
+    /// `0` = scalar
+    /// 
+    /// `1` `2` `3` = vector
+    pub fn as_array(&self) -> &[f32;4] {
+        self.quaternion.as_array()
+    }
+
+    /// Returns: new `UnitQuaternion` with the opposite rotation
+    pub fn inverse(&self) -> Self {
+        Self { quaternion:self.quaternion.conjugate() }
+    }
+
+    /// Returns: `vector` rotated by `UnitQuaternion`
+    pub fn rotate( &self, vector:&Vector3 ) -> Vector3 {
+        self.quaternion * *vector
+    }
+
+    /// Get rotation as **rotation** `Matrix4x4`
+    pub fn as_matrix4x4(&self) -> Matrix4x4 {
+        self.quaternion.as_matrix4x4()
+    }
+
+    /// Get rotation as euler angles (`Vector3`)
+    /// 
+    /// Angles in **Radians**
+    pub fn as_euler_angles(&self) -> Vector3 {
+        self.quaternion.as_euler_angles()
+    }
+
+    // =============================== STATIC =====================================
+    /// Spherically interpolate from `a` to `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new `UnitQuaternion` with rotation between `a` and `b`
+    pub fn slerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::slerp_unclamped( a, b, t.clamp( 0.0, 1.0 ) )
+    }
+
+    /// Spherically interpolate from `a` to `b`
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// Returns: new `UnitQuaternion` with rotation between `a` and `b`,
+    /// `a` if the result cannot be normalized
+    pub fn slerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        let result = Quaternion::slerp_unclamped( &a.quaternion, &b.quaternion, t );
+        // guard against drift when extrapolating
+        Self::new( &result ).unwrap_or( *a )
+    }
+
+}
+
+impl Default for UnitQuaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Display for UnitQuaternion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "UnitQuaternion:\n    scalar:{}, vector: {}, {}, {}",
+            self.quaternion[0], self.quaternion[1], self.quaternion[2], self.quaternion[3]
+        )
+    }
+}
+
+impl Mul<UnitQuaternion> for UnitQuaternion {
+    type Output = Self;
+    fn mul( self, rhs:Self ) -> Self {
+        Self { quaternion:self.quaternion * rhs.quaternion }
+    }
+}
+
+impl Mul<Vector3> for UnitQuaternion {
+    type Output = Vector3;
+    fn mul( self, rhs:Vector3 ) -> Vector3 {
+        self.rotate( &rhs )
+    }
+}
+
+impl TryFrom<Quaternion> for UnitQuaternion {
+    type Error = String;
+    fn try_from( quaternion:Quaternion ) -> Result<Self, String> {
+        Self::new( &quaternion )
+    }
+}
+
+impl From<UnitQuaternion> for Quaternion {
+    fn from( unit:UnitQuaternion ) -> Self {
+        unit.quaternion
+    }
+}
+
+impl From<UnitQuaternion> for Matrix4x4 {
+    fn from( unit:UnitQuaternion ) -> Self {
+        unit.as_matrix4x4()
+    }
+}
@@ -0,0 +1,246 @@
+use core::fmt::Display;
+
+use super::{
+    Vector3,
+    Matrix4x4,
+    Quaternion,
+    Transform,
+    TransformHierarchy,
+};
+
+/// Conversion between two units of measure
+///
+/// Unit sizes are described in **meters** per unit
+///
+/// Can optionally flip handedness by mirroring the `z` axis
+///
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct UnitScale {
+    source:f32,
+    target:f32,
+    flip_handedness:bool,
+}
+
+impl UnitScale {
+
+    /// Create new `UnitScale`
+    ///
+    /// `source`: size of one source unit in **meters**
+    ///
+    /// `target`: size of one target unit in **meters**
+    ///
+    /// `flip_handedness`: mirror `z` axis when converting
+    pub fn new( source:f32, target:f32, flip_handedness:bool ) -> Self {
+        Self { source, target, flip_handedness }
+    }
+
+    /// Create new `UnitScale` that converts **centimeters** to **meters**
+    pub fn new_centimeters_to_meters() -> Self {
+        Self::new( 0.01, 1.0, false )
+    }
+
+    /// Create new `UnitScale` that converts **meters** to **centimeters**
+    pub fn new_meters_to_centimeters() -> Self {
+        Self::new( 1.0, 0.01, false )
+    }
+
+    /// Returns: size of one source unit in **meters**
+    pub fn source(&self) -> f32 {
+        self.source
+    }
+
+    /// Returns: size of one target unit in **meters**
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Returns: `true` if conversion mirrors `z` axis
+    pub fn flips_handedness(&self) -> bool {
+        self.flip_handedness
+    }
+
+    /// Returns: value source lengths are multiplied by
+    pub fn factor(&self) -> f32 {
+        self.source / self.target
+    }
+
+    /// Returns: new `UnitScale` that converts back to source units
+    pub fn inverse(&self) -> Self {
+        Self::new( self.target, self.source, self.flip_handedness )
+    }
+
+    /// Rescale `position`
+    ///
+    /// Returns: new `Vector3` in target units
+    pub fn apply_vector3( &self, position:&Vector3 ) -> Vector3 {
+        let mut result = *position * self.factor();
+        if self.flip_handedness {
+            *result.z_mut() = -result[2];
+        }
+        result
+    }
+
+    /// Rescale every position in `positions` in place
+    pub fn apply_vector3_slice( &self, positions:&mut [Vector3] ) {
+        for position in positions.iter_mut() {
+            *position = self.apply_vector3(position);
+        }
+    }
+
+    /// Rescale translation of `matrix`
+    ///
+    /// Rotation and scale are left untouched unless handedness is flipped,
+    /// in which case `matrix` is mirrored on both sides along the `z` axis
+    ///
+    /// Returns: new `Matrix4x4` in target units
+    pub fn apply_matrix4x4( &self, matrix:&Matrix4x4 ) -> Matrix4x4 {
+        let mut result = *matrix;
+
+        if self.flip_handedness {
+            // mirror( z ) * matrix * mirror( z )
+            let mut i = 0;
+            while i < 16 {
+                let row    = i % 4;
+                let column = i / 4;
+                if ( row == 2 ) != ( column == 2 ) {
+                    result[i] = -result[i];
+                }
+                i += 1;
+            }
+        }
+
+        // data indeces( 12, 13, 14 ) are where translation values go
+        let factor = self.factor();
+        result[12] *= factor;
+        result[13] *= factor;
+        result[14] *= factor;
+
+        result
+    }
+
+    /// Rescale every matrix in `matrices` in place
+    ///
+    /// Local matrices of a hierarchy can be converted independently,
+    /// parent-child relationships are preserved
+    pub fn apply_matrix4x4_slice( &self, matrices:&mut [Matrix4x4] ) {
+        for matrix in matrices.iter_mut() {
+            *matrix = self.apply_matrix4x4(matrix);
+        }
+    }
+
+    /// Rescale translation of `transform`
+    ///
+    /// Rotation and scale are left untouched unless handedness is flipped,
+    /// in which case rotation is mirrored along the `z` axis like `apply_matrix4x4`
+    ///
+    /// Returns: new `Transform` in target units
+    pub fn apply_transform( &self, transform:&Transform ) -> Transform {
+        let mut rotation = *transform.rotation();
+        if self.flip_handedness {
+            // mirror( z ) * rotation * mirror( z ) negates x and y of the rotation axis
+            rotation = Quaternion::new( rotation[0], -rotation[1], -rotation[2], rotation[3] );
+        }
+        Transform::new(
+            self.apply_vector3( transform.translation() ),
+            rotation,
+            *transform.scale()
+        )
+    }
+
+    /// Rescale every transform in `transforms` in place
+    pub fn apply_transform_slice( &self, transforms:&mut [Transform] ) {
+        for transform in transforms.iter_mut() {
+            *transform = self.apply_transform(transform);
+        }
+    }
+
+    /// Rescale every local transform of `hierarchy` in place,
+    /// parent-child relationships are preserved
+    pub fn apply_transform_hierarchy( &self, hierarchy:&mut TransformHierarchy ) {
+        for node in 0..hierarchy.len() {
+            let local = hierarchy.local_mut( node );
+            *local = self.apply_transform( local );
+        }
+    }
+
+}
+
+impl Display for UnitScale {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f, "UnitScale: {}m -> {}m, flip handedness: {}",
+            self.source, self.target, self.flip_handedness
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close( a:&Vector3, b:&Vector3 ) {
+        assert!( ( *a - *b ).magnitude() < 1e-4, "{} != {}", a, b );
+    }
+
+    fn sample_transform() -> Transform {
+        Transform::new(
+            Vector3::new( 120.0, -45.0, 300.0 ),
+            Quaternion::from_euler_angles( Vector3::new( 0.3, -1.1, 2.0 ) ),
+            Vector3::new( 1.0, 2.0, 0.5 )
+        )
+    }
+
+    #[test]
+    fn transform_round_trip() {
+        let transform = sample_transform();
+        for scale in [
+            UnitScale::new_centimeters_to_meters(),
+            UnitScale::new( 0.0254, 1.0, true ),
+        ] {
+            let converted = scale.apply_transform( &transform );
+            let back = scale.inverse().apply_transform( &converted );
+            assert_close( back.translation(), transform.translation() );
+            assert_close( back.scale(), transform.scale() );
+            assert_eq!( back.rotation(), transform.rotation() );
+        }
+    }
+
+    #[test]
+    fn transform_matches_matrix() {
+        let transform = sample_transform();
+        let scale = UnitScale::new( 0.01, 1.0, true );
+        let expected = scale.apply_matrix4x4( &transform.as_matrix4x4() );
+        let converted = scale.apply_transform( &transform ).as_matrix4x4();
+        for i in 0..16 {
+            assert!( ( converted[i] - expected[i] ).abs() < 1e-4 );
+        }
+    }
+
+    #[test]
+    fn hierarchy_round_trip() {
+        let mut hierarchy = TransformHierarchy::new();
+        let root  = hierarchy.add( sample_transform(), None ).unwrap();
+        let child = hierarchy.add(
+            Transform::new( Vector3::new( 0.0, 50.0, -10.0 ), Quaternion::IDENTITY, Vector3::new_one() ),
+            Some( root )
+        ).unwrap();
+        let original = hierarchy.clone();
+        let point = Vector3::new( 1.0, 2.0, 3.0 );
+
+        let scale = UnitScale::new( 0.01, 1.0, true );
+        scale.apply_transform_hierarchy( &mut hierarchy );
+        assert_eq!( hierarchy.parent( child ), Some( root ) );
+        assert_close(
+            &hierarchy.transform_point( child, &scale.apply_vector3( &point ) ),
+            &scale.apply_vector3( &original.transform_point( child, &point ) )
+        );
+
+        scale.inverse().apply_transform_hierarchy( &mut hierarchy );
+        for node in [ root, child ] {
+            assert_close( hierarchy.local( node ).translation(), original.local( node ).translation() );
+            assert_eq!( hierarchy.local( node ).rotation(), original.local( node ).rotation() );
+        }
+    }
+
+}
@@ -1,3 +1,10 @@
+use alloc::{
+    format,
+    string::String,
+};
+
+use crate::math;
+
 mod vector2;
 pub use vector2::{
     Vector2,
@@ -10,6 +17,87 @@ mod vector4;
 pub use vector4::{
     Vector4,
 };
+mod vector3_large;
+pub use vector3_large::{
+    Vector3Large,
+};
+mod vector_n;
+pub use vector_n::{
+    VectorN,
+};
+mod vector_bool;
+pub use vector_bool::{
+    Vector2Bool, Vector3Bool, Vector4Bool,
+};
+
+/// How floats are rounded when converting to integers
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round to nearest, halfway values away from zero
+    Round,
+    /// Round towards zero
+    Trunc,
+}
+
+impl RoundingMode {
+    /// Returns: `f` rounded to a whole number
+    pub fn apply( &self, f:f32 ) -> f32 {
+        match self {
+            RoundingMode::Floor => math::floor( f ),
+            RoundingMode::Ceil  => math::ceil( f ),
+            RoundingMode::Round => math::round( f ),
+            RoundingMode::Trunc => math::trunc( f ),
+        }
+    }
+}
+
+/// Round `components` to `i32`, saturating out of range values and mapping *NaN* to **0**
+pub(crate) fn to_int_components<const N:usize>( components:&[f32;N], mode:RoundingMode ) -> [i32;N] {
+    let mut result = [0;N];
+    for ( res, component ) in result.iter_mut().zip( components.iter() ) {
+        *res = mode.apply( *component ) as i32;
+    }
+    result
+}
+
+/// Round `components` to `i32`, erroring on *NaN* and out of range values
+pub(crate) fn try_to_int_components<const N:usize>(
+    components:&[f32;N], mode:RoundingMode
+) -> Result<[i32;N], String> {
+    let mut result = [0;N];
+    for ( i, ( res, component ) ) in result.iter_mut().zip( components.iter() ).enumerate() {
+        let rounded = mode.apply( *component );
+        // i32::MAX is not representable, 2^31 is the first value out of range
+        if rounded.is_nan() || rounded < i32::MIN as f32 || rounded >= 2_147_483_648.0 {
+            return Err(
+                format!(
+                    "TO INT ERROR: Component {} ( {} ) is not representable as i32!",
+                    i, component
+                )
+            );
+        }
+        *res = rounded as i32;
+    }
+    Ok( result )
+}
+
+/// Component-wise comparison of two `arrays` with equal lengths.
+pub(crate) fn compare_components<const N:usize>(
+    v1:&[f32;N], v2:&[f32;N], compare:fn( &f32, &f32 ) -> bool
+) -> [bool;N] {
+    let mut result = [false;N];
+    let iter = v1.iter().zip(v2.iter()).zip(result.iter_mut());
+    for ( (a, b), res ) in iter {
+        *res = compare( a, b );
+    }
+    result
+}
 
 pub(crate) fn negate_components( v:&mut [f32] ) {
     for v in v.iter_mut() {
@@ -41,6 +129,7 @@ pub(crate) fn scale_components( v:&[f32], scalar:f32, result:&mut [f32] ) {
     }
 }
 
+#[allow(clippy::needless_return)]
 pub(crate) fn dot_components( v1:&[f32], v2:&[f32] ) -> f32 {
     let mut result = 0.0;
     let iter = v1.iter().zip(v2.iter());
@@ -59,9 +148,18 @@ pub(crate) fn cross_components( a:&[f32;3], b:&[f32;3] ) -> [f32;3] {
 }
 
 pub(crate) fn angle_components( v1:&[f32], v2:&[f32] ) -> f32 {
-    dot_components(v1, v2).acos().abs()
+    let magnitudes = math::sqrt( sqr_magnitude_components(v1) * sqr_magnitude_components(v2) );
+    if magnitudes == 0.0 {
+        return 0.0;
+    }
+    math::acos( ( dot_components(v1, v2) / magnitudes ).clamp( -1.0, 1.0 ) )
+}
+
+pub(crate) fn angle_normalized_components( v1:&[f32], v2:&[f32] ) -> f32 {
+    math::acos( dot_components(v1, v2).clamp( -1.0, 1.0 ) )
 }
 
+#[allow(clippy::needless_return)]
 pub(crate) fn sqr_magnitude_components( components:&[f32] ) -> f32 {
     let mut result = 0.0;
     for component in components.iter() {
@@ -71,7 +169,7 @@ pub(crate) fn sqr_magnitude_components( components:&[f32] ) -> f32 {
 }
 
 pub(crate) fn magnitude_components( components:&[f32] ) -> f32 {
-    sqr_magnitude_components(components).sqrt()
+    math::sqrt( sqr_magnitude_components(components) )
 }
 
 pub(crate) fn clamp_magnitude_components( components:&mut[f32], max:f32 ) {
@@ -100,17 +198,13 @@ pub(crate) fn componentwise_div_components( v1:&[f32], v2:&[f32], result:&mut [f
 }
 
 pub(crate) fn reflect_components( v1:&[f32], v2:&[f32], result:&mut [f32] ) {
-    // reflection = 2 * ( dot( v1, v2 ) ) * v2 - v1
+    // reflection = v1 - 2 * dot( v1, v2 ) * v2
 
     let dot = dot_components(v1, v2); // dot( v1, v2 )
 
-    let mut sub_result:[f32;2] = [0.0, 0.0];
-    sub_components(v2, v1, &mut sub_result); // v2 - v1
-
-    let mut i = 0;
-    while i < result.len() {
-        result[i] = ( 2.0 * dot ) * sub_result[i]; // 2 * dot * ( v2 - v1 )
-        i += 1;
+    let iter = v1.iter().zip(v2.iter()).zip(result.iter_mut());
+    for ( ( a, b ), res ) in iter {
+        *res = a - ( 2.0 * dot * b );
     }
 
 }
\ No newline at end of file
@@ -3,10 +3,16 @@ use core::ops::{
     Add, Sub, Mul, Div, Neg, Index, IndexMut
 };
 
+use alloc::string::String;
+
 use super::{
     Vector3,
-    Vector4
+    Vector4,
+    Vector2Bool,
+    RoundingMode,
 };
+use crate::math;
+use crate::types::Matrix3x3;
 
 /// 2-component Vector
 /// 
@@ -14,12 +20,19 @@ use super::{
 /// 
 /// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
 pub struct Vector2 {
     components:[f32;2]
 }
 
 impl Vector2 {
 
+    /// Unit vectors along each axis, in *x*, *y* order
+    pub const AXES:[Self;2] = [
+        Self { components:[1.0, 0.0] },
+        Self { components:[0.0, 1.0] },
+    ];
+
     /// Create new `Vector2` with given `x` and `y` components
     pub fn new( x:f32, y:f32 ) -> Self {
         Self {
@@ -80,6 +93,11 @@ impl Vector2 {
         Self { components }
     }
 
+    /// Create new `Vector2` from `i32` components
+    pub fn from_int( components:[i32;2] ) -> Self {
+        Self::from_array( components.map( | component | component as f32 ) )
+    }
+
     /// Returns: `reference` to vector's components `array`
     pub fn as_array(&self) -> &[f32;2] {
         &self.components
@@ -144,6 +162,149 @@ impl Vector2 {
         super::sqr_magnitude_components(&self.components)
     }
 
+    /// Apply `f` to every component
+    /// 
+    /// Returns: new `Vector2`
+    pub fn map<F>( &self, f:F ) -> Self
+    where F:FnMut( f32 ) -> f32
+    {
+        Self::from_array( self.components.map( f ) )
+    }
+
+    /// Apply `f` to every pair of components from `self` and `other`
+    /// 
+    /// Returns: new `Vector2`
+    pub fn zip_map<F>( &self, other:&Self, mut f:F ) -> Self
+    where F:FnMut( f32, f32 ) -> f32
+    {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( other.components.iter() ) {
+            *a = f( *a, *b );
+        }
+        Self::from_array( result )
+    }
+
+    /// Combine every component into a single value, starting with `init`
+    pub fn fold<T, F>( &self, init:T, f:F ) -> T
+    where F:FnMut( T, f32 ) -> T
+    {
+        self.components.iter().copied().fold( init, f )
+    }
+
+    /// Component-wise `self < other`
+    /// 
+    /// Returns: new `Vector2Bool`
+    pub fn cmp_lt( &self, other:&Self ) -> Vector2Bool {
+        Vector2Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::lt )
+        )
+    }
+
+    /// Component-wise `self <= other`
+    /// 
+    /// Returns: new `Vector2Bool`
+    pub fn cmp_le( &self, other:&Self ) -> Vector2Bool {
+        Vector2Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::le )
+        )
+    }
+
+    /// Component-wise `self > other`
+    /// 
+    /// Returns: new `Vector2Bool`
+    pub fn cmp_gt( &self, other:&Self ) -> Vector2Bool {
+        Vector2Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::gt )
+        )
+    }
+
+    /// Component-wise `self >= other`
+    /// 
+    /// Returns: new `Vector2Bool`
+    pub fn cmp_ge( &self, other:&Self ) -> Vector2Bool {
+        Vector2Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::ge )
+        )
+    }
+
+    /// Component-wise `self == other`
+    /// 
+    /// Returns: new `Vector2Bool`
+    pub fn cmp_eq( &self, other:&Self ) -> Vector2Bool {
+        Vector2Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::eq )
+        )
+    }
+
+    /// Round components to `i32` with given `mode`
+    /// 
+    /// Out of range components saturate, *NaN* becomes **0**
+    /// 
+    /// Returns: `array` of `i32`
+    pub fn to_int( &self, mode:RoundingMode ) -> [i32;2] {
+        super::to_int_components( &self.components, mode )
+    }
+
+    /// Round components to `i32` with given `mode`
+    /// 
+    /// Returns: `array` of `i32` if every component is representable
+    /// 
+    /// Returns: `String` error if any component is *NaN*, infinite or out of range
+    pub fn try_to_int( &self, mode:RoundingMode ) -> Result<[i32;2], String> {
+        super::try_to_int_components( &self.components, mode )
+    }
+
+    /// Returns: new `Vector2` rotated **90** degrees *counter-clockwise* ( *-y, x* )
+    pub fn perpendicular_ccw(&self) -> Self {
+        Self::new( -self[1], self[0] )
+    }
+
+    /// Returns: new `Vector2` rotated **90** degrees *clockwise* ( *y, -x* )
+    pub fn perpendicular_cw(&self) -> Self {
+        Self::new( self[1], -self[0] )
+    }
+
+    /// Rotate vector around origin
+    /// 
+    /// Angle is in **Radians**, positive angles rotate counter-clockwise
+    /// 
+    /// Returns: new `Vector2`
+    pub fn rotate( &self, theta_rad:f32 ) -> Self {
+        let ( sin, cos ) = math::sin_cos( theta_rad );
+        Self::new(
+            ( self[0] * cos ) - ( self[1] * sin ),
+            ( self[0] * sin ) + ( self[1] * cos ),
+        )
+    }
+
+    /// Rotate point around `pivot`
+    /// 
+    /// Angle is in **Radians**, positive angles rotate counter-clockwise
+    /// 
+    /// Returns: new `Vector2`
+    pub fn rotate_around( &self, pivot:&Self, theta_rad:f32 ) -> Self {
+        ( *self - *pivot ).rotate( theta_rad ) + *pivot
+    }
+
+    /// Clamp every component between `min` and `max`
+    /// 
+    /// Panics if `min` is greater than `max` or either is *NaN*
+    /// 
+    /// Returns: new `Vector2`
+    pub fn clamp_scalar( &self, min:f32, max:f32 ) -> Self {
+        self.map( | c | c.clamp( min, max ) )
+    }
+
+    /// Returns: new `Vector2` with every component no greater than `max`
+    pub fn min_scalar( &self, max:f32 ) -> Self {
+        self.map( | c | c.min( max ) )
+    }
+
+    /// Returns: new `Vector2` with every component no smaller than `min`
+    pub fn max_scalar( &self, min:f32 ) -> Self {
+        self.map( | c | c.max( min ) )
+    }
+
     // =============================== &mut self =====================================
     /// Clamp vector's `magnitude` to given `max`
     pub fn clamp_magnitude(&mut self, max:f32) {
@@ -151,7 +312,20 @@ impl Vector2 {
     }
 
     // =============================== STATIC =====================================
+    /// Rotate every point in `points` around `pivot` in place
+    /// 
+    /// Angle is in **Radians**, positive angles rotate counter-clockwise
+    /// 
+    /// Sine and cosine are computed once for the whole slice
+    pub fn rotate_slice( points:&mut [Self], pivot:&Self, theta_rad:f32 ) {
+        let matrix = Matrix3x3::new_rotate_around_2d( pivot, theta_rad );
+        for point in points.iter_mut() {
+            *point = matrix.mul_vector2( point );
+        }
+    }
+
     /// Normalize given vector
+    #[allow(clippy::assign_op_pattern)]
     pub fn normalize( v:&mut Self ) {
         let mag = v.magnitude();
         let mut i = 0;
@@ -184,14 +358,40 @@ impl Vector2 {
         super::dot_components( a.as_array(), b.as_array() )
     }
 
-    /// Returns: angle between `a` and `b`
+    /// Returns: **perp-dot** product of `a` and `b` ( *2D cross product* )
+    /// 
+    /// Positive if `b` is *counter-clockwise* from `a`
+    pub fn perp_dot( a:&Self, b:&Self ) -> f32 {
+        ( a[0] * b[1] ) - ( a[1] * b[0] )
+    }
+
+    /// Returns: *unsigned* angle between `a` and `b` in **radians**
+    /// 
+    /// `a` and `b` do **not** need to be normalized,
+    /// returns **0.0** if either has zero length
     pub fn angle( a:&Self, b:&Self ) -> f32 {
         super::angle_components( a.as_array(), b.as_array() )
     }
 
+    /// Returns: *unsigned* angle between normalized `a` and `b` in **radians**
+    /// 
+    /// Faster than `angle`, `a` and `b` **must** be normalized
+    pub fn angle_normalized( a:&Self, b:&Self ) -> f32 {
+        super::angle_normalized_components( a.as_array(), b.as_array() )
+    }
+
+    /// Returns: *signed* angle from `a` to `b` in **radians**,
+    /// positive when `b` is *counter-clockwise* from `a`
+    /// 
+    /// `a` and `b` do **not** need to be normalized
+    pub fn signed_angle( a:&Self, b:&Self ) -> f32 {
+        math::atan2( Self::perp_dot( a, b ), Self::dot( a, b ) )
+    }
+
     /// Component-wise scale `a` and `b`
     /// 
     /// Returns: new `Vector2` with each component being a\[-\] * b\[-\]
+    #[allow(clippy::clone_on_copy)]
     pub fn scale( a:&Self, b:&Self ) -> Self {
         let mut result = a.components.clone();
         super::componentwise_scale_components(a.as_array(), b.as_array(), &mut result);
@@ -203,6 +403,7 @@ impl Vector2 {
     /// `normal` should be a normalized vector
     /// 
     /// Returns: new `Vector2`
+    #[allow(clippy::clone_on_copy)]
     pub fn reflect( direction:&Self, normal:&Self ) -> Self {
         let mut result = direction.components.clone();
         super::reflect_components(direction.as_array(), normal.as_array(), &mut result);
@@ -213,10 +414,23 @@ impl Vector2 {
     pub fn distance( from:&Self, to:&Self ) -> f32 {
         ( *from - *to ).magnitude()
     }
+
+    /// Pick components from `a` where `mask` is `true` and from `b` where it's `false`
+    /// 
+    /// Returns: new `Vector2`
+    pub fn select( mask:&Vector2Bool, a:&Self, b:&Self ) -> Self {
+        let mut result = b.components;
+        for ( i, component ) in result.iter_mut().enumerate() {
+            if mask[i] {
+                *component = a[i];
+            }
+        }
+        Self::from_array( result )
+    }
 }
 
 impl Display for Vector2 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!( f, 
             "( {:7.3}, {:7.3} )", self.x(), self.y()
         )
@@ -237,9 +451,79 @@ impl IndexMut<usize> for Vector2 {
     }
 }
 
+impl AsRef<[f32]> for Vector2 {
+    fn as_ref(&self) -> &[f32] {
+        &self.components
+    }
+}
+
+impl AsMut<[f32]> for Vector2 {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.components
+    }
+}
+
+impl IntoIterator for Vector2 {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Vector2 {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Vector2 {
+    type Item = &'a mut f32;
+    type IntoIter = core::slice::IterMut<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter_mut()
+    }
+}
+
+impl From<[f32;2]> for Vector2 {
+    fn from(components:[f32;2]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl From<Vector2> for [f32;2] {
+    fn from(v:Vector2) -> Self {
+        v.components
+    }
+}
+
+impl From<&Vector2> for [f32;2] {
+    fn from(v:&Vector2) -> Self {
+        v.components
+    }
+}
+
+impl From<( f32, f32 )> for Vector2 {
+    fn from(t:( f32, f32 )) -> Self {
+        Self::from_array([t.0, t.1])
+    }
+}
+
+impl From<Vector2> for ( f32, f32 ) {
+    fn from(v:Vector2) -> Self {
+        ( v[0], v[1] )
+    }
+}
+
 impl Neg for Vector2 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn neg(self) -> Self {
         let mut result = self.components.clone();
         super::negate_components(&mut result);
@@ -250,6 +534,7 @@ impl Neg for Vector2 {
 impl Add for Vector2 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn add(self, rhs: Self) -> Self {
         
         let mut result = self.components.clone();
@@ -262,6 +547,7 @@ impl Add for Vector2 {
 impl Sub for Vector2 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn sub(self, rhs: Self) -> Self {
         
         let mut result = self.components.clone();
@@ -274,6 +560,7 @@ impl Sub for Vector2 {
 impl Mul<f32> for Vector2 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn mul(self, rhs:f32) -> Self {
         
         let mut result = self.components.clone();
@@ -286,6 +573,7 @@ impl Mul<f32> for Vector2 {
 impl Div<f32> for Vector2 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn div(self, rhs:f32) -> Self {
         
         let mut result = self.components.clone();
@@ -293,4 +581,51 @@ impl Div<f32> for Vector2 {
         Self::from_array(result)
 
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::{ FRAC_PI_2, PI };
+
+    fn assert_close( a:Vector2, b:Vector2 ) {
+        assert!( ( a - b ).magnitude() < 1e-6, "{} != {}", a, b );
+    }
+
+    #[test]
+    fn rotate_quarter_and_half_turn() {
+        assert_close( Vector2::new( 1.0, 0.0 ).rotate( FRAC_PI_2 ), Vector2::new( 0.0, 1.0 ) );
+        assert_close( Vector2::new( 1.0, 2.0 ).rotate( FRAC_PI_2 ), Vector2::new( -2.0, 1.0 ) );
+        assert_close( Vector2::new( 1.0, 2.0 ).rotate( PI ), Vector2::new( -1.0, -2.0 ) );
+    }
+
+    #[test]
+    fn rotate_around_quarter_and_half_turn() {
+        let pivot = Vector2::new( 1.0, 1.0 );
+        assert_close( Vector2::new( 2.0, 1.0 ).rotate_around( &pivot, FRAC_PI_2 ), Vector2::new( 1.0, 2.0 ) );
+        assert_close( Vector2::new( 2.0, 1.0 ).rotate_around( &pivot, PI ), Vector2::new( 0.0, 1.0 ) );
+    }
+
+    #[test]
+    fn rotate_around_matrix_quarter_and_half_turn() {
+        let pivot = Vector2::new( 1.0, 1.0 );
+        let quarter = Matrix3x3::new_rotate_around_2d( &pivot, FRAC_PI_2 );
+        let half    = Matrix3x3::new_rotate_around_2d( &pivot, PI );
+        assert_close( quarter.mul_vector2( &Vector2::new( 2.0, 1.0 ) ), Vector2::new( 1.0, 2.0 ) );
+        assert_close( half.mul_vector2( &Vector2::new( 2.0, 1.0 ) ), Vector2::new( 0.0, 1.0 ) );
+    }
+
+    #[test]
+    fn rotate_slice_matches_rotate_around() {
+        let pivot = Vector2::new( -1.0, 0.5 );
+        let points = [ Vector2::new( 2.0, 1.0 ), Vector2::new( 0.0, -3.0 ), pivot ];
+        for theta in [ FRAC_PI_2, PI ] {
+            let mut rotated = points;
+            Vector2::rotate_slice( &mut rotated, &pivot, theta );
+            for ( point, result ) in points.iter().zip( rotated ) {
+                assert_close( result, point.rotate_around( &pivot, theta ) );
+            }
+        }
+    }
+
+}
@@ -3,10 +3,16 @@ use core::ops::{
     Add, Sub, Mul, Div, Neg, Index, IndexMut
 };
 
+use alloc::string::String;
+
 use super::{
     Vector2,
-    Vector4
+    Vector4,
+    Vector3Bool,
+    RoundingMode,
 };
+use crate::types::color::RGB;
+use crate::math;
 
 /// 3-component Vector
 /// 
@@ -14,11 +20,20 @@ use super::{
 /// 
 /// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
 pub struct Vector3 {
     components:[f32;3]
 }
 
 impl Vector3 {
+
+    /// Unit vectors along each axis, in *x*, *y*, *z* order
+    pub const AXES:[Self;3] = [
+        Self { components:[1.0, 0.0, 0.0] },
+        Self { components:[0.0, 1.0, 0.0] },
+        Self { components:[0.0, 0.0, 1.0] },
+    ];
+
     /// Create new `Vector3` with given `x`, `y` and `z` components
     pub fn new( x:f32, y:f32, z:f32 ) -> Self {
         Self {
@@ -33,6 +48,11 @@ impl Vector3 {
         }
     }
 
+    /// Create new `Vector3` from `i32` components
+    pub fn from_int( components:[i32;3] ) -> Self {
+        Self::from_array( components.map( | component | component as f32 ) )
+    }
+
     /// Create new `Vector3` with `x`, `y` and `z` set to **1.0**
     pub fn new_one() -> Self {
         Self::new(1.0, 1.0, 1.0)
@@ -157,6 +177,138 @@ impl Vector3 {
         super::sqr_magnitude_components(&self.components)
     }
 
+    /// Apply `f` to every component
+    /// 
+    /// Returns: new `Vector3`
+    pub fn map<F>( &self, f:F ) -> Self
+    where F:FnMut( f32 ) -> f32
+    {
+        Self::from_array( self.components.map( f ) )
+    }
+
+    /// Apply `f` to every pair of components from `self` and `other`
+    /// 
+    /// Returns: new `Vector3`
+    pub fn zip_map<F>( &self, other:&Self, mut f:F ) -> Self
+    where F:FnMut( f32, f32 ) -> f32
+    {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( other.components.iter() ) {
+            *a = f( *a, *b );
+        }
+        Self::from_array( result )
+    }
+
+    /// Combine every component into a single value, starting with `init`
+    pub fn fold<T, F>( &self, init:T, f:F ) -> T
+    where F:FnMut( T, f32 ) -> T
+    {
+        self.components.iter().copied().fold( init, f )
+    }
+
+    /// Component-wise `self < other`
+    /// 
+    /// Returns: new `Vector3Bool`
+    pub fn cmp_lt( &self, other:&Self ) -> Vector3Bool {
+        Vector3Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::lt )
+        )
+    }
+
+    /// Component-wise `self <= other`
+    /// 
+    /// Returns: new `Vector3Bool`
+    pub fn cmp_le( &self, other:&Self ) -> Vector3Bool {
+        Vector3Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::le )
+        )
+    }
+
+    /// Component-wise `self > other`
+    /// 
+    /// Returns: new `Vector3Bool`
+    pub fn cmp_gt( &self, other:&Self ) -> Vector3Bool {
+        Vector3Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::gt )
+        )
+    }
+
+    /// Component-wise `self >= other`
+    /// 
+    /// Returns: new `Vector3Bool`
+    pub fn cmp_ge( &self, other:&Self ) -> Vector3Bool {
+        Vector3Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::ge )
+        )
+    }
+
+    /// Component-wise `self == other`
+    /// 
+    /// Returns: new `Vector3Bool`
+    pub fn cmp_eq( &self, other:&Self ) -> Vector3Bool {
+        Vector3Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::eq )
+        )
+    }
+
+    /// Interpret components as **0.0**-**1.0** `R` `G` `B`
+    /// 
+    /// Returns: new opaque `RGB`
+    pub fn as_color(&self) -> RGB {
+        RGB::from_float_array_rgb( self.components )
+    }
+
+    /// Round components to `i32` with given `mode`
+    /// 
+    /// Out of range components saturate, *NaN* becomes **0**
+    /// 
+    /// Returns: `array` of `i32`
+    pub fn to_int( &self, mode:RoundingMode ) -> [i32;3] {
+        super::to_int_components( &self.components, mode )
+    }
+
+    /// Round components to `i32` with given `mode`
+    /// 
+    /// Returns: `array` of `i32` if every component is representable
+    /// 
+    /// Returns: `String` error if any component is *NaN*, infinite or out of range
+    pub fn try_to_int( &self, mode:RoundingMode ) -> Result<[i32;3], String> {
+        super::try_to_int_components( &self.components, mode )
+    }
+
+    /// Returns: normalized `Vector3` perpendicular to this vector
+    /// 
+    /// Direction around the vector is arbitrary but stable,
+    /// returns **NaN** components if vector has zero length
+    pub fn any_perpendicular(&self) -> Self {
+        // cross with the axis least aligned to avoid a degenerate result
+        let helper = if self.x().abs() < 0.9 * self.magnitude() {
+            Self::new_right()
+        } else {
+            Self::new_up()
+        };
+        Self::cross( self, &helper ).normal()
+    }
+
+    /// Clamp every component between `min` and `max`
+    /// 
+    /// Panics if `min` is greater than `max` or either is *NaN*
+    /// 
+    /// Returns: new `Vector3`
+    pub fn clamp_scalar( &self, min:f32, max:f32 ) -> Self {
+        self.map( | c | c.clamp( min, max ) )
+    }
+
+    /// Returns: new `Vector3` with every component no greater than `max`
+    pub fn min_scalar( &self, max:f32 ) -> Self {
+        self.map( | c | c.min( max ) )
+    }
+
+    /// Returns: new `Vector3` with every component no smaller than `min`
+    pub fn max_scalar( &self, min:f32 ) -> Self {
+        self.map( | c | c.max( min ) )
+    }
+
     // =============================== &mut self =====================================
     /// Clamp vector's `magnitude` to given `max`
     pub fn clamp_magnitude(&mut self, max:f32) {
@@ -165,6 +317,7 @@ impl Vector3 {
 
     // =============================== STATIC =====================================
     /// Normalize given vector
+    #[allow(clippy::assign_op_pattern)]
     pub fn normalize( v:&mut Self ) {
         let mag = v.magnitude();
         let mut i = 0;
@@ -206,14 +359,39 @@ impl Vector3 {
         ])
     }
 
-    /// Returns: angle between `a` and `b`
+    /// Returns: *unsigned* angle between `a` and `b` in **radians**
+    /// 
+    /// `a` and `b` do **not** need to be normalized,
+    /// returns **0.0** if either has zero length
     pub fn angle( a:&Self, b:&Self ) -> f32 {
         super::angle_components( a.as_array(), b.as_array() )
     }
 
+    /// Returns: *unsigned* angle between normalized `a` and `b` in **radians**
+    /// 
+    /// Faster than `angle`, `a` and `b` **must** be normalized
+    pub fn angle_normalized( a:&Self, b:&Self ) -> f32 {
+        super::angle_normalized_components( a.as_array(), b.as_array() )
+    }
+
+    /// Returns: *signed* angle from `a` to `b` in **radians** around `axis`,
+    /// positive when rotation from `a` to `b` is *counter-clockwise* looking down `axis`
+    /// 
+    /// `a`, `b` and `axis` do **not** need to be normalized
+    pub fn signed_angle( a:&Self, b:&Self, axis:&Self ) -> f32 {
+        let cross = Self::cross( a, b );
+        let angle = math::atan2( cross.magnitude(), Self::dot( a, b ) );
+        if Self::dot( &cross, axis ) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+
     /// Component-wise scale `a` and `b`
     /// 
     /// Returns: new `Vector3` with each component being a\[-\] * b\[-\]
+    #[allow(clippy::clone_on_copy)]
     pub fn scale( a:&Self, b:&Self ) -> Self {
         let mut result = a.components.clone();
         super::componentwise_scale_components(a.as_array(), b.as_array(), &mut result);
@@ -225,20 +403,118 @@ impl Vector3 {
     /// `normal` should be a normalized vector
     /// 
     /// Returns: new `Vector3`
+    #[allow(clippy::clone_on_copy)]
     pub fn reflect( direction:&Self, normal:&Self ) -> Self {
         let mut result = direction.components.clone();
         super::reflect_components(direction.as_array(), normal.as_array(), &mut result);
         Self::from_array(result)
     }
 
+    /// Refract `incident` through surface with `normal`
+    /// 
+    /// `incident` and `normal` should be normalized, `normal` facing against `incident`
+    /// 
+    /// `eta`: ratio of indices of refraction, *outside* / *inside*
+    /// 
+    /// Returns: new normalized `Vector3`, `None` on *total internal reflection*
+    pub fn refract( incident:&Self, normal:&Self, eta:f32 ) -> Option<Self> {
+        let cos_incident = Self::dot( normal, incident );
+        let k = 1.0 - ( eta * eta * ( 1.0 - ( cos_incident * cos_incident ) ) );
+        if k < 0.0 {
+            return None;
+        }
+        Some( ( *incident * eta ) - ( *normal * ( ( eta * cos_incident ) + math::sqrt( k ) ) ) )
+    }
+
+    /// Spherically interpolate direction `a` towards direction `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Magnitude is interpolated *linearly*
+    /// 
+    /// Returns: new `Vector3` between `a` and `b`
+    pub fn slerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        let t = t.clamp( 0.0, 1.0 );
+        let magnitude_a = a.magnitude();
+        let magnitude_b = b.magnitude();
+        if magnitude_a <= f32::EPSILON || magnitude_b <= f32::EPSILON {
+            return Self::lerp_unclamped( a, b, t );
+        }
+        let magnitude = ( magnitude_a * ( 1.0 - t ) ) + ( magnitude_b * t );
+
+        let from = *a / magnitude_a;
+        let to   = *b / magnitude_b;
+        let cos_theta = Self::dot( &from, &to ).clamp( -1.0, 1.0 );
+        let theta = math::acos( cos_theta );
+
+        // rotate in the plane of from and to, falling back to any plane for opposite directions
+        let perpendicular = normal_or_perpendicular( &( to - ( from * cos_theta ) ), &from );
+        let ( sin, cos ) = math::sin_cos( theta * t );
+        ( ( from * cos ) + ( perpendicular * sin ) ) * magnitude
+    }
+
+    /// Rotate `direction` towards `cone_axis` so that it lies
+    /// within `max_angle_rad` of it, magnitude is kept
+    /// 
+    /// Returns: new `Vector3`
+    pub fn clamp_direction( direction:&Self, cone_axis:&Self, max_angle_rad:f32 ) -> Self {
+        let magnitude = direction.magnitude();
+        if magnitude <= f32::EPSILON {
+            return *direction;
+        }
+
+        let axis = cone_axis.normal();
+        let normalized = *direction / magnitude;
+        let cos_angle = Self::dot( &normalized, &axis ).clamp( -1.0, 1.0 );
+        let max_angle_rad = max_angle_rad.max( 0.0 );
+        if math::acos( cos_angle ) <= max_angle_rad {
+            return *direction;
+        }
+
+        let perpendicular = normal_or_perpendicular( &( normalized - ( axis * cos_angle ) ), &axis );
+        let ( sin, cos ) = math::sin_cos( max_angle_rad );
+        ( ( axis * cos ) + ( perpendicular * sin ) ) * magnitude
+    }
+
+    /// Spherically interpolate direction `a` towards direction `b`
+    /// and keep result within `max_angle_rad` of `cone_axis`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new `Vector3`
+    pub fn slerp_clamped( a:&Self, b:&Self, t:f32, cone_axis:&Self, max_angle_rad:f32 ) -> Self {
+        Self::clamp_direction( &Self::slerp( a, b, t ), cone_axis, max_angle_rad )
+    }
+
     /// Calculate distance between `from` and `to`
     pub fn distance( from:&Self, to:&Self ) -> f32 {
         ( *from - *to ).magnitude()
     }
+
+    /// Pick components from `a` where `mask` is `true` and from `b` where it's `false`
+    /// 
+    /// Returns: new `Vector3`
+    pub fn select( mask:&Vector3Bool, a:&Self, b:&Self ) -> Self {
+        let mut result = b.components;
+        for ( i, component ) in result.iter_mut().enumerate() {
+            if mask[i] {
+                *component = a[i];
+            }
+        }
+        Self::from_array( result )
+    }
+}
+
+/// Normalize `v`, or pick any unit vector perpendicular to `axis` if `v` is too short
+fn normal_or_perpendicular( v:&Vector3, axis:&Vector3 ) -> Vector3 {
+    if v.sqr_magnitude() > 1e-12 {
+        return v.normal();
+    }
+    axis.any_perpendicular()
 }
 
 impl Display for Vector3 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!( f, 
             "( {:7.3}, {:7.3}, {:7.3} )", self.x(), self.y(), self.z()
         )
@@ -268,9 +544,79 @@ impl IndexMut<usize> for Vector3 {
     }
 }
 
+impl AsRef<[f32]> for Vector3 {
+    fn as_ref(&self) -> &[f32] {
+        &self.components
+    }
+}
+
+impl AsMut<[f32]> for Vector3 {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.components
+    }
+}
+
+impl IntoIterator for Vector3 {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Vector3 {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Vector3 {
+    type Item = &'a mut f32;
+    type IntoIter = core::slice::IterMut<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter_mut()
+    }
+}
+
+impl From<[f32;3]> for Vector3 {
+    fn from(components:[f32;3]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl From<Vector3> for [f32;3] {
+    fn from(v:Vector3) -> Self {
+        v.components
+    }
+}
+
+impl From<&Vector3> for [f32;3] {
+    fn from(v:&Vector3) -> Self {
+        v.components
+    }
+}
+
+impl From<( f32, f32, f32 )> for Vector3 {
+    fn from(t:( f32, f32, f32 )) -> Self {
+        Self::from_array([t.0, t.1, t.2])
+    }
+}
+
+impl From<Vector3> for ( f32, f32, f32 ) {
+    fn from(v:Vector3) -> Self {
+        ( v[0], v[1], v[2] )
+    }
+}
+
 impl Neg for Vector3 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn neg(self) -> Self {
         let mut result = self.components.clone();
         super::negate_components(&mut result);
@@ -281,6 +627,7 @@ impl Neg for Vector3 {
 impl Add for Vector3 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn add(self, rhs: Self) -> Self {
         
         let mut result = self.components.clone();
@@ -293,6 +640,7 @@ impl Add for Vector3 {
 impl Sub for Vector3 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn sub(self, rhs: Self) -> Self {
         
         let mut result = self.components.clone();
@@ -305,6 +653,7 @@ impl Sub for Vector3 {
 impl Mul<f32> for Vector3 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn mul(self, rhs:f32) -> Self {
         
         let mut result = self.components.clone();
@@ -317,6 +666,7 @@ impl Mul<f32> for Vector3 {
 impl Div<f32> for Vector3 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn div(self, rhs:f32) -> Self {
         
         let mut result = self.components.clone();
@@ -324,4 +674,17 @@ impl Div<f32> for Vector3 {
         Self::from_array(result)
 
     }
-}
\ No newline at end of file
+}
+/// Component-wise multiply by `R` `G` `B` as **0.0**-**1.0** floats
+impl Mul<RGB> for Vector3 {
+    type Output = Self;
+    fn mul(self, rhs: RGB) -> Self {
+        Self::scale( &self, &rhs.as_vector3() )
+    }
+}
+
+impl From<RGB> for Vector3 {
+    fn from(color:RGB) -> Self {
+        color.as_vector3()
+    }
+}
@@ -0,0 +1,153 @@
+use core::fmt::Display;
+use core::ops::{
+    Add, Sub, Neg, Index, IndexMut
+};
+
+use super::Vector3;
+use crate::types::TwoF32;
+
+/// 3-component Vector with `TwoF32` extended precision components
+/// 
+/// For positions in very large worlds, rebase to a nearby origin
+/// with `relative_to` before doing `f32` math
+/// 
+/// Indexable with **[ ]**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`, `Default`
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Vector3Large {
+    components:[TwoF32;3]
+}
+
+impl Vector3Large {
+
+    /// Create new `Vector3Large`
+    pub fn new( x:TwoF32, y:TwoF32, z:TwoF32 ) -> Self {
+        Self { components:[x, y, z] }
+    }
+
+    /// Create new `Vector3Large` from `f64` components
+    pub fn from_f64( x:f64, y:f64, z:f64 ) -> Self {
+        Self::new( TwoF32::from_f64( x ), TwoF32::from_f64( y ), TwoF32::from_f64( z ) )
+    }
+
+    /// Create new `Vector3Large` from `Vector3`
+    pub fn from_vector3( v:&Vector3 ) -> Self {
+        Self::new( TwoF32::from_f32( v[0] ), TwoF32::from_f32( v[1] ), TwoF32::from_f32( v[2] ) )
+    }
+
+    /// Create new `Vector3Large` with all components set to **0.0**
+    pub fn new_zero() -> Self {
+        Self::default()
+    }
+
+    /// Returns: `reference` to components
+    pub fn as_array(&self) -> &[TwoF32;3] {
+        &self.components
+    }
+
+    /// Returns: components as `f64` array
+    pub fn as_f64_array(&self) -> [f64;3] {
+        [
+            self.components[0].as_f64(),
+            self.components[1].as_f64(),
+            self.components[2].as_f64(),
+        ]
+    }
+
+    /// Returns: new `Vector3` rounded to `f32`, loses precision far from origin
+    pub fn as_vector3(&self) -> Vector3 {
+        Vector3::new(
+            self.components[0].as_f32(),
+            self.components[1].as_f32(),
+            self.components[2].as_f32(),
+        )
+    }
+
+    /// Returns: *high* and *low* parts as `Vector3`s
+    /// 
+    /// Useful for sending positions to shaders that emulate double precision
+    pub fn split(&self) -> ( Vector3, Vector3 ) {
+        (
+            Vector3::new( self.components[0].hi(), self.components[1].hi(), self.components[2].hi() ),
+            Vector3::new( self.components[0].lo(), self.components[1].lo(), self.components[2].lo() ),
+        )
+    }
+
+    /// Position relative to `origin` such as the camera position
+    /// 
+    /// Subtraction happens in extended precision so nearby results stay accurate
+    /// 
+    /// Returns: new `Vector3`
+    pub fn relative_to( &self, origin:&Self ) -> Vector3 {
+        ( *self - *origin ).as_vector3()
+    }
+
+}
+
+impl Display for Vector3Large {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [ x, y, z ] = self.as_f64_array();
+        write!( f, "( {:.3}, {:.3}, {:.3} )", x, y, z )
+    }
+}
+
+impl Index<usize> for Vector3Large {
+    type Output = TwoF32;
+
+    fn index(&self, index:usize) -> &TwoF32 {
+        &self.components[index]
+    }
+}
+
+impl IndexMut<usize> for Vector3Large {
+    fn index_mut(&mut self, index:usize) -> &mut TwoF32 {
+        &mut self.components[index]
+    }
+}
+
+impl Add for Vector3Large {
+    type Output = Self;
+
+    fn add( self, rhs:Self ) -> Self {
+        Self::new( self[0] + rhs[0], self[1] + rhs[1], self[2] + rhs[2] )
+    }
+}
+
+impl Sub for Vector3Large {
+    type Output = Self;
+
+    fn sub( self, rhs:Self ) -> Self {
+        Self::new( self[0] - rhs[0], self[1] - rhs[1], self[2] - rhs[2] )
+    }
+}
+
+impl Add<Vector3> for Vector3Large {
+    type Output = Self;
+
+    fn add( self, rhs:Vector3 ) -> Self {
+        self + Self::from_vector3( &rhs )
+    }
+}
+
+impl Sub<Vector3> for Vector3Large {
+    type Output = Self;
+
+    fn sub( self, rhs:Vector3 ) -> Self {
+        self - Self::from_vector3( &rhs )
+    }
+}
+
+impl Neg for Vector3Large {
+    type Output = Self;
+
+    fn neg( self ) -> Self {
+        Self::new( -self[0], -self[1], -self[2] )
+    }
+}
+
+impl From<Vector3> for Vector3Large {
+    fn from( v:Vector3 ) -> Self {
+        Self::from_vector3( &v )
+    }
+}
@@ -3,9 +3,18 @@ use core::ops::{
     Add, Sub, Mul, Div, Neg, Index, IndexMut
 };
 
+use alloc::string::String;
+
 use super::{
     Vector2,
-    Vector3
+    Vector3,
+    Vector4Bool,
+    RoundingMode,
+};
+use crate::{
+    math,
+    simd,
+    types::color::RGB,
 };
 
 /// 4-component Vector
@@ -14,6 +23,7 @@ use super::{
 /// 
 /// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
 pub struct Vector4 {
     components:[f32;4]
 }
@@ -44,6 +54,11 @@ impl Vector4 {
         }
     }
 
+    /// Create new `Vector4` from `i32` components
+    pub fn from_int( components:[i32;4] ) -> Self {
+        Self::from_array( components.map( | component | component as f32 ) )
+    }
+
     /// Returns: `reference` to vector's components `array`
     pub fn as_array(&self) -> &[f32;4] {
         &self.components
@@ -120,14 +135,132 @@ impl Vector4 {
 
     /// Returns: vector's length
     pub fn magnitude(&self) -> f32 {
-        super::magnitude_components(&self.components)
+        math::sqrt( self.sqr_magnitude() )
     }
 
     /// Returns: vector's length without applying square root
     /// 
     /// Useful when you only need to compare vectors' lengths.
     pub fn sqr_magnitude(&self) -> f32 {
-        super::sqr_magnitude_components(&self.components)
+        simd::dot4( &self.components, &self.components )
+    }
+
+    /// Apply `f` to every component
+    /// 
+    /// Returns: new `Vector4`
+    pub fn map<F>( &self, f:F ) -> Self
+    where F:FnMut( f32 ) -> f32
+    {
+        Self::from_array( self.components.map( f ) )
+    }
+
+    /// Apply `f` to every pair of components from `self` and `other`
+    /// 
+    /// Returns: new `Vector4`
+    pub fn zip_map<F>( &self, other:&Self, mut f:F ) -> Self
+    where F:FnMut( f32, f32 ) -> f32
+    {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( other.components.iter() ) {
+            *a = f( *a, *b );
+        }
+        Self::from_array( result )
+    }
+
+    /// Combine every component into a single value, starting with `init`
+    pub fn fold<T, F>( &self, init:T, f:F ) -> T
+    where F:FnMut( T, f32 ) -> T
+    {
+        self.components.iter().copied().fold( init, f )
+    }
+
+    /// Component-wise `self < other`
+    /// 
+    /// Returns: new `Vector4Bool`
+    pub fn cmp_lt( &self, other:&Self ) -> Vector4Bool {
+        Vector4Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::lt )
+        )
+    }
+
+    /// Component-wise `self <= other`
+    /// 
+    /// Returns: new `Vector4Bool`
+    pub fn cmp_le( &self, other:&Self ) -> Vector4Bool {
+        Vector4Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::le )
+        )
+    }
+
+    /// Component-wise `self > other`
+    /// 
+    /// Returns: new `Vector4Bool`
+    pub fn cmp_gt( &self, other:&Self ) -> Vector4Bool {
+        Vector4Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::gt )
+        )
+    }
+
+    /// Component-wise `self >= other`
+    /// 
+    /// Returns: new `Vector4Bool`
+    pub fn cmp_ge( &self, other:&Self ) -> Vector4Bool {
+        Vector4Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::ge )
+        )
+    }
+
+    /// Component-wise `self == other`
+    /// 
+    /// Returns: new `Vector4Bool`
+    pub fn cmp_eq( &self, other:&Self ) -> Vector4Bool {
+        Vector4Bool::from_array(
+            super::compare_components( &self.components, &other.components, f32::eq )
+        )
+    }
+
+    /// Interpret components as **0.0**-**1.0** `R` `G` `B` `A`
+    /// 
+    /// Returns: new `RGB` with alpha
+    pub fn as_color_rgba(&self) -> RGB {
+        RGB::from_float_array_rgba( self.components )
+    }
+
+    /// Round components to `i32` with given `mode`
+    /// 
+    /// Out of range components saturate, *NaN* becomes **0**
+    /// 
+    /// Returns: `array` of `i32`
+    pub fn to_int( &self, mode:RoundingMode ) -> [i32;4] {
+        super::to_int_components( &self.components, mode )
+    }
+
+    /// Round components to `i32` with given `mode`
+    /// 
+    /// Returns: `array` of `i32` if every component is representable
+    /// 
+    /// Returns: `String` error if any component is *NaN*, infinite or out of range
+    pub fn try_to_int( &self, mode:RoundingMode ) -> Result<[i32;4], String> {
+        super::try_to_int_components( &self.components, mode )
+    }
+
+    /// Clamp every component between `min` and `max`
+    /// 
+    /// Panics if `min` is greater than `max` or either is *NaN*
+    /// 
+    /// Returns: new `Vector4`
+    pub fn clamp_scalar( &self, min:f32, max:f32 ) -> Self {
+        self.map( | c | c.clamp( min, max ) )
+    }
+
+    /// Returns: new `Vector4` with every component no greater than `max`
+    pub fn min_scalar( &self, max:f32 ) -> Self {
+        self.map( | c | c.min( max ) )
+    }
+
+    /// Returns: new `Vector4` with every component no smaller than `min`
+    pub fn max_scalar( &self, min:f32 ) -> Self {
+        self.map( | c | c.max( min ) )
     }
 
     // =============================== &mut self =====================================
@@ -138,6 +271,7 @@ impl Vector4 {
 
     // =============================== STATIC =====================================
     /// Normalize given vector
+    #[allow(clippy::assign_op_pattern)]
     pub fn normalize( v:&mut Self ) {
         let mag = v.magnitude();
         let mut i = 0;
@@ -167,17 +301,28 @@ impl Vector4 {
 
     /// Returns: **dot** product of `a` and `b`
     pub fn dot( a:&Self, b:&Self ) -> f32 {
-        super::dot_components( a.as_array(), b.as_array() )
+        simd::dot4( a.as_array(), b.as_array() )
     }
 
-    /// Returns: angle between `a` and `b`
+    /// Returns: *unsigned* angle between `a` and `b` in **radians**
+    /// 
+    /// `a` and `b` do **not** need to be normalized,
+    /// returns **0.0** if either has zero length
     pub fn angle( a:&Self, b:&Self ) -> f32 {
         super::angle_components( a.as_array(), b.as_array() )
     }
 
+    /// Returns: *unsigned* angle between normalized `a` and `b` in **radians**
+    /// 
+    /// Faster than `angle`, `a` and `b` **must** be normalized
+    pub fn angle_normalized( a:&Self, b:&Self ) -> f32 {
+        super::angle_normalized_components( a.as_array(), b.as_array() )
+    }
+
     /// Component-wise scale `a` and `b`
     /// 
     /// Returns: new `Vector4` with each component being a\[-\] * b\[-\]
+    #[allow(clippy::clone_on_copy)]
     pub fn scale( a:&Self, b:&Self ) -> Self {
         let mut result = a.components.clone();
         super::componentwise_scale_components(a.as_array(), b.as_array(), &mut result);
@@ -189,6 +334,7 @@ impl Vector4 {
     /// `normal` should be a normalized vector
     /// 
     /// Returns: new `Vector4`
+    #[allow(clippy::clone_on_copy)]
     pub fn reflect( direction:&Self, normal:&Self ) -> Self {
         let mut result = direction.components.clone();
         super::reflect_components(direction.as_array(), normal.as_array(), &mut result);
@@ -199,10 +345,23 @@ impl Vector4 {
     pub fn distance( from:&Self, to:&Self ) -> f32 {
         ( *from - *to ).magnitude()
     }
+
+    /// Pick components from `a` where `mask` is `true` and from `b` where it's `false`
+    /// 
+    /// Returns: new `Vector4`
+    pub fn select( mask:&Vector4Bool, a:&Self, b:&Self ) -> Self {
+        let mut result = b.components;
+        for ( i, component ) in result.iter_mut().enumerate() {
+            if mask[i] {
+                *component = a[i];
+            }
+        }
+        Self::from_array( result )
+    }
 }
 
 impl Display for Vector4 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!( f, 
             "( {:7.3}, {:7.3}, {:7.3}, {:7.3} )", self.x(), self.y(), self.z(), self.w()
         )
@@ -241,9 +400,79 @@ impl IndexMut<usize> for Vector4 {
     }
 }
 
+impl AsRef<[f32]> for Vector4 {
+    fn as_ref(&self) -> &[f32] {
+        &self.components
+    }
+}
+
+impl AsMut<[f32]> for Vector4 {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.components
+    }
+}
+
+impl IntoIterator for Vector4 {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Vector4 {
+    type Item = &'a f32;
+    type IntoIter = core::slice::Iter<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Vector4 {
+    type Item = &'a mut f32;
+    type IntoIter = core::slice::IterMut<'a, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter_mut()
+    }
+}
+
+impl From<[f32;4]> for Vector4 {
+    fn from(components:[f32;4]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl From<Vector4> for [f32;4] {
+    fn from(v:Vector4) -> Self {
+        v.components
+    }
+}
+
+impl From<&Vector4> for [f32;4] {
+    fn from(v:&Vector4) -> Self {
+        v.components
+    }
+}
+
+impl From<( f32, f32, f32, f32 )> for Vector4 {
+    fn from(t:( f32, f32, f32, f32 )) -> Self {
+        Self::from_array([t.0, t.1, t.2, t.3])
+    }
+}
+
+impl From<Vector4> for ( f32, f32, f32, f32 ) {
+    fn from(v:Vector4) -> Self {
+        ( v[0], v[1], v[2], v[3] )
+    }
+}
+
 impl Neg for Vector4 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn neg(self) -> Self {
         let mut result = self.components.clone();
         super::negate_components(&mut result);
@@ -254,6 +483,7 @@ impl Neg for Vector4 {
 impl Add for Vector4 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn add(self, rhs: Self) -> Self {
         
         let mut result = self.components.clone();
@@ -266,6 +496,7 @@ impl Add for Vector4 {
 impl Sub for Vector4 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn sub(self, rhs: Self) -> Self {
         
         let mut result = self.components.clone();
@@ -278,6 +509,7 @@ impl Sub for Vector4 {
 impl Mul<f32> for Vector4 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn mul(self, rhs:f32) -> Self {
         
         let mut result = self.components.clone();
@@ -290,6 +522,7 @@ impl Mul<f32> for Vector4 {
 impl Div<f32> for Vector4 {
     type Output = Self;
 
+    #[allow(clippy::clone_on_copy)]
     fn div(self, rhs:f32) -> Self {
         
         let mut result = self.components.clone();
@@ -297,4 +530,17 @@ impl Div<f32> for Vector4 {
         Self::from_array(result)
 
     }
-}
\ No newline at end of file
+}
+/// Component-wise multiply by `R` `G` `B` `A` as **0.0**-**1.0** floats
+impl Mul<RGB> for Vector4 {
+    type Output = Self;
+    fn mul(self, rhs: RGB) -> Self {
+        Self::scale( &self, &rhs.as_vector4() )
+    }
+}
+
+impl From<RGB> for Vector4 {
+    fn from(color:RGB) -> Self {
+        color.as_vector4()
+    }
+}
@@ -0,0 +1,319 @@
+use core::fmt::Display;
+use core::ops::{
+    Not, BitAnd, BitOr, Index, IndexMut
+};
+
+/// 2-component boolean Vector, used as a component-wise mask
+/// 
+/// Indexable with **[ ]**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Vector2Bool {
+    components:[bool;2]
+}
+
+impl Vector2Bool {
+
+    /// Create new `Vector2Bool` with given components
+    pub fn new( x:bool, y:bool ) -> Self {
+        Self {
+            components:[x,y]
+        }
+    }
+
+    /// Create new `Vector2Bool` with every component set to `value`
+    pub fn new_splat( value:bool ) -> Self {
+        Self {
+            components:[value;2]
+        }
+    }
+
+    /// Create new `Vector2Bool` from 2-component `array`
+    pub fn from_array( components:[bool;2] ) -> Self {
+        Self { components }
+    }
+
+    /// Returns: `reference` to vector's components `array`
+    pub fn as_array(&self) -> &[bool;2] {
+        &self.components
+    }
+
+    /// Returns: `true` if **any** component is `true`
+    pub fn any(&self) -> bool {
+        self.components.iter().any( |c| *c )
+    }
+
+    /// Returns: `true` if **all** components are `true`
+    pub fn all(&self) -> bool {
+        self.components.iter().all( |c| *c )
+    }
+
+    /// Returns: number of components that are `true`
+    pub fn count_true(&self) -> usize {
+        self.components.iter().filter( |c| **c ).count()
+    }
+
+}
+
+impl Display for Vector2Bool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "( {}, {} )", self.components[0], self.components[1] )
+    }
+}
+
+impl Index<usize> for Vector2Bool {
+    type Output = bool;
+
+    fn index(&self, index:usize) -> &bool {
+        &self.components[index]
+    }
+}
+
+impl IndexMut<usize> for Vector2Bool {
+    fn index_mut(&mut self, index:usize) -> &mut bool {
+        &mut self.components[index]
+    }
+}
+
+impl Not for Vector2Bool {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_array( self.components.map( |c| !c ) )
+    }
+}
+
+impl BitAnd for Vector2Bool {
+    type Output = Self;
+
+    fn bitand(self, rhs:Self) -> Self {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( rhs.components.iter() ) {
+            *a = *a && *b;
+        }
+        Self::from_array( result )
+    }
+}
+
+impl BitOr for Vector2Bool {
+    type Output = Self;
+
+    fn bitor(self, rhs:Self) -> Self {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( rhs.components.iter() ) {
+            *a = *a || *b;
+        }
+        Self::from_array( result )
+    }
+}
+
+/// 3-component boolean Vector, used as a component-wise mask
+/// 
+/// Indexable with **[ ]**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Vector3Bool {
+    components:[bool;3]
+}
+
+impl Vector3Bool {
+
+    /// Create new `Vector3Bool` with given components
+    pub fn new( x:bool, y:bool, z:bool ) -> Self {
+        Self {
+            components:[x,y,z]
+        }
+    }
+
+    /// Create new `Vector3Bool` with every component set to `value`
+    pub fn new_splat( value:bool ) -> Self {
+        Self {
+            components:[value;3]
+        }
+    }
+
+    /// Create new `Vector3Bool` from 3-component `array`
+    pub fn from_array( components:[bool;3] ) -> Self {
+        Self { components }
+    }
+
+    /// Returns: `reference` to vector's components `array`
+    pub fn as_array(&self) -> &[bool;3] {
+        &self.components
+    }
+
+    /// Returns: `true` if **any** component is `true`
+    pub fn any(&self) -> bool {
+        self.components.iter().any( |c| *c )
+    }
+
+    /// Returns: `true` if **all** components are `true`
+    pub fn all(&self) -> bool {
+        self.components.iter().all( |c| *c )
+    }
+
+    /// Returns: number of components that are `true`
+    pub fn count_true(&self) -> usize {
+        self.components.iter().filter( |c| **c ).count()
+    }
+
+}
+
+impl Display for Vector3Bool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "( {}, {}, {} )", self.components[0], self.components[1], self.components[2] )
+    }
+}
+
+impl Index<usize> for Vector3Bool {
+    type Output = bool;
+
+    fn index(&self, index:usize) -> &bool {
+        &self.components[index]
+    }
+}
+
+impl IndexMut<usize> for Vector3Bool {
+    fn index_mut(&mut self, index:usize) -> &mut bool {
+        &mut self.components[index]
+    }
+}
+
+impl Not for Vector3Bool {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_array( self.components.map( |c| !c ) )
+    }
+}
+
+impl BitAnd for Vector3Bool {
+    type Output = Self;
+
+    fn bitand(self, rhs:Self) -> Self {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( rhs.components.iter() ) {
+            *a = *a && *b;
+        }
+        Self::from_array( result )
+    }
+}
+
+impl BitOr for Vector3Bool {
+    type Output = Self;
+
+    fn bitor(self, rhs:Self) -> Self {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( rhs.components.iter() ) {
+            *a = *a || *b;
+        }
+        Self::from_array( result )
+    }
+}
+
+/// 4-component boolean Vector, used as a component-wise mask
+/// 
+/// Indexable with **[ ]**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Eq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Vector4Bool {
+    components:[bool;4]
+}
+
+impl Vector4Bool {
+
+    /// Create new `Vector4Bool` with given components
+    pub fn new( x:bool, y:bool, z:bool, w:bool ) -> Self {
+        Self {
+            components:[x,y,z,w]
+        }
+    }
+
+    /// Create new `Vector4Bool` with every component set to `value`
+    pub fn new_splat( value:bool ) -> Self {
+        Self {
+            components:[value;4]
+        }
+    }
+
+    /// Create new `Vector4Bool` from 4-component `array`
+    pub fn from_array( components:[bool;4] ) -> Self {
+        Self { components }
+    }
+
+    /// Returns: `reference` to vector's components `array`
+    pub fn as_array(&self) -> &[bool;4] {
+        &self.components
+    }
+
+    /// Returns: `true` if **any** component is `true`
+    pub fn any(&self) -> bool {
+        self.components.iter().any( |c| *c )
+    }
+
+    /// Returns: `true` if **all** components are `true`
+    pub fn all(&self) -> bool {
+        self.components.iter().all( |c| *c )
+    }
+
+    /// Returns: number of components that are `true`
+    pub fn count_true(&self) -> usize {
+        self.components.iter().filter( |c| **c ).count()
+    }
+
+}
+
+impl Display for Vector4Bool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "( {}, {}, {}, {} )", self.components[0], self.components[1], self.components[2], self.components[3] )
+    }
+}
+
+impl Index<usize> for Vector4Bool {
+    type Output = bool;
+
+    fn index(&self, index:usize) -> &bool {
+        &self.components[index]
+    }
+}
+
+impl IndexMut<usize> for Vector4Bool {
+    fn index_mut(&mut self, index:usize) -> &mut bool {
+        &mut self.components[index]
+    }
+}
+
+impl Not for Vector4Bool {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::from_array( self.components.map( |c| !c ) )
+    }
+}
+
+impl BitAnd for Vector4Bool {
+    type Output = Self;
+
+    fn bitand(self, rhs:Self) -> Self {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( rhs.components.iter() ) {
+            *a = *a && *b;
+        }
+        Self::from_array( result )
+    }
+}
+
+impl BitOr for Vector4Bool {
+    type Output = Self;
+
+    fn bitor(self, rhs:Self) -> Self {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( rhs.components.iter() ) {
+            *a = *a || *b;
+        }
+        Self::from_array( result )
+    }
+}
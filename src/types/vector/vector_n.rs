@@ -0,0 +1,290 @@
+use core::fmt::Display;
+use core::ops::{
+    Add, Sub, Mul, Div, Neg, Index, IndexMut
+};
+
+use super::{
+    Vector2,
+    Vector3,
+    Vector4,
+};
+
+/// N-component Vector for arbitrary small dimensions
+/// 
+/// Prefer `Vector2`, `Vector3` and `Vector4` when the dimension fits,
+/// `VectorN` only has the operations shared by every dimension
+/// 
+/// Indexable with **[ ]**
+/// 
+/// Implements: `Clone`, `Copy`, `PartialEq`, `Debug`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VectorN<const N:usize> {
+    components:[f32;N]
+}
+
+impl<const N:usize> VectorN<N> {
+
+    /// Create new `VectorN` from `array`
+    pub fn from_array( components:[f32;N] ) -> Self {
+        Self { components }
+    }
+
+    /// Create new `VectorN` with all components set to `value`
+    pub fn new_splat( value:f32 ) -> Self {
+        Self { components:[value;N] }
+    }
+
+    /// Create new `VectorN` with all components set to **0.0**
+    pub fn new_zero() -> Self {
+        Self::new_splat( 0.0 )
+    }
+
+    /// Create new `VectorN` with all components set to **1.0**
+    pub fn new_one() -> Self {
+        Self::new_splat( 1.0 )
+    }
+
+    /// Returns: `reference` to components `array`
+    pub fn as_array(&self) -> &[f32;N] {
+        &self.components
+    }
+
+    /// Returns: `mutable reference` to components `array`
+    pub fn as_mut_array(&mut self) -> &mut [f32;N] {
+        &mut self.components
+    }
+
+    // =============================== &self =====================================
+    /// Returns: new `VectorN` with the same direction but with `magnitude` of **1.0**
+    pub fn normal(&self) -> Self {
+        *self / self.magnitude()
+    }
+
+    /// Returns: vector's length
+    pub fn magnitude(&self) -> f32 {
+        super::magnitude_components( &self.components )
+    }
+
+    /// Returns: vector's length without applying square root
+    pub fn sqr_magnitude(&self) -> f32 {
+        super::sqr_magnitude_components( &self.components )
+    }
+
+    /// Apply `f` to every component
+    /// 
+    /// Returns: new `VectorN`
+    pub fn map<F>( &self, f:F ) -> Self
+    where F:FnMut( f32 ) -> f32
+    {
+        Self::from_array( self.components.map( f ) )
+    }
+
+    /// Apply `f` to every pair of components from `self` and `other`
+    /// 
+    /// Returns: new `VectorN`
+    pub fn zip_map<F>( &self, other:&Self, mut f:F ) -> Self
+    where F:FnMut( f32, f32 ) -> f32
+    {
+        let mut result = self.components;
+        for ( a, b ) in result.iter_mut().zip( other.components.iter() ) {
+            *a = f( *a, *b );
+        }
+        Self::from_array( result )
+    }
+
+    /// Combine every component into a single value, starting with `init`
+    pub fn fold<T, F>( &self, init:T, f:F ) -> T
+    where F:FnMut( T, f32 ) -> T
+    {
+        self.components.iter().copied().fold( init, f )
+    }
+
+    // =============================== &mut self =====================================
+    /// Clamp vector's magnitude to `max`
+    pub fn clamp_magnitude( &mut self, max:f32 ) {
+        super::clamp_magnitude_components( &mut self.components, max );
+    }
+
+    // =============================== STATIC =====================================
+    /// Linearly interpolate from `a` to `b`
+    /// 
+    /// Clamps `t` between **0.0** and **1.0**
+    /// 
+    /// Returns: new `VectorN` with values between `a` and `b`
+    pub fn lerp( a:&Self, b:&Self, t:f32 ) -> Self {
+        Self::lerp_unclamped( a, b, t.clamp( 0.0, 1.0 ) )
+    }
+
+    /// Linearly interpolate from `a` to `b`
+    /// 
+    /// Does **not** clamp `t`
+    /// 
+    /// Returns: new `VectorN` with values between `a` and `b`
+    pub fn lerp_unclamped( a:&Self, b:&Self, t:f32 ) -> Self {
+        ( *a * ( 1.0 - t ) ) + ( *b * t )
+    }
+
+    /// Returns: **dot** product of `a` and `b`
+    pub fn dot( a:&Self, b:&Self ) -> f32 {
+        super::dot_components( a.as_array(), b.as_array() )
+    }
+
+    /// Component-wise scale `a` and `b`
+    /// 
+    /// Returns: new `VectorN` with each component being a\[-\] * b\[-\]
+    pub fn scale( a:&Self, b:&Self ) -> Self {
+        let mut result = a.components;
+        super::componentwise_scale_components( a.as_array(), b.as_array(), &mut result );
+        Self::from_array( result )
+    }
+
+    /// Calculate distance between `from` and `to`
+    pub fn distance( from:&Self, to:&Self ) -> f32 {
+        ( *from - *to ).magnitude()
+    }
+}
+
+impl<const N:usize> Default for VectorN<N> {
+    fn default() -> Self {
+        Self::new_zero()
+    }
+}
+
+impl<const N:usize> Display for VectorN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!( f, "(" )?;
+        for ( i, component ) in self.components.iter().enumerate() {
+            if i != 0 {
+                write!( f, "," )?;
+            }
+            write!( f, " {:7.3}", component )?;
+        }
+        write!( f, " )" )
+    }
+}
+
+impl<const N:usize> Index<usize> for VectorN<N> {
+    type Output = f32;
+    fn index(&self, index:usize) -> &f32 {
+        &self.components[index]
+    }
+}
+
+impl<const N:usize> IndexMut<usize> for VectorN<N> {
+    fn index_mut(&mut self, index:usize) -> &mut f32 {
+        &mut self.components[index]
+    }
+}
+
+impl<const N:usize> AsRef<[f32]> for VectorN<N> {
+    fn as_ref(&self) -> &[f32] {
+        &self.components
+    }
+}
+
+impl<const N:usize> AsMut<[f32]> for VectorN<N> {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.components
+    }
+}
+
+impl<const N:usize> IntoIterator for VectorN<N> {
+    type Item = f32;
+    type IntoIter = core::array::IntoIter<f32, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+impl<const N:usize> From<[f32;N]> for VectorN<N> {
+    fn from(components:[f32;N]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl<const N:usize> From<VectorN<N>> for [f32;N] {
+    fn from(v:VectorN<N>) -> Self {
+        v.components
+    }
+}
+
+impl From<Vector2> for VectorN<2> {
+    fn from(v:Vector2) -> Self {
+        Self::from_array( *v.as_array() )
+    }
+}
+
+impl From<VectorN<2>> for Vector2 {
+    fn from(v:VectorN<2>) -> Self {
+        Vector2::from_array( v.components )
+    }
+}
+
+impl From<Vector3> for VectorN<3> {
+    fn from(v:Vector3) -> Self {
+        Self::from_array( *v.as_array() )
+    }
+}
+
+impl From<VectorN<3>> for Vector3 {
+    fn from(v:VectorN<3>) -> Self {
+        Vector3::from_array( v.components )
+    }
+}
+
+impl From<Vector4> for VectorN<4> {
+    fn from(v:Vector4) -> Self {
+        Self::from_array( *v.as_array() )
+    }
+}
+
+impl From<VectorN<4>> for Vector4 {
+    fn from(v:VectorN<4>) -> Self {
+        Vector4::from_array( v.components )
+    }
+}
+
+impl<const N:usize> Neg for VectorN<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let mut result = self.components;
+        super::negate_components(&mut result);
+        Self::from_array(result)
+    }
+}
+
+impl<const N:usize> Add for VectorN<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut result = self.components;
+        super::add_components(self.as_array(), rhs.as_array(), &mut result);
+        Self::from_array(result)
+    }
+}
+
+impl<const N:usize> Sub for VectorN<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let mut result = self.components;
+        super::sub_components(self.as_array(), rhs.as_array(), &mut result);
+        Self::from_array(result)
+    }
+}
+
+impl<const N:usize> Mul<f32> for VectorN<N> {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        let mut result = self.components;
+        super::scale_components(self.as_array(), rhs, &mut result);
+        Self::from_array(result)
+    }
+}
+
+impl<const N:usize> Div<f32> for VectorN<N> {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self {
+        let mut result = self.components;
+        super::scale_components(self.as_array(), 1.0 / rhs, &mut result);
+        Self::from_array(result)
+    }
+}
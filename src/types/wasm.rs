@@ -0,0 +1,150 @@
+//! *WebAssembly* interop through `js_sys` typed arrays
+//! 
+//! `as_float32_array_view` functions create a view straight into wasm memory
+//! so data can be handed to *WebGL* without copying
+
+use js_sys::Float32Array;
+
+use super::{
+    Vector2, Vector3, Vector4,
+    Quaternion,
+    Matrix3x3, Matrix4x4,
+};
+
+impl Vector2 {
+    /// Returns: new `Float32Array` with a copy of `Vector2` data
+    pub fn to_float32_array(&self) -> Float32Array {
+        Float32Array::from( self.as_array().as_slice() )
+    }
+
+    /// Returns: `Float32Array` view into `Vector2` data without copying
+    /// 
+    /// # Safety
+    /// 
+    /// View is invalidated if wasm memory grows or `Vector2` is moved or dropped,
+    /// do **not** allocate while the view is alive
+    pub unsafe fn as_float32_array_view(&self) -> Float32Array {
+        Float32Array::view( self.as_array().as_slice() )
+    }
+}
+
+impl From<&Vector2> for Float32Array {
+    fn from( value:&Vector2 ) -> Self {
+        value.to_float32_array()
+    }
+}
+
+impl Vector3 {
+    /// Returns: new `Float32Array` with a copy of `Vector3` data
+    pub fn to_float32_array(&self) -> Float32Array {
+        Float32Array::from( self.as_array().as_slice() )
+    }
+
+    /// Returns: `Float32Array` view into `Vector3` data without copying
+    /// 
+    /// # Safety
+    /// 
+    /// View is invalidated if wasm memory grows or `Vector3` is moved or dropped,
+    /// do **not** allocate while the view is alive
+    pub unsafe fn as_float32_array_view(&self) -> Float32Array {
+        Float32Array::view( self.as_array().as_slice() )
+    }
+}
+
+impl From<&Vector3> for Float32Array {
+    fn from( value:&Vector3 ) -> Self {
+        value.to_float32_array()
+    }
+}
+
+impl Vector4 {
+    /// Returns: new `Float32Array` with a copy of `Vector4` data
+    pub fn to_float32_array(&self) -> Float32Array {
+        Float32Array::from( self.as_array().as_slice() )
+    }
+
+    /// Returns: `Float32Array` view into `Vector4` data without copying
+    /// 
+    /// # Safety
+    /// 
+    /// View is invalidated if wasm memory grows or `Vector4` is moved or dropped,
+    /// do **not** allocate while the view is alive
+    pub unsafe fn as_float32_array_view(&self) -> Float32Array {
+        Float32Array::view( self.as_array().as_slice() )
+    }
+}
+
+impl From<&Vector4> for Float32Array {
+    fn from( value:&Vector4 ) -> Self {
+        value.to_float32_array()
+    }
+}
+
+impl Quaternion {
+    /// Returns: new `Float32Array` with a copy of `Quaternion` data
+    pub fn to_float32_array(&self) -> Float32Array {
+        Float32Array::from( self.as_array().as_slice() )
+    }
+
+    /// Returns: `Float32Array` view into `Quaternion` data without copying
+    /// 
+    /// # Safety
+    /// 
+    /// View is invalidated if wasm memory grows or `Quaternion` is moved or dropped,
+    /// do **not** allocate while the view is alive
+    pub unsafe fn as_float32_array_view(&self) -> Float32Array {
+        Float32Array::view( self.as_array().as_slice() )
+    }
+}
+
+impl From<&Quaternion> for Float32Array {
+    fn from( value:&Quaternion ) -> Self {
+        value.to_float32_array()
+    }
+}
+
+impl Matrix3x3 {
+    /// Returns: new `Float32Array` with a copy of `Matrix3x3` data
+    pub fn to_float32_array(&self) -> Float32Array {
+        Float32Array::from( self.as_array().as_slice() )
+    }
+
+    /// Returns: `Float32Array` view into `Matrix3x3` data without copying
+    /// 
+    /// # Safety
+    /// 
+    /// View is invalidated if wasm memory grows or `Matrix3x3` is moved or dropped,
+    /// do **not** allocate while the view is alive
+    pub unsafe fn as_float32_array_view(&self) -> Float32Array {
+        Float32Array::view( self.as_array().as_slice() )
+    }
+}
+
+impl From<&Matrix3x3> for Float32Array {
+    fn from( value:&Matrix3x3 ) -> Self {
+        value.to_float32_array()
+    }
+}
+
+impl Matrix4x4 {
+    /// Returns: new `Float32Array` with a copy of `Matrix4x4` data
+    pub fn to_float32_array(&self) -> Float32Array {
+        Float32Array::from( self.as_array().as_slice() )
+    }
+
+    /// Returns: `Float32Array` view into `Matrix4x4` data without copying
+    /// 
+    /// # Safety
+    /// 
+    /// View is invalidated if wasm memory grows or `Matrix4x4` is moved or dropped,
+    /// do **not** allocate while the view is alive
+    pub unsafe fn as_float32_array_view(&self) -> Float32Array {
+        Float32Array::view( self.as_array().as_slice() )
+    }
+}
+
+impl From<&Matrix4x4> for Float32Array {
+    fn from( value:&Matrix4x4 ) -> Self {
+        value.to_float32_array()
+    }
+}